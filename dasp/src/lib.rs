@@ -43,17 +43,86 @@
 //!     - The **envelope-rms** feature enables RMS envelope detection.
 //! - The **graph** feature enables the `dasp_graph` crate via the [graph](./graph/index.html)
 //!   module.
+//!     - The **node-agc** feature provides the `Agc` node, a leveler that slowly adjusts its gain
+//!       to keep the long-term RMS of its input near a target level.
+//!     - The **node-balance** feature provides a stereo `Balance` node that attenuates the left or
+//!       right channel without folding one into the other.
+//!     - The **node-band-split** feature provides the `BandSplit` and `BandMerge` nodes, which
+//!       split a signal into Linkwitz-Riley bands and merge them back with phase compensation for
+//!       transparent multiband processing.
+//!     - The **node-bass-enhancer** feature provides the `BassEnhancer` node, which generates
+//!       harmonics of low frequencies so that small speakers may convey the missing fundamental.
+//!     - The **node-biquad** feature provides the `Biquad` node, a second-order IIR filter with
+//!       coefficients that may be set at runtime.
 //!     - The **node-boxed** feature provides a `Node` implementation for `Box<dyn Node>`.
+//!     - The **node-channel-delay** feature provides a `ChannelDelay` node that applies an
+//!       independent, optionally fractional delay to each channel.
+//!     - The **node-compressor** feature provides a `Compressor` node with a quadratic soft knee
+//!       for feed-forward dynamic range compression.
+//!     - The **node-convolver** feature provides the `Convolver` node, which convolves its input
+//!       with a user-supplied impulse response, including true-stereo impulse responses.
+//!     - The **node-crest-meter** feature provides the `CrestMeter` node, which passes audio
+//!       through while measuring the crest factor over a sliding window.
+//!     - The **node-crossover** feature provides a `Crossover` node that splits a signal into
+//!       multiple bands using Linkwitz-Riley filters.
+//!     - The **node-deesser** feature provides the `DeEsser` node, which detects sibilance via a
+//!       band-pass sidechain and attenuates either the high band or the whole signal.
 //!     - The **node-delay** feature provides a simple multi-channel `Delay` node.
+//!     - The **node-env-vca** feature provides the `EnvVca` node, a gate-triggered ADSR envelope
+//!       applied directly to its audio input.
+//!     - The **node-exciter** feature provides an `Exciter` node that adds upper harmonics by
+//!       saturating a high-passed copy of the signal and mixing it with the dry signal.
+//!     - The **node-filter-morph** feature provides the `FilterMorph` node, a filter that smoothly
+//!       morphs between two filter shapes.
+//!     - The **node-gain** feature provides the **Gain** node, which applies a gain to its input
+//!       with a selectable **FaderLaw** for mapping a fader position to a linear gain.
+//!     - The **node-gate** feature provides the `Gate` node, a noise gate with optional external
+//!       sidechain keying.
 //!     - The **node-graph** feature provides an implementation of `Node` for a type that encapsulates
 //!       another `dasp` graph type.
+//!     - The **node-input-trim** feature provides the `InputTrim` node, which sums its inputs after
+//!       applying an independent trim in decibels to each, given by the `Input` variant.
+//!     - The **node-looper** feature provides a `Looper` node that records its input into a loop
+//!       buffer and plays it back, with support for overdubbing.
+//!     - The **node-mixer** feature provides the `Mixer` node, which sums its inputs onto the
+//!       output after applying a per-edge gain given by the `f32` edge weight.
+//!     - The **node-mono** feature provides the `Mono` node, which sums a stereo input to mono with
+//!       optional attenuation and detection of anti-phase content.
+//!     - The **node-ms-eq** feature provides the `MidSideEq` node, which applies independent
+//!       shelving EQ to the mid and side components of a stereo signal.
+//!     - The **node-null-test** feature provides the `NullTest` node, which outputs the difference
+//!       between a reference and a processed signal after aligning their latency and gain, for
+//!       verifying that processing is transparent.
+//!     - The **node-oversampled-clipper** feature provides the `OversampledClipper` node, a hard or
+//!       soft clipper that runs at 2x or 4x the sample rate to reduce aliasing.
 //!     - The **node-pass** feature provides a `Pass` node that simply passes audio from its
 //!       inputs to its outputs.
-//!     - The **node-signal** feature provides an implementation of `Node` for `dyn Signal`.
+//!     - The **node-safe-gain** feature provides the `SafeGain` node, a gain with dezippered
+//!       changes and an optional soft limit, suitable as a robust output stage.
+//!     - The **node-sample-player** feature provides a `SamplePlayer` node that plays a preloaded
+//!       one-shot sample with overlapping voices upon each trigger.
+//!     - The **node-signal** feature provides the `SignalNode` source node and an implementation of
+//!       `Node` for `dyn Signal`.
+//!     - The **node-silence-detect** feature provides a `SilenceDetect` node that passes audio
+//!       through while reporting runs of silence and sudden dropouts.
+//!     - The **node-slew-limit** feature provides the `SlewLimit` node, which limits how fast each
+//!       channel may rise and fall per second.
 //!     - The **node-sum** feature provides `Sum` and `SumBuffers` `Node` implementations.
+//!     - The **node-table-shaper** feature provides the `TableShaper` node, a waveshaper driven by
+//!       a user-supplied transfer function lookup table.
+//!     - The **node-tilt** feature provides the `Tilt` node, a spectral tilt filter that boosts one
+//!       end of the spectrum while cutting the other around a pivot frequency.
+//!     - The **node-tremolo** feature provides the `Tremolo` node, which modulates the amplitude of
+//!       its input with an LFO, optionally offset in phase between channels for auto-panning.
+//!     - The **node-vibrato** feature provides the `Vibrato` node, which modulates the pitch of its
+//!       input by sweeping a short delay with an LFO.
+//!     - The **graph-async** feature provides the experimental `AsyncNode` trait and the
+//!       `AsyncSource` node for bridging asynchronous sources into the graph.
+//!     - The **graph-ftz** feature enables flushing of denormal samples to zero between nodes.
 //! - The **interpolate** feature enables the `dasp_interpolate` crate via the
 //!   [interpolate](./interpolate/index.html) module.
 //!     - The **interpolate-floor** feature enables a floor interpolation implementation.
+//!     - The **interpolate-lagrange** feature enables a Lagrange interpolation implementation.
 //!     - The **interpolate-linear** feature enables a linear interpolation implementation.
 //!     - The **interpolate-sinc** feature enables a sinc interpolation implementation.
 //! - The **peak** feature enables the `dasp_peak` crate via the [peak](./peak/index.html) module.
@@ -72,19 +141,27 @@
 //!       trait.
 //!     - The **signal-window** feature enables the
 //!       [**signal::window**](./signal/window/index.html) module.
+//!     - The **signal-window-gaussian** enables the
+//!       [**signal::window::gaussian**](./signal/window/fn.gaussian.html) window constructor.
 //!     - The **signal-window-hann** enables the
 //!       [**signal::window::hann**](./signal/window/fn.hann.html) window constructor.
 //!     - The **signal-window-rectangle** enables the
 //!       [**signal::window::rectangle**](./signal/window/fn.rectangle.html) window constructor.
+//!     - The **signal-window-tukey** enables the
+//!       [**signal::window::tukey**](./signal/window/fn.tukey.html) window constructor.
 //! - The **slice** feature enables the `dasp_slice` crate via the [slice](./slice/index.html)
 //!   module.
 //!     - The **slice-boxed** feature enables boxed slice conversion traits and functions.
 //! - The **window** feature enables the `dasp_window` crate via the [window](./window/index.html)
 //!   module.
+//!     - The **window-gaussian** feature enables the
+//!       [**Gaussian**](./window/struct.Gaussian.html) window implementation.
 //!     - The **window-hann** feature enables the [**Hann**](./window/struct.Hann.html)
 //!       window implementation.
 //!     - The **window-rectangle** feature enables the
 //!       [**Rectangle**](./window/struct.Rectangle.html) window implementation.
+//!     - The **window-tukey** feature enables the [**Tukey**](./window/struct.Tukey.html)
+//!       window implementation.
 //!
 //! You can also enable all of the above features with the `--all-features` flag.
 //!
@@ -80,7 +80,54 @@ pub trait Frame: Copy + Clone + PartialEq {
     where
         I: Iterator<Item = Self::Sample>;
 
+    /// Create a new `Frame` from any iterable collection of samples for each channel.
+    ///
+    /// Returns `None` unless the given channels yield exactly `CHANNELS` samples. Along with
+    /// `channels`, this allows for converting between frames and arbitrary containers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dasp_frame::Frame;
+    ///
+    /// fn main() {
+    ///     let channels = vec![0.25f32, -0.5];
+    ///     let frame = <[f32; 2]>::try_from_channels(channels).unwrap();
+    ///     assert_eq!(frame, [0.25, -0.5]);
+    ///     let channels: Vec<f32> = frame.channels().collect();
+    ///     assert_eq!(<[f32; 2]>::try_from_channels(channels), Some(frame));
+    ///
+    ///     assert_eq!(<[f32; 2]>::try_from_channels(vec![0.25]), None);
+    ///     assert_eq!(<[f32; 2]>::try_from_channels(vec![0.25, -0.5, 1.0]), None);
+    /// }
+    /// ```
+    fn try_from_channels<I>(channels: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Self::Sample>,
+    {
+        let mut channels = channels.into_iter();
+        let frame = Self::from_samples(&mut channels)?;
+        match channels.next() {
+            Some(_) => None,
+            None => Some(frame),
+        }
+    }
+
     /// Converts the frame into an iterator yielding the sample for each channel in the frame.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dasp_frame::Frame;
+    ///
+    /// fn main() {
+    ///     let frame = [0.25f32, -0.5];
+    ///     let mut channels = frame.channels();
+    ///     assert_eq!(channels.next(), Some(0.25));
+    ///     assert_eq!(channels.next(), Some(-0.5));
+    ///     assert_eq!(channels.next(), None);
+    /// }
+    /// ```
     fn channels(self) -> Self::Channels;
 
     /// Returns an iterator yielding references to the sample for each channel in the frame.
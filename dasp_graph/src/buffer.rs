@@ -1,23 +1,84 @@
+use crate::Vec;
 use core::fmt;
 use core::ops::{Deref, DerefMut};
 
-/// The fixed-size buffer used for processing the graph.
+/// The buffer used for processing the graph.
+///
+/// The length of a buffer defaults to `Buffer::LEN` and may be changed at runtime via `set_len`.
+/// Buffers of up to `Buffer::LEN` samples are stored inline, while longer buffers are stored on
+/// the heap. The **Processor** sets the length of the buffers of each node to its block size
+/// before processing the node. See `Processor::with_block_frames`.
 #[derive(Clone)]
 pub struct Buffer {
-    data: [f32; Self::LEN],
+    inline: [f32; Self::LEN],
+    heap: Vec<f32>,
+    len: usize,
 }
 
 impl Buffer {
-    /// The fixed length of the **Buffer** type.
+    /// The default length of the **Buffer** type.
     pub const LEN: usize = 64;
-    /// A silent **Buffer**.
+    /// A silent **Buffer** of the default length.
     pub const SILENT: Self = Buffer {
-        data: [0.0; Self::LEN],
+        inline: [0.0; Self::LEN],
+        heap: Vec::new(),
+        len: Self::LEN,
     };
 
+    /// A silent **Buffer** of the given length.
+    pub fn silent(len: usize) -> Self {
+        let mut buffer = Self::SILENT;
+        buffer.set_len(len);
+        buffer
+    }
+
+    /// Set the number of samples in the buffer, silencing any samples exposed by growing it.
+    ///
+    /// Only allocates when growing beyond both `Buffer::LEN` and the longest length the buffer
+    /// has held so far.
+    pub fn set_len(&mut self, len: usize) {
+        if len > Self::LEN {
+            if self.len <= Self::LEN {
+                self.heap.clear();
+                self.heap.extend_from_slice(&self.inline[..self.len]);
+            }
+            self.heap.resize(len, 0.0);
+        } else if self.len > Self::LEN {
+            self.inline[..len].copy_from_slice(&self.heap[..len]);
+        } else if len > self.len {
+            self.inline[self.len..len].iter_mut().for_each(|s| *s = 0.0);
+        }
+        self.len = len;
+    }
+
     /// Short-hand for writing silence to the whole buffer.
     pub fn silence(&mut self) {
-        self.data.copy_from_slice(&Self::SILENT)
+        self.iter_mut().for_each(|s| *s = 0.0);
+    }
+
+    /// Whether or not every sample in the buffer is equal to equilibrium, i.e. `0.0`.
+    ///
+    /// Returns as soon as a non-silent sample is found.
+    pub fn is_silent(&self) -> bool {
+        self.iter().all(|&s| s == 0.0)
+    }
+
+    /// Whether or not the magnitude of every sample in the buffer is less than or equal to `eps`.
+    ///
+    /// Useful for treating denormals or residual filter tails as silence. Returns as soon as a
+    /// sample exceeding `eps` is found.
+    pub fn is_silent_eps(&self, eps: f32) -> bool {
+        self.iter().all(|s| s.abs() <= eps)
+    }
+
+    /// Replace every denormal (subnormal) sample in the buffer with `0.0`.
+    #[cfg(feature = "ftz")]
+    pub fn flush_denormals(&mut self) {
+        for s in self.iter_mut() {
+            if s.is_subnormal() {
+                *s = 0.0;
+            }
+        }
     }
 }
 
@@ -28,14 +89,17 @@ impl Default for Buffer {
 }
 
 impl From<[f32; Self::LEN]> for Buffer {
-    fn from(data: [f32; Self::LEN]) -> Self {
-        Buffer { data }
+    fn from(inline: [f32; Self::LEN]) -> Self {
+        Buffer {
+            inline,
+            ..Self::SILENT
+        }
     }
 }
 
 impl fmt::Debug for Buffer {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        fmt::Debug::fmt(&self.data[..], f)
+        fmt::Debug::fmt(&self[..], f)
     }
 }
 
@@ -48,12 +112,20 @@ impl PartialEq for Buffer {
 impl Deref for Buffer {
     type Target = [f32];
     fn deref(&self) -> &Self::Target {
-        &self.data[..]
+        if self.len > Self::LEN {
+            &self.heap[..]
+        } else {
+            &self.inline[..self.len]
+        }
     }
 }
 
 impl DerefMut for Buffer {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.data[..]
+        if self.len > Self::LEN {
+            &mut self.heap[..]
+        } else {
+            &mut self.inline[..self.len]
+        }
     }
 }
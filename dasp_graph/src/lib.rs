@@ -43,10 +43,10 @@
 //!
 //! On the other hand, `dasp_graph`'s requirement for a fixed sample rate can also be a limitation.
 //! A `dasp_graph` cannot be composed of nodes with differing input sample rates meaning it is
-//! unsuitable for writing a streaming sample rate converter. `dasp_graph`'s fixed buffer size
+//! unsuitable for writing a streaming sample rate converter. `dasp_graph`'s block-based processing
 //! results in another limitation. It implies that when creating a cycle within the graph, a
-//! minimum delay of `Buffer::LEN` is incurred at the edge causing the cycle. This makes it
-//! tricky to compose per-sample feedback delays by using cycles in the graph.
+//! minimum delay of one block is incurred at the edge causing the cycle. This makes it tricky to
+//! compose per-sample feedback delays by using cycles in the graph.
 //!
 //! | Feature                                           | `dasp_graph`  | `dasp_signal` |
 //! | ------------------------------------------------- |:-------------:|:-------------:|
@@ -104,31 +104,131 @@
 //! disabled by disabling default features. You can then enable only the implementations you
 //! require with the following features:
 //!
+//! - The **node-agc** feature provides the `Agc` node, a leveler that slowly adjusts its gain to
+//!   keep the long-term RMS of its input near a target level.
+//! - The **node-balance** feature provides a stereo `Balance` node that attenuates the left or
+//!   right channel without folding one into the other.
+//! - The **node-band-split** feature provides the `BandSplit` and `BandMerge` nodes, which split a
+//!   signal into Linkwitz-Riley bands and merge them back with phase compensation for transparent
+//!   multiband processing.
+//! - The **node-bass-enhancer** feature provides the `BassEnhancer` node, which generates harmonics
+//!   of low frequencies so that small speakers may convey the missing fundamental.
+//! - The **node-biquad** feature provides the `Biquad` node, a second-order IIR filter with
+//!   coefficients that may be set at runtime. This is a building block for EQs, crossovers and
+//!   filter sweeps.
 //! - The **node-boxed** feature provides a `Node` implementation for `Box<dyn Node>`. This is
 //!   particularly useful for working with a graph composed of many different node types.
+//! - The **node-channel-delay** feature provides a `ChannelDelay` node that applies an independent,
+//!   optionally fractional delay to each channel. This is useful for time-aligning multi-microphone
+//!   recordings.
+//! - The **node-compressor** feature provides a `Compressor` node with a quadratic soft knee for
+//!   feed-forward dynamic range compression.
+//! - The **node-convolver** feature provides the `Convolver` node, which convolves its input with a
+//!   user-supplied impulse response, including true-stereo impulse responses.
+//! - The **node-crest-meter** feature provides the `CrestMeter` node, which passes audio through
+//!   while measuring the crest factor over a sliding window.
+//! - The **node-crossover** feature provides a `Crossover` node that splits a signal into multiple
+//!   bands using Linkwitz-Riley filters. This is useful as the front-end for multiband processing.
+//! - The **node-deesser** feature provides the `DeEsser` node, which detects sibilance via a
+//!   band-pass sidechain and attenuates either the high band or the whole signal.
+//! - The **node-env-vca** feature provides the `EnvVca` node, a gate-triggered ADSR envelope
+//!   applied directly to its audio input.
+//! - The **node-exciter** feature provides an `Exciter` node that adds upper harmonics by
+//!   saturating a high-passed copy of the signal and mixing it with the dry signal.
+//! - The **node-filter-morph** feature provides the `FilterMorph` node, a filter that smoothly
+//!   morphs between two filter shapes.
+//! - The **node-gain** feature provides the **Gain** node, which applies a gain to its input with a
+//!   selectable **FaderLaw** for mapping a fader position to a linear gain.
+//! - The **node-gate** feature provides the `Gate` node, a noise gate with optional external
+//!   sidechain keying.
 //! - The **node-graph** feature provides an implementation of `Node` for a type that encapsulates
 //!   another `dasp` graph type. This allows for composing individual nodes from graphs of other
 //!   nodes.
-//! - The **node-signal** feature provides an implementation of `Node` for `dyn Signal`. This is
-//!   useful when designing nodes using `dasp_signal`.
+//! - The **node-input-trim** feature provides the `InputTrim` node, which sums its inputs after
+//!   applying an independent trim in decibels to each, given by the `Input` variant.
+//! - The **node-looper** feature provides a `Looper` node that records its input into a loop buffer
+//!   and plays it back, with support for overdubbing.
+//! - The **node-mixer** feature provides the `Mixer` node, which sums its inputs onto the output
+//!   after applying a per-edge gain given by the `f32` edge weight.
+//! - The **node-mono** feature provides the `Mono` node, which sums a stereo input to mono with
+//!   optional attenuation and detection of anti-phase content.
+//! - The **node-ms-eq** feature provides the `MidSideEq` node, which applies independent shelving
+//!   EQ to the mid and side components of a stereo signal.
+//! - The **node-null-test** feature provides the `NullTest` node, which outputs the difference
+//!   between a reference and a processed signal after aligning their latency and gain, for
+//!   verifying that processing is transparent.
+//! - The **node-oversampled-clipper** feature provides the `OversampledClipper` node, a hard or
+//!   soft clipper that runs at 2x or 4x the sample rate to reduce aliasing.
+//! - The **node-safe-gain** feature provides the `SafeGain` node, a gain with dezippered changes
+//!   and an optional soft limit, suitable as a robust output stage.
+//! - The **node-sample-player** feature provides a `SamplePlayer` node that plays a preloaded
+//!   one-shot sample with overlapping voices upon each trigger. This is the core of a drum sampler.
+//! - The **node-signal** feature provides the `SignalNode` source node, which adapts any `Signal`
+//!   into a `Node`, along with an implementation of `Node` for `dyn Signal`. This is useful when
+//!   designing nodes using `dasp_signal`.
 //! - The **node-delay** feature provides a simple multi-channel `Delay` node.
 //! - The **node-pass** feature provides a `Pass` node that simply passes audio from its
 //!   inputs to its outputs.
+//! - The **node-silence-detect** feature provides a `SilenceDetect` node that passes audio through
+//!   while reporting runs of silence and sudden dropouts. This is useful for catching glitches in
+//!   batch renders.
+//! - The **node-slew-limit** feature provides the `SlewLimit` node, which limits how fast each
+//!   channel may rise and fall per second.
 //! - The **node-sum** feature provides `Sum` and `SumBuffers` `Node` implementations. These are
 //!   useful for mixing together multiple inputs, and for simple mappings between different channel
 //!   layouts.
+//! - The **node-table-shaper** feature provides the `TableShaper` node, a waveshaper driven by a
+//!   user-supplied transfer function lookup table.
+//! - The **node-tilt** feature provides the `Tilt` node, a spectral tilt filter that boosts one end
+//!   of the spectrum while cutting the other around a pivot frequency. This is a popular one-knob
+//!   tonal shaper.
+//! - The **node-tremolo** feature provides the `Tremolo` node, which modulates the amplitude of its
+//!   input with an LFO, optionally offset in phase between channels for auto-panning.
+//! - The **node-vibrato** feature provides the `Vibrato` node, which modulates the pitch of its
+//!   input by sweeping a short delay with an LFO.
+//!
+//! The following features are not enabled by default:
+//!
+//! - The **async** feature provides the experimental `AsyncNode` trait for sources whose audio
+//!   may not be immediately available, along with an `AsyncSource` node that wraps an `AsyncNode`
+//!   with an internal queue of blocks in order to satisfy the synchronous `Node` trait.
+//! - The **ftz** feature enables flushing of denormal samples to zero in the output of every node
+//!   processed by a `Processor`. See `Processor::set_flush_denormals` for details.
 //!
 //! ### no_std
 //!
-//! *TODO: Adding support for `no_std` is pending the addition of support for `no_std` in petgraph.
-//! See https://github.com/petgraph/petgraph/pull/238.
+//! If working in a `no_std` context, you can disable the default **std** feature with
+//! `--no-default-features`. The `Node`, `Input`, `Buffer` and `Processor` types only require
+//! `core` along with the `alloc` crate for the `Vec`s that store buffers and traversal state.
+//!
+//! - The **alloc** feature provides the `Node` implementation for `Box<T>`. This is implied by
+//!   the **std** and **node-boxed** features.
+//!
+//! Each of the node features are available in a `no_std` context. Note that building the `dasp`
+//! crates without **std** currently requires a nightly toolchain for the floating point
+//! intrinsics used by `dasp_sample`.
+//!
+//! *Note: petgraph itself does not yet support `no_std`, which is pending
+//! https://github.com/petgraph/petgraph/pull/238. Until then, petgraph links `std` even with
+//! its default features disabled, so the `Processor`, the **node-graph** feature and any other
+//! use of petgraph's graph types still depend on `std` in practice. Only `dasp_graph`'s own
+//! items are limited to `core` and `alloc`.*
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+#[macro_use]
+extern crate alloc;
 
 pub use buffer::Buffer;
+#[cfg(feature = "alloc")]
+use core::any::Any;
+use core::fmt;
 pub use node::{Input, Node};
 use petgraph::data::{DataMap, DataMapMut};
 use petgraph::visit::{
-    Data, DfsPostOrder, EdgeRef, GraphBase, IntoEdgesDirected, IntoNeighborsDirected, NodeCount,
-    NodeIndexable, Reversed, Visitable,
+    Data, DfsPostOrder, EdgeRef, GraphBase, IntoEdgesDirected, IntoNeighborsDirected,
+    IntoNodeIdentifiers, NodeCount, NodeIndexable, Reversed, VisitMap, Visitable,
 };
 use petgraph::{Incoming, Outgoing};
 
@@ -138,6 +238,16 @@ pub use node::{BoxedNode, BoxedNodeSend};
 mod buffer;
 pub mod node;
 
+#[cfg(not(feature = "std"))]
+type Vec<T> = alloc::vec::Vec<T>;
+#[cfg(feature = "std")]
+type Vec<T> = std::vec::Vec<T>;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+type Box<T> = alloc::boxed::Box<T>;
+#[cfg(feature = "std")]
+type Box<T> = std::boxed::Box<T>;
+
 /// State related to the processing of an audio graph of type `G`.
 ///
 /// The **Processor** allows for the re-use of resources related to traversal and requesting audio
@@ -186,6 +296,44 @@ where
     dfs_post_order: DfsPostOrder<G::NodeId, G::Map>,
     // Solely for collecting the inputs of a node in order to apply its `Node::process` method.
     inputs: Vec<node::Input<G::EdgeWeight>>,
+    // The index of the source node of each input, used to keep `inputs` sorted.
+    input_sources: Vec<usize>,
+    // A pending crossfade from the output of a previous topology, if any.
+    crossfade: Option<Crossfade<G::NodeId>>,
+    // The order in which nodes are visited, as computed by the most recent call to `prepare`.
+    order: Vec<G::NodeId>,
+    // The output node and crossfade source of the prepared `order`, if any.
+    prepared: Option<Prepared<G::NodeId>>,
+    // The nodes that were visited by the most recent call to `process_prepared`.
+    rendered: Vec<G::NodeId>,
+    // A copy of the previous output of a feedback node with an edge to itself.
+    self_feedback: Vec<Buffer>,
+    // A copy of a channel of the old output while blending it into the new during a crossfade.
+    crossfade_buf: Buffer,
+    // The number of frames processed by each call to `process`.
+    block_frames: usize,
+    // Whether or not denormal samples are flushed to zero in the output of each node.
+    #[cfg(feature = "ftz")]
+    flush_denormals: bool,
+}
+
+// State related to a crossfade from the output of one node to another.
+#[derive(Clone)]
+struct Crossfade<N> {
+    // The output node of the topology that is being faded out.
+    from: N,
+    // The total duration of the crossfade in frames.
+    frames: usize,
+    // The number of frames that have been processed so far.
+    elapsed: usize,
+}
+
+// The nodes for which the visit order of a **Processor** was prepared.
+struct Prepared<N> {
+    // The node at which the prepared subgraph ends.
+    node: N,
+    // The old output node of a crossfade in progress at the time of preparation, if any.
+    from: Option<N>,
 }
 
 /// For use as the node weight within a dasp graph. Contains the node and its buffers.
@@ -199,9 +347,58 @@ pub struct NodeData<T: ?Sized> {
     /// a node processing mono data would store one buffer, a node processing stereo data would
     /// store two, and so on.
     pub buffers: Vec<Buffer>,
+    /// Whether or not the outgoing edges of this node are **feedback** edges.
+    ///
+    /// The **Processor** does not follow feedback edges when computing the order in which nodes
+    /// are visited. Instead, the node is processed after all nodes that read its output, so that
+    /// they receive the output of the *previous* block. This allows for cycles within the graph,
+    /// e.g. for comb filters and reverb tanks, where the feedback node breaks the cycle with a
+    /// delay of one block. An edge from a feedback node to itself provides the node with its own
+    /// output from the previous block.
+    ///
+    /// During the first block, the inputs from a feedback node contain the initial contents of
+    /// its `buffers`, which are silent for all of the **NodeData** constructors.
+    pub feedback: bool,
     pub node: T,
 }
 
+/// The state of every node within a graph, as captured by `Processor::snapshot`.
+///
+/// Contains the state returned by `Node::save_state` and a copy of the buffers of each node, along
+/// with the progress of any crossfade in progress within the **Processor**.
+#[cfg(feature = "alloc")]
+pub struct Snapshot<N> {
+    nodes: Vec<NodeSnapshot<N>>,
+    crossfade: Option<Crossfade<N>>,
+}
+
+// The state of a single node within a **Snapshot**.
+#[cfg(feature = "alloc")]
+struct NodeSnapshot<N> {
+    node: N,
+    state: Option<Box<dyn Any + Send>>,
+    buffers: Vec<Buffer>,
+}
+
+/// A mismatch between the inputs of a node and those that it expects, as returned by `validate`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GraphError<N> {
+    /// The node receives a different number of inputs than given by `Node::expected_inputs`.
+    InputCount {
+        node: N,
+        expected: usize,
+        found: usize,
+    },
+    /// The `input` node has a different number of buffers than given by the
+    /// `Node::expected_input_channels` of `node`.
+    InputChannels {
+        node: N,
+        input: N,
+        expected: usize,
+        found: usize,
+    },
+}
+
 impl<G> Processor<G>
 where
     G: Data + Visitable,
@@ -210,19 +407,172 @@ where
     ///
     /// As long as this node count is not exceeded, the **Processor** should never require dynamic
     /// allocation following construction.
+    ///
+    /// Each call to `process` processes a block of `Buffer::LEN` frames. See `with_block_frames`
+    /// for choosing the block size at runtime.
     pub fn with_capacity(max_nodes: usize) -> Self
     where
         G::Map: Default,
     {
+        Self::with_block_frames(max_nodes, Buffer::LEN)
+    }
+
+    /// Construct a new graph processor from the given maximum anticipated node count and the
+    /// number of frames to process per block.
+    ///
+    /// This allows for matching the block size to that negotiated with an audio host at runtime.
+    /// Before processing each block, the length of the buffers of every node in the subgraph is
+    /// set to `block_frames` via `Buffer::set_len`. Nodes must read the number of frames to
+    /// process from the length of the buffers passed to `Node::process`, e.g. `output[0].len()`,
+    /// rather than assuming `Buffer::LEN`.
+    ///
+    /// Buffers of up to `Buffer::LEN` samples never allocate. For larger blocks, node buffers
+    /// constructed with fewer than `block_frames` samples, e.g. via `NodeData::new1`, are grown
+    /// when first processed. To avoid allocating on the audio thread, construct them with
+    /// `Buffer::silent(block_frames)` instead.
+    ///
+    /// **Panics** if `block_frames` is `0`.
+    pub fn with_block_frames(max_nodes: usize, block_frames: usize) -> Self
+    where
+        G::Map: Default,
+    {
+        assert!(block_frames > 0, "`block_frames` must be greater than `0`");
         let mut dfs_post_order = DfsPostOrder::default();
         dfs_post_order.stack = Vec::with_capacity(max_nodes);
         let inputs = Vec::with_capacity(max_nodes);
+        let input_sources = Vec::with_capacity(max_nodes);
+        let order = Vec::with_capacity(max_nodes);
+        let rendered = Vec::with_capacity(max_nodes);
         Self {
             dfs_post_order,
             inputs,
+            input_sources,
+            crossfade: None,
+            order,
+            prepared: None,
+            rendered,
+            self_feedback: Vec::new(),
+            crossfade_buf: Buffer::silent(block_frames),
+            block_frames,
+            #[cfg(feature = "ftz")]
+            flush_denormals: true,
         }
     }
 
+    /// The number of frames processed by each call to `process`.
+    pub fn block_frames(&self) -> usize {
+        self.block_frames
+    }
+
+    /// Enable or disable flushing denormal samples to zero. Enabled by default.
+    ///
+    /// Long-decaying filters, delays and reverbs can produce denormal (subnormal) numbers, which
+    /// are processed very slowly by many CPUs. While enabled, every denormal sample in the output
+    /// buffers of each node is replaced with `0.0` immediately after the node is processed. As
+    /// this is performed in software rather than by setting CPU flags, the result is identical
+    /// across platforms.
+    ///
+    /// Only the buffers of each node are flushed. Nodes that feed their own previous output back
+    /// into their processing benefit directly, while any other internal state is left untouched.
+    #[cfg(feature = "ftz")]
+    pub fn set_flush_denormals(&mut self, flush: bool) {
+        self.flush_denormals = flush;
+    }
+
+    /// Whether or not denormal samples are flushed to zero in the output of each node.
+    #[cfg(feature = "ftz")]
+    pub fn flushes_denormals(&self) -> bool {
+        self.flush_denormals
+    }
+
+    /// Schedule a crossfade from the output of the node at `from` over the given number of frames.
+    ///
+    /// This allows for making structural edits to a live graph without introducing a
+    /// discontinuity. Rather than editing the existing path in place, add the new path alongside it
+    /// with its own output node and pass the old output node here. During the following calls to
+    /// `process`, the subgraphs ending at both `from` and the requested node are processed and the
+    /// output of `from` is blended into the buffers of the requested node, fading linearly from the
+    /// old output to the new. Nodes shared by both subgraphs are only processed once per call.
+    ///
+    /// Once `is_crossfading` returns `false`, the old output node and any nodes that only it
+    /// depends upon may be safely removed from the graph.
+    ///
+    /// Scheduling a new crossfade replaces any crossfade that is already in progress. A `frames`
+    /// value of `0` cancels any crossfade in progress.
+    ///
+    /// As the subgraph of `from` must be processed during the crossfade, this invalidates any
+    /// order prepared via `prepare`.
+    pub fn crossfade_from(&mut self, from: G::NodeId, frames: usize) {
+        self.invalidate();
+        self.crossfade = match frames {
+            0 => None,
+            _ => Some(Crossfade {
+                from,
+                frames,
+                elapsed: 0,
+            }),
+        };
+    }
+
+    /// Whether or not a crossfade scheduled via `crossfade_from` is still in progress.
+    pub fn is_crossfading(&self) -> bool {
+        self.crossfade.is_some()
+    }
+
+    /// Compute and store the order in which the subgraph ending at `node` is processed, for
+    /// re-use by `process_prepared`.
+    ///
+    /// For a graph whose topology rarely changes, this avoids repeating the traversal on every
+    /// call to `process`. If a crossfade is in progress, the subgraph of the old output is included
+    /// in the prepared order and continues to be processed until the order is next prepared.
+    ///
+    /// The prepared order is only valid for as long as the topology of the graph is unchanged.
+    /// After adding or removing nodes or edges, call `invalidate` and prepare the order again.
+    ///
+    /// As long as the node count given to `with_capacity` is not exceeded, this only allocates when
+    /// first preparing a feedback node with an edge to itself, for copying its output.
+    ///
+    /// **Panics** if there is no node for the given index.
+    pub fn prepare<T>(&mut self, graph: &G, node: G::NodeId)
+    where
+        G: Data<NodeWeight = NodeData<T>> + DataMap,
+        for<'a> &'a G: GraphBase<NodeId = G::NodeId, EdgeId = G::EdgeId> + IntoEdgesDirected,
+    {
+        prepare(self, graph, node)
+    }
+
+    /// Process audio through the subgraph whose order was computed by the last call to
+    /// `prepare`, skipping the graph traversal entirely.
+    ///
+    /// Each node is processed exactly as it would be by `process`. As long as no node has more
+    /// inputs than the node count given to `with_capacity`, this does not allocate.
+    ///
+    /// **Panics** if no order has been prepared since construction or the last call to
+    /// `invalidate`, or if a prepared node no longer exists within the graph.
+    pub fn process_prepared<T>(&mut self, graph: &mut G)
+    where
+        G: Data<NodeWeight = NodeData<T>> + DataMapMut + NodeIndexable,
+        for<'a> &'a G: GraphBase<NodeId = G::NodeId, EdgeId = G::EdgeId> + IntoEdgesDirected,
+        T: Node<G::EdgeWeight>,
+        G::EdgeWeight: Clone,
+    {
+        process_prepared(self, graph)
+    }
+
+    /// Discard the order computed by `prepare`.
+    ///
+    /// This must be called after adding or removing nodes or edges within the graph, before
+    /// preparing the order again.
+    pub fn invalidate(&mut self) {
+        self.order.clear();
+        self.prepared = None;
+    }
+
+    /// Whether or not an order has been prepared for use by `process_prepared`.
+    pub fn is_prepared(&self) -> bool {
+        self.prepared.is_some()
+    }
+
     /// Process audio through the subgraph ending at the node with the given ID.
     ///
     /// Specifically, this traverses nodes in depth-first-search *post* order where the edges of
@@ -230,30 +580,227 @@ where
     /// connected to the inputs of the given `node`. This ensures that all inputs of each node are
     /// visited before the node itself.
     ///
+    /// Edges from nodes marked as `NodeData::feedback` are not followed during traversal. Each
+    /// feedback node is instead visited after the nodes that read its output, which receive its
+    /// output from the previous block.
+    ///
     /// The `Node::process` method is called on each node as they are visited in the traversal.
     ///
     /// Upon returning, the buffers of each visited node will contain the audio processed by their
     /// respective nodes.
     ///
+    /// If a crossfade has been scheduled via `crossfade_from`, the output of the old node is
+    /// blended into the buffers of `node`.
+    ///
+    /// This is a convenience wrapper that calls `prepare` followed by `process_prepared`. For
+    /// graphs with a stable topology, call these directly to avoid repeating the traversal.
+    ///
     /// Supports all graphs that implement the necessary petgraph traits and whose nodes are of
     /// type `NodeData<T>` where `T` implements the `Node` trait.
     ///
     /// **Panics** if there is no node for the given index.
     pub fn process<T>(&mut self, graph: &mut G, node: G::NodeId)
     where
-        G: Data<NodeWeight = NodeData<T>> + DataMapMut,
+        G: Data<NodeWeight = NodeData<T>> + DataMapMut + NodeIndexable,
         for<'a> &'a G: GraphBase<NodeId = G::NodeId, EdgeId = G::EdgeId> + IntoEdgesDirected,
         T: Node<G::EdgeWeight>,
         G::EdgeWeight: Clone,
     {
         process(self, graph, node)
     }
+
+    /// Process the given number of blocks through the subgraph ending at `node`, discarding the
+    /// output.
+    ///
+    /// This is useful for offline rendering, where the state of filters, delays and reverbs should
+    /// settle before the "real" render begins.
+    ///
+    /// Every node in the subgraph is processed exactly as it would be by `process`. This means
+    /// that input source nodes are advanced by `blocks * block_frames` frames. E.g. a node reading
+    /// from a file will consume that many frames of its input. If the render should begin from
+    /// the start of its input, rewind the sources following the preroll while leaving the state
+    /// of the remaining nodes intact.
+    ///
+    /// Upon returning, the buffers of `node` are silenced.
+    ///
+    /// **Panics** if there is no node for the given index.
+    pub fn preroll<T>(&mut self, graph: &mut G, node: G::NodeId, blocks: usize)
+    where
+        G: Data<NodeWeight = NodeData<T>> + DataMapMut + NodeIndexable,
+        for<'a> &'a G: GraphBase<NodeId = G::NodeId, EdgeId = G::EdgeId> + IntoEdgesDirected,
+        T: Node<G::EdgeWeight>,
+        G::EdgeWeight: Clone,
+    {
+        for _ in 0..blocks {
+            process(self, graph, node);
+        }
+        let data = graph.node_weight_mut(node).expect(NO_NODE);
+        for buffer in data.buffers.iter_mut() {
+            buffer.silence();
+        }
+    }
+
+    /// The number of frames that the subgraph ending at `node` may continue to produce output
+    /// after its sources become silent.
+    ///
+    /// This is useful for offline rendering, where rendering should continue for long enough to
+    /// capture the tails of any delays or reverbs. The tail of each path through the subgraph is
+    /// the sum of the `Node::tail_frames` of each node along the path, and the longest of these is
+    /// returned. Nodes that report no tail contribute `0` frames. Divide by `block_frames`, rounding
+    /// up, for the number of blocks.
+    ///
+    /// **Panics** if there is no node for the given index.
+    pub fn tail_frames<T>(&self, graph: &G, node: G::NodeId) -> usize
+    where
+        G: Data<NodeWeight = NodeData<T>> + DataMap + NodeIndexable,
+        for<'a> &'a G: GraphBase<NodeId = G::NodeId, EdgeId = G::EdgeId> + IntoEdgesDirected,
+        T: Node<G::EdgeWeight>,
+    {
+        tail_frames(graph, node)
+    }
+
+    /// Copy the most recently rendered buffers of `node` into `dst`, e.g. for writing the output
+    /// of the graph to the buffers of an audio host.
+    ///
+    /// This should be called after `process` or `process_prepared` and before the graph is next
+    /// processed. The buffers of each node are overwritten during each call to `process`, so
+    /// `copy_output` always yields the output of the most recent block.
+    ///
+    /// Each buffer of `dst` should have a length of `block_frames`, e.g. by constructing it once
+    /// via `Buffer::silent(processor.block_frames())`, so that copying never allocates. If a
+    /// buffer of `dst` differs in length, only the frames that fit are copied and any remaining
+    /// frames are silenced. If `dst` has fewer buffers than `node`, only those that fit are
+    /// copied. If `dst` has more, the remaining buffers are silenced.
+    ///
+    /// If `node` was not visited by the most recent call to `process` or `process_prepared`, its
+    /// buffers do not contain the output of the current block. In this case, all buffers of `dst`
+    /// are silenced and `0` is returned. Preparing or invalidating the order does not affect which
+    /// nodes were rendered, as their buffers are left untouched until the graph is next processed.
+    ///
+    /// Returns the number of channels that were copied.
+    ///
+    /// **Panics** if there is no node for the given index.
+    pub fn copy_output<T>(&self, graph: &G, node: G::NodeId, dst: &mut [Buffer]) -> usize
+    where
+        G: Data<NodeWeight = NodeData<T>> + DataMap,
+    {
+        let buffers = &graph.node_weight(node).expect(NO_NODE).buffers;
+        let copied = if self.rendered.contains(&node) {
+            dst.len().min(buffers.len())
+        } else {
+            0
+        };
+        for (dst_buf, src_buf) in dst.iter_mut().zip(&buffers[..copied]) {
+            debug_assert_eq!(
+                dst_buf.len(),
+                src_buf.len(),
+                "`dst` buffers should have a length of `block_frames`"
+            );
+            let frames = dst_buf.len().min(src_buf.len());
+            dst_buf[..frames].copy_from_slice(&src_buf[..frames]);
+            dst_buf[frames..].iter_mut().for_each(|s| *s = 0.0);
+        }
+        for dst_buf in dst.iter_mut().skip(copied) {
+            dst_buf.silence();
+        }
+        copied
+    }
+
+    /// Capture the state of every node within the graph.
+    ///
+    /// The state of each node is captured via `Node::save_state`, along with a copy of its
+    /// buffers, which hold the input to any feedback edges for the following block. The progress
+    /// of any crossfade scheduled via `crossfade_from` is also captured.
+    ///
+    /// Passing the snapshot to `restore` returns the graph to the captured state, such that
+    /// processing the graph again reproduces the same output, provided that every node with
+    /// internal state implements `Node::save_state` and `Node::load_state`. This allows for undo
+    /// and redo, and for seeking within deterministic offline renders.
+    ///
+    /// Capturing the state of each node generally allocates, so this should not be called on the
+    /// audio thread.
+    #[cfg(feature = "alloc")]
+    pub fn snapshot<T>(&self, graph: &G) -> Snapshot<G::NodeId>
+    where
+        G: Data<NodeWeight = NodeData<T>> + DataMap,
+        for<'a> &'a G: GraphBase<NodeId = G::NodeId> + IntoNodeIdentifiers,
+        T: Node<G::EdgeWeight>,
+    {
+        let nodes = graph
+            .node_identifiers()
+            .map(|node| {
+                let data = graph.node_weight(node).expect(NO_NODE);
+                NodeSnapshot {
+                    node,
+                    state: data.node.save_state(),
+                    buffers: data.buffers.clone(),
+                }
+            })
+            .collect();
+        Snapshot {
+            nodes,
+            crossfade: self.crossfade.clone(),
+        }
+    }
+
+    /// Return the graph to the state captured by `snapshot`.
+    ///
+    /// The state of each node is restored via `Node::load_state` and its buffers are replaced by
+    /// those captured. Nodes that have been removed from the graph since the snapshot was taken
+    /// are skipped, while nodes that have been added are left untouched. The same snapshot may be
+    /// restored any number of times.
+    ///
+    /// The prepared order is retained, so `process_prepared` may be called immediately after
+    /// restoring a snapshot of the same topology.
+    #[cfg(feature = "alloc")]
+    pub fn restore<T>(&mut self, graph: &mut G, snapshot: &Snapshot<G::NodeId>)
+    where
+        G: Data<NodeWeight = NodeData<T>> + DataMapMut,
+        T: Node<G::EdgeWeight>,
+    {
+        for node_snapshot in &snapshot.nodes {
+            let data = match graph.node_weight_mut(node_snapshot.node) {
+                Some(data) => data,
+                None => continue,
+            };
+            if let Some(ref state) = node_snapshot.state {
+                data.node.load_state(&**state);
+            }
+            data.buffers.clear();
+            data.buffers.extend_from_slice(&node_snapshot.buffers);
+        }
+        self.crossfade = snapshot.crossfade.clone();
+    }
+}
+
+/// Adapts a graph **Processor** to callbacks requesting an arbitrary number of frames.
+///
+/// Audio hosts rarely request frames in multiples of the block size. The **ProcessorAdapter**
+/// processes whole blocks through the graph as necessary to satisfy each request and retains any
+/// frames of the most recent block that were not yet requested. These remaining frames are
+/// yielded first upon the following request, so that the output is continuous across callbacks.
+///
+/// At most one block of frames is retained at a time, adding at most `Processor::block_frames`
+/// frames of latency.
+pub struct ProcessorAdapter<G>
+where
+    G: Data + Visitable,
+{
+    processor: Processor<G>,
+    // A copy of the output buffers of the most recently processed block.
+    block: Vec<Buffer>,
+    // The index of the next frame within `block` that has not yet been yielded.
+    frame: usize,
 }
 
 impl<T> NodeData<T> {
     /// Construct a new **NodeData** from an instance of its node type and buffers.
     pub fn new(node: T, buffers: Vec<Buffer>) -> Self {
-        NodeData { node, buffers }
+        NodeData {
+            node,
+            buffers,
+            feedback: false,
+        }
     }
 
     /// Creates a new **NodeData** with a single buffer.
@@ -265,8 +812,60 @@ impl<T> NodeData<T> {
     pub fn new2(node: T) -> Self {
         Self::new(node, vec![Buffer::SILENT; 2])
     }
+
+    /// Mark the outgoing edges of this node as **feedback** edges.
+    ///
+    /// See the `feedback` field for details.
+    pub fn with_feedback(mut self) -> Self {
+        self.feedback = true;
+        self
+    }
+}
+
+impl<N> GraphError<N> {
+    /// The node whose inputs do not match its expectations.
+    pub fn node(&self) -> N
+    where
+        N: Copy,
+    {
+        match *self {
+            GraphError::InputCount { node, .. } | GraphError::InputChannels { node, .. } => node,
+        }
+    }
+}
+
+impl<N> fmt::Display for GraphError<N>
+where
+    N: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GraphError::InputCount {
+                node,
+                expected,
+                found,
+            } => write!(
+                f,
+                "node {:?} expects {} inputs but has {}",
+                node, expected, found
+            ),
+            GraphError::InputChannels {
+                node,
+                input,
+                expected,
+                found,
+            } => write!(
+                f,
+                "node {:?} expects inputs with {} channels but input {:?} has {}",
+                node, expected, input, found
+            ),
+        }
+    }
 }
 
+#[cfg(feature = "std")]
+impl<N> std::error::Error for GraphError<N> where N: fmt::Debug {}
+
 #[cfg(feature = "node-boxed")]
 impl<I> NodeData<BoxedNode<I>> {
     /// The same as **new**, but boxes the given node data before storing it.
@@ -294,6 +893,98 @@ impl<I> NodeData<BoxedNode<I>> {
     }
 }
 
+impl<G> ProcessorAdapter<G>
+where
+    G: Data + Visitable,
+{
+    /// Wrap the given **Processor**, where `channels` is the anticipated number of buffers of the
+    /// output node.
+    ///
+    /// As long as the output node has no more than `channels` buffers, the **ProcessorAdapter**
+    /// should never require dynamic allocation following construction.
+    pub fn new(processor: Processor<G>, channels: usize) -> Self {
+        let frame = processor.block_frames;
+        ProcessorAdapter {
+            processor,
+            block: Vec::with_capacity(channels),
+            frame,
+        }
+    }
+
+    /// A reference to the inner **Processor**.
+    pub fn processor(&self) -> &Processor<G> {
+        &self.processor
+    }
+
+    /// A mutable reference to the inner **Processor**.
+    pub fn processor_mut(&mut self) -> &mut Processor<G> {
+        &mut self.processor
+    }
+
+    /// Consume the adapter, returning the inner **Processor**.
+    ///
+    /// Any retained frames are discarded.
+    pub fn into_processor(self) -> Processor<G> {
+        self.processor
+    }
+
+    /// The number of frames retained from the most recent block that will be yielded first upon
+    /// the next request.
+    pub fn buffered_frames(&self) -> usize {
+        self.processor.block_frames - self.frame
+    }
+
+    /// Discard any retained frames, so that the next request begins with a new block.
+    pub fn clear(&mut self) {
+        self.frame = self.processor.block_frames;
+    }
+
+    /// Fill `output` with interleaved frames from the subgraph ending at `node`.
+    ///
+    /// The number of channels is the number of buffers of `node`, and `output` is filled with
+    /// `output.len() / channels` frames. Retained frames from a previous request are written
+    /// first, after which blocks are processed via `Processor::process` as necessary.
+    ///
+    /// **Panics** if there is no node for the given index, or if the length of `output` is not a
+    /// multiple of the number of buffers of `node`.
+    pub fn process_interleaved<T>(&mut self, graph: &mut G, node: G::NodeId, output: &mut [f32])
+    where
+        G: Data<NodeWeight = NodeData<T>> + DataMapMut + NodeIndexable,
+        for<'a> &'a G: GraphBase<NodeId = G::NodeId, EdgeId = G::EdgeId> + IntoEdgesDirected,
+        T: Node<G::EdgeWeight>,
+        G::EdgeWeight: Clone,
+    {
+        let channels = graph.node_weight(node).expect(NO_NODE).buffers.len();
+        if channels == 0 {
+            return;
+        }
+        assert_eq!(
+            output.len() % channels,
+            0,
+            "the output length must be a multiple of the number of channels"
+        );
+        let block_frames = self.processor.block_frames;
+        if self.block.len() != channels {
+            self.block.resize(channels, Buffer::silent(block_frames));
+            self.frame = block_frames;
+        }
+        for out_frame in output.chunks_mut(channels) {
+            if self.frame == block_frames {
+                process(&mut self.processor, graph, node);
+                let buffers = &graph.node_weight(node).expect(NO_NODE).buffers;
+                for (block_buf, buf) in self.block.iter_mut().zip(buffers) {
+                    block_buf.copy_from_slice(buf);
+                }
+                self.frame = 0;
+            }
+            for (out, block_buf) in out_frame.iter_mut().zip(&self.block) {
+                *out = block_buf[self.frame];
+            }
+            self.frame += 1;
+        }
+    }
+}
+
 /// Process audio through the subgraph ending at the node with the given ID.
 ///
 /// Specifically, this traverses nodes in depth-first-search *post* order where the edges of
@@ -301,46 +992,388 @@ impl<I> NodeData<BoxedNode<I>> {
 /// connected to the inputs of the given `node`. This ensures that all inputs of each node are
 /// visited before the node itself.
 ///
+/// Edges from nodes marked as `NodeData::feedback` are not followed during traversal. Each
+/// feedback node is instead visited after the nodes that read its output, which receive its output
+/// from the previous block.
+///
 /// The `Node::process` method is called on each node as they are visited in the traversal.
 ///
 /// Upon returning, the buffers of each visited node will contain the audio processed by their
 /// respective nodes.
 ///
+/// If a crossfade has been scheduled via `Processor::crossfade_from`, the output of the old node
+/// is blended into the buffers of `node`.
+///
 /// Supports all graphs that implement the necessary petgraph traits and whose nodes are of
 /// type `NodeData<T>` where `T` implements the `Node` trait.
 ///
 /// **Panics** if there is no node for the given index.
 pub fn process<G, T>(processor: &mut Processor<G>, graph: &mut G, node: G::NodeId)
 where
-    G: Data<NodeWeight = NodeData<T>> + DataMapMut + Visitable,
+    G: Data<NodeWeight = NodeData<T>> + DataMapMut + NodeIndexable + Visitable,
     for<'a> &'a G: GraphBase<NodeId = G::NodeId, EdgeId = G::EdgeId> + IntoEdgesDirected,
     T: Node<G::EdgeWeight>,
     G::EdgeWeight: Clone,
 {
-    const NO_NODE: &str = "no node exists for the given index";
-    processor.dfs_post_order.reset(Reversed(&*graph));
-    processor.dfs_post_order.move_to(node);
-    while let Some(n) = processor.dfs_post_order.next(Reversed(&*graph)) {
-        let data: *mut NodeData<T> = graph.node_weight_mut(n).expect(NO_NODE) as *mut _;
-        processor.inputs.clear();
+    prepare(processor, graph, node);
+    process_prepared(processor, graph);
+}
+
+// Compute the visit order of the subgraph ending at `node` and store it within the processor.
+fn prepare<G, T>(processor: &mut Processor<G>, graph: &G, node: G::NodeId)
+where
+    G: Data<NodeWeight = NodeData<T>> + DataMap + Visitable,
+    for<'a> &'a G: GraphBase<NodeId = G::NodeId, EdgeId = G::EdgeId> + IntoEdgesDirected,
+{
+    processor.order.clear();
+    processor.dfs_post_order.reset(Reversed(graph));
+
+    // If crossfading, first visit the subgraph of the old output. Nodes visited here are not
+    // revisited while traversing the subgraph of the new output below.
+    let from = match processor.crossfade {
+        Some(ref crossfade) if crossfade.from != node => Some(crossfade.from),
+        _ => None,
+    };
+    if let Some(from) = from {
+        traverse(processor, graph, from);
+    }
+    traverse(processor, graph, node);
+
+    // Visit each feedback node, along with any of its inputs that have not yet been visited, after
+    // the nodes that read its output. This includes the inputs of feedback nodes appended here.
+    let mut ix = 0;
+    while ix < processor.order.len() {
+        let n = processor.order[ix];
         for edge_ref in graph.edges_directed(n, Incoming) {
-            // Skip edges that connect the node to itself to avoid aliasing `node`.
-            if n == edge_ref.source() {
+            let source = edge_ref.source();
+            if is_feedback(graph, source)
+                && !processor.dfs_post_order.discovered.is_visited(&source)
+            {
+                traverse(processor, graph, source);
+            }
+        }
+        ix += 1;
+    }
+
+    // Allocate the copies of the output of feedback nodes that read their own output, so that they
+    // are not allocated while processing.
+    for &n in &processor.order {
+        let data = graph.node_weight(n).expect(NO_NODE);
+        let reads_self = graph.edges_directed(n, Incoming).any(|e| e.source() == n);
+        if data.feedback && reads_self && processor.self_feedback.len() < data.buffers.len() {
+            let buffer = Buffer::silent(processor.block_frames);
+            processor.self_feedback.resize(data.buffers.len(), buffer);
+        }
+    }
+    processor.prepared = Some(Prepared { node, from });
+}
+
+// Append the nodes of the subgraph ending at `start` that have not yet been visited to the order
+// of the processor in depth-first-search post order, without following edges from feedback nodes.
+fn traverse<G, T>(processor: &mut Processor<G>, graph: &G, start: G::NodeId)
+where
+    G: Data<NodeWeight = NodeData<T>> + DataMap + Visitable,
+    for<'a> &'a G: GraphBase<NodeId = G::NodeId, EdgeId = G::EdgeId> + IntoEdgesDirected,
+{
+    let dfs = &mut processor.dfs_post_order;
+    dfs.stack.clear();
+    dfs.stack.push(start);
+    while let Some(&n) = dfs.stack.last() {
+        if dfs.discovered.visit(n) {
+            for edge_ref in graph.edges_directed(n, Incoming) {
+                let source = edge_ref.source();
+                if !is_feedback(graph, source) && !dfs.discovered.is_visited(&source) {
+                    dfs.stack.push(source);
+                }
+            }
+        } else {
+            dfs.stack.pop();
+            if dfs.finished.visit(n) {
+                processor.order.push(n);
+            }
+        }
+    }
+}
+
+// Whether or not the outgoing edges of the node `n` are feedback edges.
+fn is_feedback<G, T>(graph: &G, n: G::NodeId) -> bool
+where
+    G: Data<NodeWeight = NodeData<T>> + DataMap,
+{
+    graph.node_weight(n).expect(NO_NODE).feedback
+}
+
+// Process each node in the prepared order, then apply any crossfade in progress.
+fn process_prepared<G, T>(processor: &mut Processor<G>, graph: &mut G)
+where
+    G: Data<NodeWeight = NodeData<T>> + DataMapMut + NodeIndexable + Visitable,
+    for<'a> &'a G: GraphBase<NodeId = G::NodeId, EdgeId = G::EdgeId> + IntoEdgesDirected,
+    T: Node<G::EdgeWeight>,
+    G::EdgeWeight: Clone,
+{
+    let (node, from) = match processor.prepared {
+        Some(ref prepared) => (prepared.node, prepared.from),
+        None => panic!("no order has been prepared via `Processor::prepare`"),
+    };
+    // Size the buffers of every node before processing, as feedback nodes are read before they
+    // are processed.
+    for &n in &processor.order {
+        let data = graph.node_weight_mut(n).expect(NO_NODE);
+        for buffer in data.buffers.iter_mut() {
+            buffer.set_len(processor.block_frames);
+        }
+    }
+    for ix in 0..processor.order.len() {
+        let n = processor.order[ix];
+        visit(processor, graph, n);
+    }
+    processor.rendered.clear();
+    processor.rendered.extend_from_slice(&processor.order);
+
+    // Blend the output of the old subgraph into the output of the new.
+    if let Some(crossfade) = processor.crossfade.as_mut() {
+        if let Some(from) = from {
+            blend(graph, crossfade, &mut processor.crossfade_buf, from, node);
+        }
+        crossfade.elapsed += processor.block_frames;
+        if crossfade.elapsed >= crossfade.frames {
+            processor.crossfade = None;
+        }
+    }
+}
+
+const NO_NODE: &str = "no node exists for the given index";
+
+/// The number of frames that the subgraph ending at `node` may continue to produce output after
+/// its sources become silent.
+///
+/// See `Processor::tail_frames` for details.
+///
+/// **Panics** if there is no node for the given index.
+pub fn tail_frames<G, T>(graph: &G, node: G::NodeId) -> usize
+where
+    G: Data<NodeWeight = NodeData<T>> + DataMap + NodeIndexable + Visitable,
+    for<'a> &'a G: GraphBase<NodeId = G::NodeId, EdgeId = G::EdgeId> + IntoEdgesDirected,
+    T: Node<G::EdgeWeight>,
+{
+    // The longest tail of any path ending at each node, indexed by node index.
+    let mut tails = vec![0; graph.node_bound()];
+    let mut dfs_post_order = DfsPostOrder::new(Reversed(graph), node);
+    while let Some(n) = dfs_post_order.next(Reversed(graph)) {
+        let input_tail = graph
+            .edges_directed(n, Incoming)
+            .filter(|edge_ref| edge_ref.source() != n)
+            .map(|edge_ref| tails[graph.to_index(edge_ref.source())])
+            .max()
+            .unwrap_or(0);
+        let data = graph.node_weight(n).expect(NO_NODE);
+        tails[graph.to_index(n)] = input_tail + data.node.tail_frames().unwrap_or(0);
+    }
+    tails[graph.to_index(node)]
+}
+
+/// Check that the inputs of each node within the graph match those expected by the node.
+///
+/// For each node that declares `Node::expected_inputs`, the number of inputs that the node
+/// receives during processing is compared to the expected number. For each node that declares
+/// `Node::expected_input_channels`, the number of buffers of each of its input nodes is compared to
+/// the expected number. Nodes that declare neither are never flagged, so validation is opt-in on a
+/// per-node basis.
+///
+/// Inputs are counted as they are during processing, i.e. an edge from a node to itself is only
+/// counted if the node is a feedback node.
+///
+/// This is useful for catching misconfigured graphs, which would otherwise produce silent or
+/// incorrect output, before rendering begins.
+///
+/// Returns every mismatch found within the graph.
+pub fn validate<G, T>(graph: &G) -> Result<(), Vec<GraphError<G::NodeId>>>
+where
+    G: Data<NodeWeight = NodeData<T>> + DataMap,
+    for<'a> &'a G:
+        GraphBase<NodeId = G::NodeId, EdgeId = G::EdgeId> + IntoEdgesDirected + IntoNodeIdentifiers,
+    T: Node<G::EdgeWeight>,
+{
+    let mut errors = Vec::new();
+    for n in graph.node_identifiers() {
+        let data = graph.node_weight(n).expect(NO_NODE);
+        let expected_inputs = data.node.expected_inputs();
+        let expected_channels = data.node.expected_input_channels();
+        if expected_inputs.is_none() && expected_channels.is_none() {
+            continue;
+        }
+        let mut inputs = 0;
+        for edge_ref in graph.edges_directed(n, Incoming) {
+            let input = edge_ref.source();
+            let input_data = graph.node_weight(input).expect(NO_NODE);
+            if input == n && !input_data.feedback {
                 continue;
             }
-            let input_container = graph.node_weight(edge_ref.source()).expect(NO_NODE);
-            let input_target = graph.edge_weight(edge_ref.id()).expect(NO_NODE);
-            let input = node::Input::new(&input_container.buffers, (*input_target).clone());
-            processor.inputs.push(input);
+            inputs += 1;
+            match expected_channels {
+                Some(expected) if input_data.buffers.len() != expected => {
+                    errors.push(GraphError::InputChannels {
+                        node: n,
+                        input,
+                        expected,
+                        found: input_data.buffers.len(),
+                    });
+                }
+                _ => (),
+            }
+        }
+        match expected_inputs {
+            Some(expected) if inputs != expected => {
+                errors.push(GraphError::InputCount {
+                    node: n,
+                    expected,
+                    found: inputs,
+                });
+            }
+            _ => (),
+        }
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Process the given signal forward and then backward through a copy of `node`, cancelling any
+/// phase distortion introduced by the node.
+///
+/// This is useful for offline zero-phase filtering, also known as forward-backward filtering or
+/// "filtfilt". The signal is processed through a clone of `node` in blocks of `block_frames`
+/// frames, reversed, processed through another clone of `node` and reversed again. As each pass
+/// begins from the state of `node`, the result is the same as a single pass through a filter with
+/// the squared magnitude response of `node` and no phase shift, i.e. a symmetric impulse response.
+///
+/// Each element of `channels` is a single channel of the signal, which is replaced by the
+/// processed signal. The node is provided with one input containing a buffer for each channel, and
+/// one output buffer for each channel.
+///
+/// The processed signal has the same length as the input. Any tail produced by the node beyond
+/// either end of the signal is discarded, so pad the signal with silence on both ends in order to
+/// capture the complete response.
+///
+/// **Panics** if the channels differ in length or if `block_frames` is `0`.
+pub fn process_zero_phase<T>(node: &T, channels: &mut [Vec<f32>], block_frames: usize)
+where
+    T: Node + Clone,
+{
+    assert!(block_frames > 0, "`block_frames` must be greater than `0`");
+    let len = channels.first().map(|ch| ch.len()).unwrap_or(0);
+    assert!(
+        channels.iter().all(|ch| ch.len() == len),
+        "all channels must have the same length"
+    );
+    let mut in_bufs = vec![Buffer::silent(block_frames); channels.len()];
+    let mut out_bufs = vec![Buffer::silent(block_frames); channels.len()];
+    for _ in 0..2 {
+        let mut node = node.clone();
+        for start in (0..len).step_by(block_frames) {
+            let end = (start + block_frames).min(len);
+            for (in_buf, ch) in in_bufs.iter_mut().zip(channels.iter()) {
+                in_buf.silence();
+                in_buf[..end - start].copy_from_slice(&ch[start..end]);
+            }
+            node.process(&[Input::new(&in_bufs, ())], &mut out_bufs);
+            for (ch, out_buf) in channels.iter_mut().zip(&out_bufs) {
+                ch[start..end].copy_from_slice(&out_buf[..end - start]);
+            }
+        }
+        channels.iter_mut().for_each(|ch| ch.reverse());
+    }
+}
+
+// Process the node `n`, collecting the outputs of its input nodes via its incoming edges.
+fn visit<G, T>(processor: &mut Processor<G>, graph: &mut G, n: G::NodeId)
+where
+    G: Data<NodeWeight = NodeData<T>> + DataMapMut + NodeIndexable + Visitable,
+    for<'a> &'a G: GraphBase<NodeId = G::NodeId, EdgeId = G::EdgeId> + IntoEdgesDirected,
+    T: Node<G::EdgeWeight>,
+    G::EdgeWeight: Clone,
+{
+    let data: *mut NodeData<T> = graph.node_weight_mut(n).expect(NO_NODE) as *mut _;
+    processor.inputs.clear();
+    processor.input_sources.clear();
+    let mut self_feedback_copied = false;
+    for edge_ref in graph.edges_directed(n, Incoming) {
+        let input_container = graph.node_weight(edge_ref.source()).expect(NO_NODE);
+        let input_target = graph.edge_weight(edge_ref.id()).expect(NO_NODE);
+        let input = if n == edge_ref.source() {
+            // Skip edges that connect the node to itself to avoid aliasing `node`, unless it is a
+            // feedback node, in which case it reads a copy of its output from the previous block.
+            if !input_container.feedback {
+                continue;
+            }
+            let channels = input_container.buffers.len();
+            if !self_feedback_copied {
+                if processor.self_feedback.len() < channels {
+                    processor.self_feedback.resize(channels, Buffer::SILENT);
+                }
+                let copies = processor.self_feedback.iter_mut();
+                for (copy, buffer) in copies.zip(&input_container.buffers) {
+                    copy.set_len(buffer.len());
+                    copy.copy_from_slice(buffer);
+                }
+                self_feedback_copied = true;
+            }
+            let copies = &processor.self_feedback[..channels];
+            node::Input::new(copies, (*input_target).clone())
+        } else {
+            node::Input::new(&input_container.buffers, (*input_target).clone())
+        };
+        // Insert the input in order of its source node's index.
+        let source = graph.to_index(edge_ref.source());
+        let ix = processor.input_sources.partition_point(|&s| s <= source);
+        processor.inputs.insert(ix, input);
+        processor.input_sources.insert(ix, source);
+    }
+    for (ix, input) in processor.inputs.iter_mut().enumerate() {
+        input.index = ix;
+    }
+    // Here we deference our raw pointer to the `NodeData`. The only references to the graph at
+    // this point in time are the input references and the node itself. We know that the input
+    // references do not alias our node's mutable reference as we explicitly check for it while
+    // looping through the inputs above, copying the buffers of feedback nodes that read their own
+    // output.
+    unsafe {
+        (*data)
+            .node
+            .process(&processor.inputs, &mut (*data).buffers);
+        #[cfg(feature = "ftz")]
+        if processor.flush_denormals {
+            (*data).buffers.iter_mut().for_each(Buffer::flush_denormals);
+        }
+    }
+}
+
+// Blend the buffers of `from` into those of `to` according to the progress of the crossfade.
+//
+// Channels of `to` for which `from` has no buffer are faded in from silence.
+fn blend<G, T>(
+    graph: &mut G,
+    crossfade: &Crossfade<G::NodeId>,
+    from_buf: &mut Buffer,
+    from: G::NodeId,
+    to: G::NodeId,
+) where
+    G: Data<NodeWeight = NodeData<T>> + DataMapMut,
+{
+    let channels = graph.node_weight(to).expect(NO_NODE).buffers.len();
+    for ch in 0..channels {
+        let to_len = graph.node_weight(to).expect(NO_NODE).buffers[ch].len();
+        from_buf.set_len(to_len);
+        match graph.node_weight(from).expect(NO_NODE).buffers.get(ch) {
+            Some(buf) => from_buf.copy_from_slice(buf),
+            None => from_buf.silence(),
         }
-        // Here we deference our raw pointer to the `NodeData`. The only references to the graph at
-        // this point in time are the input references and the node itself. We know that the input
-        // references do not alias our node's mutable reference as we explicitly check for it while
-        // looping through the inputs above.
-        unsafe {
-            (*data)
-                .node
-                .process(&processor.inputs, &mut (*data).buffers);
+        let to_buf = &mut graph.node_weight_mut(to).expect(NO_NODE).buffers[ch];
+        for (ix, (out, &old)) in to_buf.iter_mut().zip(from_buf.iter()).enumerate() {
+            let progress = (crossfade.elapsed + ix) as f32 / crossfade.frames as f32;
+            *out = old + (*out - old) * progress.min(1.0);
         }
     }
 }
@@ -0,0 +1,130 @@
+use super::block_frames;
+use crate::{Buffer, Input, Node};
+
+/// A leveler, or automatic gain control (AGC), that slowly adjusts its gain to keep the long-term
+/// RMS level of its input near a target level, e.g. to even out the levels of speakers in a
+/// podcast.
+///
+/// The level is detected as the mean square across all input channels, averaged over a time
+/// constant of `rms_frames`, so that the same gain is applied to every channel. The gain required
+/// to bring this level to `target_db` is approached slowly, over `rise_frames` when the gain is
+/// increasing and `fall_frames` when it is decreasing. A shorter `fall_frames` allows the AGC to
+/// quickly back off from sudden loud passages.
+///
+/// Unlike a compressor, an AGC will amplify quiet input. To avoid raising the noise floor during
+/// pauses, the gain is held at its current value while the detected level is below `gate_db`.
+/// `gate_db` should therefore sit between the noise floor and the quietest wanted content. The
+/// gain is additionally never raised above `max_gain_db`, limiting how far quiet content and the
+/// noise within it may be amplified. Content quieter than `target_db - max_gain_db` will not
+/// reach the target level.
+///
+/// Assumes that there is one input node.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Agc {
+    /// The long-term RMS level in dBFS towards which the output is levelled.
+    pub target_db: f32,
+    /// The maximum gain in dB that may be applied.
+    pub max_gain_db: f32,
+    /// The level in dBFS below which the input is considered noise and the gain is held.
+    pub gate_db: f32,
+    /// The time constant in frames over which the RMS level is detected.
+    pub rms_frames: f32,
+    /// The time in frames for the gain to move towards a greater gain.
+    pub rise_frames: f32,
+    /// The time in frames for the gain to move towards a lesser gain.
+    pub fall_frames: f32,
+    mean_square: f32,
+    gain_db: f32,
+}
+
+impl Agc {
+    /// The default value for `max_gain_db`.
+    pub const DEFAULT_MAX_GAIN_DB: f32 = 24.0;
+    /// The default value for `gate_db`.
+    pub const DEFAULT_GATE_DB: f32 = -50.0;
+    /// The default value for `rms_frames`, roughly 400ms at 44.1kHz.
+    pub const DEFAULT_RMS_FRAMES: f32 = 17_640.0;
+    /// The default value for `rise_frames`, roughly 3s at 44.1kHz.
+    pub const DEFAULT_RISE_FRAMES: f32 = 132_300.0;
+    /// The default value for `fall_frames`, roughly 500ms at 44.1kHz.
+    pub const DEFAULT_FALL_FRAMES: f32 = 22_050.0;
+
+    /// Create a new **Agc** with the given target RMS level in dBFS.
+    pub fn new(target_db: f32) -> Self {
+        Agc {
+            target_db,
+            max_gain_db: Self::DEFAULT_MAX_GAIN_DB,
+            gate_db: Self::DEFAULT_GATE_DB,
+            rms_frames: Self::DEFAULT_RMS_FRAMES,
+            rise_frames: Self::DEFAULT_RISE_FRAMES,
+            fall_frames: Self::DEFAULT_FALL_FRAMES,
+            mean_square: 0.0,
+            gain_db: 0.0,
+        }
+    }
+
+    /// The current smoothed gain in dB.
+    pub fn current_gain_db(&self) -> f32 {
+        self.gain_db
+    }
+
+    /// The current detected RMS level of the input in dBFS.
+    pub fn rms_db(&self) -> f32 {
+        10.0 * self.mean_square.max(1e-20).log10()
+    }
+
+    /// Reset the detected level and the gain to `0.0`.
+    pub fn reset(&mut self) {
+        self.mean_square = 0.0;
+        self.gain_db = 0.0;
+    }
+}
+
+impl Node for Agc {
+    fn process(&mut self, inputs: &[Input], output: &mut [Buffer]) {
+        let input = match inputs.first() {
+            Some(input) => input,
+            None => {
+                output.iter_mut().for_each(|out_buf| out_buf.silence());
+                return;
+            }
+        };
+        let in_bufs = input.buffers();
+        let detect = smoothing_coefficient(self.rms_frames);
+        let rise = smoothing_coefficient(self.rise_frames);
+        let fall = smoothing_coefficient(self.fall_frames);
+        let channels = in_bufs.len().max(1) as f32;
+        for ix in 0..block_frames(output) {
+            let square = in_bufs.iter().map(|buf| buf[ix] * buf[ix]).sum::<f32>() / channels;
+            self.mean_square = square + detect * (self.mean_square - square);
+            let level_db = self.rms_db();
+            if level_db >= self.gate_db {
+                let target = (self.target_db - level_db).min(self.max_gain_db);
+                let coef = if target > self.gain_db { rise } else { fall };
+                self.gain_db = target + coef * (self.gain_db - target);
+            }
+            let gain = db_to_linear(self.gain_db);
+            for (out_buf, in_buf) in output.iter_mut().zip(in_bufs) {
+                out_buf[ix] = in_buf[ix] * gain;
+            }
+        }
+        for out_buf in output.iter_mut().skip(in_bufs.len()) {
+            out_buf.silence();
+        }
+    }
+
+    clone_state!();
+}
+
+// The one-pole smoothing coefficient for the given time constant in frames.
+fn smoothing_coefficient(frames: f32) -> f32 {
+    if frames <= 0.0 {
+        0.0
+    } else {
+        (-1.0 / frames).exp()
+    }
+}
+
+fn db_to_linear(db: f32) -> f32 {
+    10f32.powf(db / 20.0)
+}
@@ -0,0 +1,207 @@
+//! An experimental bridge between asynchronous audio sources and the synchronous `Node` trait.
+//!
+//! Sources backed by network or disk streaming cannot always produce a block of audio on demand.
+//! Rather than blocking within the audio thread, such sources may implement the
+//! [`AsyncNode`](./trait.AsyncNode.html) trait and be wrapped in an
+//! [`AsyncSource`](./struct.AsyncSource.html). The `AsyncSource` polls the inner node to keep an
+//! internal queue of blocks topped up and yields a block from the front of the queue each time
+//! the graph is processed.
+
+use crate::{Buffer, Input, Node, Vec};
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+/// An audio source whose blocks of audio may not be immediately available.
+///
+/// This is the asynchronous counterpart to the [`Node`](./trait.Node.html) trait for source nodes.
+/// Rather than requiring that a block is written immediately, `poll_process` may return
+/// `Poll::Pending` to indicate that the next block is not yet ready. In this case, the
+/// implementation should arrange for the waker within `cx` to be woken once it is.
+pub trait AsyncNode {
+    /// Attempt to write the next block of audio to the `output` buffers.
+    ///
+    /// Returns `Poll::Ready(())` once the `output` buffers contain the next block. If
+    /// `Poll::Pending` is returned, the contents of `output` are unspecified and the same block
+    /// will be requested again upon the next call.
+    fn poll_process(&mut self, cx: &mut Context, output: &mut [Buffer]) -> Poll<()>;
+}
+
+/// Wraps an [`AsyncNode`](./trait.AsyncNode.html) with an internal queue of blocks in order to
+/// provide a synchronous `Node` implementation.
+///
+/// Each call to `Node::process` first polls the inner node until either the queue is full or the
+/// inner node returns `Poll::Pending`. The block at the front of the queue is then written to the
+/// output. If the queue is empty, silence is written instead and the underrun is counted. Blocks
+/// longer than the output buffers are truncated, while shorter blocks are padded with silence.
+///
+/// The queue is allocated once during construction. Use [`prefill`](#method.prefill) to await a
+/// full queue before handing the node to the audio thread.
+pub struct AsyncSource<N> {
+    node: N,
+    blocks: Vec<Vec<Buffer>>,
+    start: usize,
+    len: usize,
+    underruns: usize,
+}
+
+/// A future that resolves once the queue of an **AsyncSource** is full.
+///
+/// Returned by the `AsyncSource::prefill` method.
+pub struct Prefill<'a, N> {
+    source: &'a mut AsyncSource<N>,
+}
+
+impl<N> AsyncSource<N> {
+    /// Wrap the given async `node`, producing `channels` buffers per block with room for
+    /// `capacity` queued blocks.
+    ///
+    /// Each block contains `Buffer::LEN` frames. See `with_block_frames` for other block sizes.
+    ///
+    /// **Panics** if `capacity` is `0`.
+    pub fn new(node: N, channels: usize, capacity: usize) -> Self {
+        Self::with_block_frames(node, channels, capacity, Buffer::LEN)
+    }
+
+    /// The same as `new`, but with blocks of `block_frames` frames, matching a **Processor**
+    /// constructed via `Processor::with_block_frames`.
+    ///
+    /// **Panics** if `capacity` is `0`.
+    pub fn with_block_frames(
+        node: N,
+        channels: usize,
+        capacity: usize,
+        block_frames: usize,
+    ) -> Self {
+        assert!(
+            capacity > 0,
+            "an `AsyncSource` requires a capacity of at least one block"
+        );
+        let blocks = vec![vec![Buffer::silent(block_frames); channels]; capacity];
+        AsyncSource {
+            node,
+            blocks,
+            start: 0,
+            len: 0,
+            underruns: 0,
+        }
+    }
+
+    /// The number of blocks that are currently queued.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether or not the queue is currently empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The maximum number of blocks that may be queued.
+    pub fn capacity(&self) -> usize {
+        self.blocks.len()
+    }
+
+    /// The number of times `process` was called while the queue was empty.
+    pub fn underruns(&self) -> usize {
+        self.underruns
+    }
+
+    /// A reference to the inner async node.
+    pub fn node(&self) -> &N {
+        &self.node
+    }
+
+    /// A mutable reference to the inner async node.
+    pub fn node_mut(&mut self) -> &mut N {
+        &mut self.node
+    }
+
+    /// Consume the **AsyncSource** and return the inner async node.
+    ///
+    /// Any queued blocks are discarded.
+    pub fn into_inner(self) -> N {
+        self.node
+    }
+
+    /// Produces a future that polls the inner node until the queue is full.
+    pub fn prefill(&mut self) -> Prefill<'_, N> {
+        Prefill { source: self }
+    }
+}
+
+impl<N> AsyncSource<N>
+where
+    N: AsyncNode,
+{
+    /// Poll the inner node until either the queue is full or the inner node is pending.
+    ///
+    /// Returns `Poll::Ready(())` if the queue is full.
+    pub fn poll_fill(&mut self, cx: &mut Context) -> Poll<()> {
+        let capacity = self.blocks.len();
+        while self.len < capacity {
+            let ix = (self.start + self.len) % capacity;
+            match self.node.poll_process(cx, &mut self.blocks[ix]) {
+                Poll::Ready(()) => self.len += 1,
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+        Poll::Ready(())
+    }
+}
+
+impl<N> Node for AsyncSource<N>
+where
+    N: AsyncNode,
+{
+    fn process(&mut self, _inputs: &[Input], output: &mut [Buffer]) {
+        // The audio thread is driven by the audio callback rather than by a waker, so we poll with
+        // a waker that does nothing.
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        let _ = self.poll_fill(&mut cx);
+
+        if self.len == 0 {
+            for out_buf in output.iter_mut() {
+                out_buf.silence();
+            }
+            self.underruns += 1;
+            return;
+        }
+
+        let block = &self.blocks[self.start];
+        for (out_buf, block_buf) in output.iter_mut().zip(block) {
+            // The block size may differ from that of the processor, in which case any output
+            // frames beyond the end of the block are silenced.
+            let frames = out_buf.len().min(block_buf.len());
+            out_buf[..frames].copy_from_slice(&block_buf[..frames]);
+            out_buf[frames..].iter_mut().for_each(|s| *s = 0.0);
+        }
+        for out_buf in output.iter_mut().skip(block.len()) {
+            out_buf.silence();
+        }
+        self.start = (self.start + 1) % self.blocks.len();
+        self.len -= 1;
+    }
+}
+
+impl<'a, N> Future for Prefill<'a, N>
+where
+    N: AsyncNode,
+{
+    type Output = ();
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        self.source.poll_fill(cx)
+    }
+}
+
+// A `Waker` that does nothing when woken.
+fn noop_waker() -> Waker {
+    const VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+    fn clone(_: *const ()) -> RawWaker {
+        RawWaker::new(core::ptr::null(), &VTABLE)
+    }
+    fn noop(_: *const ()) {}
+    // Safe, as none of the vtable functions dereference the data pointer.
+    unsafe { Waker::from_raw(RawWaker::new(core::ptr::null(), &VTABLE)) }
+}
@@ -0,0 +1,77 @@
+use crate::{Buffer, Input, Node};
+
+/// A stereo balance control.
+///
+/// The balance position is in the range `-1.0..=1.0`. At the center position of `0.0` both
+/// channels pass through at unity gain. Moving the position towards `1.0` linearly attenuates the
+/// left channel, until it is silent at full-right balance. Likewise, moving towards `-1.0`
+/// attenuates the right channel. The favoured channel is always left untouched.
+///
+/// This differs from a pan control, which positions a source within the stereo field by
+/// distributing it between both channels. A balance control never folds one channel into the
+/// other. Rather, it only adjusts the relative level of the two existing channels. Use a balance
+/// control for stereo material that should retain its original image.
+///
+/// Assumes that there is one input node with two channels. Any further channels are passed
+/// through unchanged.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Balance {
+    position: f32,
+}
+
+impl Balance {
+    /// Create a new **Balance** node with the given balance position.
+    ///
+    /// The position is clamped to the range `-1.0..=1.0`.
+    pub fn new(position: f32) -> Self {
+        Balance {
+            position: position.clamp(-1.0, 1.0),
+        }
+    }
+
+    /// The current balance position.
+    pub fn position(&self) -> f32 {
+        self.position
+    }
+
+    /// Set the balance position, clamped to the range `-1.0..=1.0`.
+    pub fn set_position(&mut self, position: f32) {
+        self.position = position.clamp(-1.0, 1.0);
+    }
+
+    /// The gain applied to the left and right channels respectively for the current position.
+    pub fn gains(&self) -> [f32; 2] {
+        let left = 1.0 - self.position.max(0.0);
+        let right = 1.0 + self.position.min(0.0);
+        [left, right]
+    }
+}
+
+impl Default for Balance {
+    fn default() -> Self {
+        Balance::new(0.0)
+    }
+}
+
+impl Node for Balance {
+    fn process(&mut self, inputs: &[Input], output: &mut [Buffer]) {
+        let input = match inputs.first() {
+            Some(input) => input,
+            None => {
+                output.iter_mut().for_each(|out_buf| out_buf.silence());
+                return;
+            }
+        };
+        let in_bufs = input.buffers();
+        let gains = self.gains();
+        let gains = gains.iter().cloned().chain(core::iter::repeat(1.0));
+        for ((out_buf, in_buf), gain) in output.iter_mut().zip(in_bufs).zip(gains) {
+            for (out, &sample) in out_buf.iter_mut().zip(in_buf.iter()) {
+                *out = sample * gain;
+            }
+        }
+        for out_buf in output.iter_mut().skip(in_bufs.len()) {
+            out_buf.silence();
+        }
+    }
+}
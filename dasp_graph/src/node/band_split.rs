@@ -0,0 +1,167 @@
+use super::crossover::Lr4;
+use super::Crossover;
+use crate::{Buffer, Input, Node, Vec};
+
+/// Splits a signal into multiple Linkwitz-Riley frequency bands for multiband processing.
+///
+/// The bands are identical to those of a **Crossover** with the same frequencies. The first
+/// channel of the input is split into one more band than there are crossover frequencies, ordered
+/// from lowest to highest, and each band is written to its own output buffer.
+///
+/// While the bands sum to a flat magnitude response, each Linkwitz-Riley split shifts the phase of
+/// the sum like an all-pass filter. Use the **BandMerge** returned by `BandSplit::merge` to sum
+/// the bands while undoing this phase shift.
+///
+/// Assumes that there is one input node. Output buffers beyond the number of bands are silenced.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BandSplit {
+    crossover: Crossover,
+}
+
+/// Sums the bands produced by a **BandSplit**, compensating for the phase shift of the
+/// Linkwitz-Riley splits so that splitting then merging reconstructs the input.
+///
+/// The combined phase shift of the splits is equivalent to an all-pass filter. Its inverse is
+/// approximated by convolving the sum of the bands with the time-reversed impulse response of the
+/// all-pass, truncated once it has decayed below audibility. The output is therefore a delayed
+/// copy of the input to the **BandSplit**, accurate to within rounding error, where the delay is
+/// given by `latency`. As the impulse response rings for longer at lower frequencies, the latency
+/// and the cost per frame grow as the lowest crossover frequency decreases, e.g. from roughly 200
+/// frames for a 1kHz crossover to roughly 1700 frames for a 100Hz crossover at 48kHz.
+///
+/// Every buffer of every input is summed, allowing each band to be processed by a separate node
+/// before merging. The result is written to the first output buffer and any remaining output
+/// buffers are silenced.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BandMerge {
+    // The time-reversed impulse response of the combined all-pass of the splits.
+    taps: Vec<f32>,
+    // The most recent sums of the bands, stored circularly.
+    history: Vec<f32>,
+    // The index of the most recent sum within the history.
+    head: usize,
+}
+
+impl BandSplit {
+    /// Create a new **BandSplit** with the given sample rate and crossover frequencies in Hz.
+    ///
+    /// The frequencies are sorted in ascending order. The number of bands is always one more than
+    /// the number of frequencies.
+    pub fn new(sample_rate: f64, frequencies: Vec<f64>) -> Self {
+        BandSplit {
+            crossover: Crossover::new(sample_rate, frequencies),
+        }
+    }
+
+    /// Create a **BandMerge** that compensates for the phase shift of this **BandSplit**.
+    pub fn merge(&self) -> BandMerge {
+        BandMerge::new(self.sample_rate(), self.frequencies())
+    }
+
+    /// The crossover frequencies in Hz, in ascending order.
+    pub fn frequencies(&self) -> &[f64] {
+        self.crossover.frequencies()
+    }
+
+    /// The sample rate in Hz.
+    pub fn sample_rate(&self) -> f64 {
+        self.crossover.sample_rate()
+    }
+
+    /// The number of bands produced by the split.
+    pub fn bands(&self) -> usize {
+        self.crossover.bands()
+    }
+
+    /// Reset the state of all filters to silence.
+    pub fn reset(&mut self) {
+        self.crossover.reset();
+    }
+}
+
+impl BandMerge {
+    /// The threshold below which the decaying all-pass impulse response is truncated.
+    const THRESHOLD: f64 = 1e-8;
+
+    /// Create a new **BandMerge** that compensates for a **BandSplit** with the given sample rate
+    /// and crossover frequencies in Hz.
+    ///
+    /// The impulse response is measured for at most one second, bounding the latency for very low
+    /// crossover frequencies at the cost of accuracy.
+    pub fn new(sample_rate: f64, frequencies: &[f64]) -> Self {
+        let mut allpasses: Vec<_> = frequencies
+            .iter()
+            .map(|&hz| Lr4::new(sample_rate, hz))
+            .collect();
+        let mut ir: Vec<f64> = (0..sample_rate.max(1.0) as usize)
+            .map(|i| {
+                let impulse = if i == 0 { 1.0 } else { 0.0 };
+                allpasses.iter_mut().fold(impulse, |s, ap| {
+                    let (l, h) = ap.split(s);
+                    l + h
+                })
+            })
+            .collect();
+        let len = ir
+            .iter()
+            .rposition(|s| s.abs() > Self::THRESHOLD)
+            .map_or(1, |ix| ix + 1);
+        ir.truncate(len);
+        let taps: Vec<f32> = ir.iter().rev().map(|&s| s as f32).collect();
+        BandMerge {
+            history: vec![0.0; taps.len()],
+            taps,
+            head: 0,
+        }
+    }
+
+    /// The delay in frames between the input to the **BandSplit** and the output of the merge.
+    pub fn latency(&self) -> usize {
+        self.taps.len() - 1
+    }
+
+    /// Clear the history of the compensation filter.
+    pub fn reset(&mut self) {
+        self.history.iter_mut().for_each(|s| *s = 0.0);
+    }
+}
+
+impl Node for BandSplit {
+    fn process(&mut self, inputs: &[Input], output: &mut [Buffer]) {
+        self.crossover.process(inputs, output)
+    }
+
+    clone_state!();
+}
+
+impl Node for BandMerge {
+    fn process(&mut self, inputs: &[Input], output: &mut [Buffer]) {
+        let out_buf = match output.first_mut() {
+            Some(out_buf) => out_buf,
+            None => return,
+        };
+        let len = self.history.len();
+        for ix in 0..out_buf.len() {
+            let sum = inputs
+                .iter()
+                .flat_map(|input| input.buffers())
+                .map(|buf| buf[ix])
+                .sum();
+            self.head = (self.head + 1) % len;
+            self.history[self.head] = sum;
+            // The history is stored circularly, where `history[head]` is the most recent sum.
+            let (older, newer) = self.history.split_at(self.head + 1);
+            let recent = older.iter().rev().chain(newer.iter().rev());
+            out_buf[ix] = self.taps.iter().zip(recent).map(|(&h, &x)| h * x).sum();
+        }
+        for out_buf in output.iter_mut().skip(1) {
+            out_buf.silence();
+        }
+    }
+
+    fn tail_frames(&self) -> Option<usize> {
+        Some(self.latency())
+    }
+
+    clone_state!();
+}
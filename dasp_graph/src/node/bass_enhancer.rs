@@ -0,0 +1,118 @@
+use super::filter::{Biquad, BUTTERWORTH_Q};
+use crate::{Buffer, Input, Node, Vec};
+
+/// A psychoacoustic bass enhancer, allowing small speakers to convey low frequencies that they
+/// cannot reproduce.
+///
+/// Each channel is low-passed at the crossover `frequency` and the result is driven through a
+/// rectifying `tanh` waveshaper, generating both even and odd harmonics of the bass content. The
+/// generated signal is high-passed at the crossover frequency, removing the fundamental and any DC
+/// offset introduced by the rectification, and mixed back with the dry signal. The listener
+/// perceives the "missing fundamental" from its harmonics.
+///
+/// The output is `dry + mix * high_pass(shape(drive * low_passed))`, where
+/// `shape(x) = tanh(x) + |tanh(x)|`.
+///
+/// Assumes that there is one input node.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BassEnhancer {
+    /// The gain applied to the low-passed signal before waveshaping. Greater values produce more
+    /// harmonics.
+    pub drive: f32,
+    /// The amount of the generated harmonics mixed onto the dry signal.
+    pub mix: f32,
+    frequency: f64,
+    sample_rate: f64,
+    // The low-pass and high-pass filters of each channel.
+    filters: Vec<[Biquad; 2]>,
+}
+
+impl BassEnhancer {
+    /// The default value for `drive`.
+    pub const DEFAULT_DRIVE: f32 = 4.0;
+    /// The default value for `mix`.
+    pub const DEFAULT_MIX: f32 = 0.5;
+
+    /// Create a new **BassEnhancer** for the given number of channels, sample rate and crossover
+    /// frequency in Hz.
+    pub fn new(channels: usize, sample_rate: f64, frequency: f64) -> Self {
+        BassEnhancer {
+            drive: Self::DEFAULT_DRIVE,
+            mix: Self::DEFAULT_MIX,
+            frequency,
+            sample_rate,
+            filters: vec![filters(sample_rate, frequency); channels],
+        }
+    }
+
+    /// The crossover frequency in Hz below which harmonics are generated.
+    pub fn frequency(&self) -> f64 {
+        self.frequency
+    }
+
+    /// Set the crossover frequency in Hz.
+    ///
+    /// The state of each filter is retained.
+    pub fn set_frequency(&mut self, frequency: f64) {
+        self.frequency = frequency;
+        let [low, high] = filters(self.sample_rate, frequency);
+        for [l, h] in &mut self.filters {
+            l.set_coefficients(low);
+            h.set_coefficients(high);
+        }
+    }
+
+    /// The sample rate in Hz.
+    pub fn sample_rate(&self) -> f64 {
+        self.sample_rate
+    }
+
+    /// Reset the state of each filter to silence.
+    pub fn reset(&mut self) {
+        for [low, high] in &mut self.filters {
+            low.reset();
+            high.reset();
+        }
+    }
+}
+
+impl Node for BassEnhancer {
+    fn process(&mut self, inputs: &[Input], output: &mut [Buffer]) {
+        let input = match inputs.first() {
+            Some(input) => input,
+            None => {
+                output.iter_mut().for_each(|out_buf| out_buf.silence());
+                return;
+            }
+        };
+        let in_bufs = input.buffers();
+        for (ch, (out_buf, in_buf)) in output.iter_mut().zip(in_bufs).enumerate() {
+            let [low, high] = match self.filters.get_mut(ch) {
+                Some(filters) => filters,
+                None => {
+                    out_buf.copy_from_slice(in_buf);
+                    continue;
+                }
+            };
+            for (out, &dry) in out_buf.iter_mut().zip(in_buf.iter()) {
+                let bass = low.process(dry as f64) as f32;
+                let shaped = (self.drive * bass).tanh();
+                let harmonics = high.process((shaped + shaped.abs()) as f64) as f32;
+                *out = dry + self.mix * harmonics;
+            }
+        }
+        for out_buf in output.iter_mut().skip(in_bufs.len()) {
+            out_buf.silence();
+        }
+    }
+
+    clone_state!();
+}
+
+// The low-pass and high-pass filters at the given crossover frequency.
+fn filters(sample_rate: f64, frequency: f64) -> [Biquad; 2] {
+    [
+        Biquad::low_pass(sample_rate, frequency, BUTTERWORTH_Q),
+        Biquad::high_pass(sample_rate, frequency, BUTTERWORTH_Q),
+    ]
+}
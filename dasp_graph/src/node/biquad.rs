@@ -0,0 +1,87 @@
+use super::filter;
+use crate::{Buffer, Input, Node, Vec};
+
+/// A second-order IIR filter with coefficients that may be set at runtime.
+///
+/// Each channel of the input is filtered onto the output buffer of the same index by a biquad in
+/// transposed direct form II:
+///
+/// ```text
+/// y[n] = b0 * x[n] + b1 * x[n - 1] + b2 * x[n - 2] - a1 * y[n - 1] - a2 * y[n - 2]
+/// ```
+///
+/// The coefficients are normalized such that `a0` is `1.0`. This is a building block for EQs,
+/// crossovers and filter sweeps, where the coefficients are computed by the user, e.g. via the
+/// RBJ "Audio EQ Cookbook".
+///
+/// The filter state is held per channel and is sized lazily to the number of output buffers, so
+/// adding channels never panics. Growing the number of channels allocates. Output channels for
+/// which there is no input channel are silenced.
+///
+/// Assumes that there is one input node.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Biquad {
+    // The current coefficients, with a silent state for initializing new channels.
+    coefficients: filter::Biquad,
+    filters: Vec<filter::Biquad>,
+}
+
+impl Biquad {
+    /// Create a new **Biquad** with the given coefficients.
+    pub fn new(b0: f64, b1: f64, b2: f64, a1: f64, a2: f64) -> Self {
+        Biquad {
+            coefficients: filter::Biquad::new(b0, b1, b2, a1, a2),
+            filters: Vec::new(),
+        }
+    }
+
+    /// The `[b0, b1, b2, a1, a2]` coefficients of the filter.
+    pub fn coefficients(&self) -> [f64; 5] {
+        self.coefficients.coefficients()
+    }
+
+    /// Set the coefficients of the filter.
+    ///
+    /// The state of each channel is retained, allowing for sweeping the filter between blocks.
+    /// Call `reset` as well when reconfiguring the filter entirely.
+    pub fn set_coefficients(&mut self, b0: f64, b1: f64, b2: f64, a1: f64, a2: f64) {
+        self.coefficients = filter::Biquad::new(b0, b1, b2, a1, a2);
+        for filter in &mut self.filters {
+            filter.set_coefficients(self.coefficients);
+        }
+    }
+
+    /// Clear the two-sample history of each channel.
+    pub fn reset(&mut self) {
+        for filter in &mut self.filters {
+            filter.reset();
+        }
+    }
+}
+
+impl Node for Biquad {
+    fn process(&mut self, inputs: &[Input], output: &mut [Buffer]) {
+        let input = match inputs.first() {
+            Some(input) => input,
+            None => {
+                output.iter_mut().for_each(|out_buf| out_buf.silence());
+                return;
+            }
+        };
+        if self.filters.len() < output.len() {
+            self.filters.resize(output.len(), self.coefficients);
+        }
+        let in_bufs = input.buffers();
+        let channels = output.iter_mut().zip(in_bufs).zip(&mut self.filters);
+        for ((out_buf, in_buf), filter) in channels {
+            for (out, &x) in out_buf.iter_mut().zip(in_buf.iter()) {
+                *out = filter.process(x as f64) as f32;
+            }
+        }
+        for out_buf in output.iter_mut().skip(in_bufs.len()) {
+            out_buf.silence();
+        }
+    }
+
+    clone_state!();
+}
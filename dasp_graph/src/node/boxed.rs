@@ -1,4 +1,5 @@
-use crate::{Buffer, Input, Node};
+use crate::{Box, Buffer, Input, Node};
+use core::any::Any;
 use core::fmt;
 use core::ops::{Deref, DerefMut};
 
@@ -46,12 +47,52 @@ impl<I> Node<I> for BoxedNode<I> {
     fn process(&mut self, inputs: &[Input<I>], output: &mut [Buffer]) {
         self.0.process(inputs, output)
     }
+
+    fn tail_frames(&self) -> Option<usize> {
+        self.0.tail_frames()
+    }
+
+    fn expected_inputs(&self) -> Option<usize> {
+        self.0.expected_inputs()
+    }
+
+    fn expected_input_channels(&self) -> Option<usize> {
+        self.0.expected_input_channels()
+    }
+
+    fn save_state(&self) -> Option<Box<dyn Any + Send>> {
+        self.0.save_state()
+    }
+
+    fn load_state(&mut self, state: &dyn Any) {
+        self.0.load_state(state)
+    }
 }
 
 impl<I> Node<I> for BoxedNodeSend<I> {
     fn process(&mut self, inputs: &[Input<I>], output: &mut [Buffer]) {
         self.0.process(inputs, output)
     }
+
+    fn tail_frames(&self) -> Option<usize> {
+        self.0.tail_frames()
+    }
+
+    fn expected_inputs(&self) -> Option<usize> {
+        self.0.expected_inputs()
+    }
+
+    fn expected_input_channels(&self) -> Option<usize> {
+        self.0.expected_input_channels()
+    }
+
+    fn save_state(&self) -> Option<Box<dyn Any + Send>> {
+        self.0.save_state()
+    }
+
+    fn load_state(&mut self, state: &dyn Any) {
+        self.0.load_state(state)
+    }
 }
 
 impl<T, I> From<Box<T>> for BoxedNode<I>
@@ -0,0 +1,104 @@
+use crate::{Buffer, Input, Node, Vec};
+use dasp_ring_buffer::{DelayLine, Interpolation};
+
+/// A delay node that applies an independent delay to each channel.
+///
+/// This is useful for time-aligning multi-microphone recordings, where each channel may arrive at
+/// a slightly different time. Delays are specified in frames and may be fractional, in which case
+/// the delayed signal is linearly interpolated between the two nearest frames.
+///
+/// The delay line for each channel is allocated once during construction and is sized to fit the
+/// greatest delay given at that time. Later calls to `set_delay` are clamped to this maximum.
+///
+/// Assumes that there is one input node. Output channels beyond the number of delays are silenced.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChannelDelay {
+    delays: Vec<f32>,
+    max_delay: f32,
+    delay_lines: Vec<DelayLine<Vec<f32>>>,
+}
+
+impl ChannelDelay {
+    /// Create a new **ChannelDelay** with the given per-channel delays in frames.
+    ///
+    /// Negative delays are treated as `0.0`.
+    pub fn new(delays: Vec<f32>) -> Self {
+        let delays: Vec<f32> = delays.into_iter().map(|d| d.max(0.0)).collect();
+        let max_delay = delays.iter().cloned().fold(0.0, f32::max);
+        // Room for the current frame plus one more to interpolate a fractional delay.
+        let len = max_delay.floor() as usize + 2;
+        let delay_lines = delays
+            .iter()
+            .map(|_| DelayLine::new(vec![0.0; len], Interpolation::Linear))
+            .collect();
+        ChannelDelay {
+            delays,
+            max_delay,
+            delay_lines,
+        }
+    }
+
+    /// The current delay in frames for each channel.
+    pub fn delays(&self) -> &[f32] {
+        &self.delays
+    }
+
+    /// The maximum delay in frames supported by the allocated delay lines.
+    pub fn max_delay(&self) -> f32 {
+        self.max_delay
+    }
+
+    /// Set the delay in frames for the given channel.
+    ///
+    /// The delay is clamped to the range `0.0..=self.max_delay()`.
+    ///
+    /// **Panics** if `channel` is out of range.
+    pub fn set_delay(&mut self, channel: usize, frames: f32) {
+        self.delays[channel] = frames.max(0.0).min(self.max_delay);
+    }
+
+    /// Reset the contents of the delay lines to silence.
+    pub fn clear(&mut self) {
+        for delay_line in &mut self.delay_lines {
+            delay_line.clear();
+        }
+    }
+}
+
+impl Node for ChannelDelay {
+    fn process(&mut self, inputs: &[Input], output: &mut [Buffer]) {
+        // Retrieve the single input, ignore any others.
+        let input = match inputs.first() {
+            Some(input) => input,
+            None => return,
+        };
+
+        let in_bufs = input.buffers();
+        let channels = self.delay_lines.iter_mut().zip(&self.delays);
+        for (ch, (out_buf, (delay_line, &delay))) in output.iter_mut().zip(channels).enumerate() {
+            let in_buf = match in_bufs.get(ch) {
+                Some(in_buf) => in_buf,
+                None => {
+                    out_buf.silence();
+                    continue;
+                }
+            };
+            for (out, &sample) in out_buf.iter_mut().zip(in_buf.iter()) {
+                delay_line.push(sample);
+                *out = delay_line.read(delay);
+            }
+        }
+
+        // Silence any output channels for which we have no delay.
+        for out_buf in output.iter_mut().skip(self.delay_lines.len()) {
+            out_buf.silence();
+        }
+    }
+
+    fn tail_frames(&self) -> Option<usize> {
+        let max_delay = self.delays.iter().cloned().fold(0.0, f32::max);
+        Some(max_delay.ceil() as usize)
+    }
+
+    clone_state!();
+}
@@ -0,0 +1,142 @@
+use super::block_frames;
+use crate::{Buffer, Input, Node};
+
+/// A feed-forward dynamic range compressor node.
+///
+/// The level of each frame is detected as the peak absolute value across all input channels, so
+/// that the same gain is applied to every channel and the stereo image is preserved. The gain
+/// reduction is smoothed in the decibel domain using separate attack and release times.
+///
+/// The gain computer uses a quadratic soft knee of width `knee_db` centred on the threshold, so
+/// that compression eases in rather than switching on abruptly. A `knee_db` of `0.0` results in a
+/// hard knee.
+///
+/// Assumes that there is one input node.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Compressor {
+    /// The level in dBFS above which compression is applied.
+    pub threshold_db: f32,
+    /// The ratio of input level change to output level change above the threshold.
+    pub ratio: f32,
+    /// The width in dB of the soft knee region centred on the threshold.
+    pub knee_db: f32,
+    /// The time in frames for the gain reduction to move towards a greater reduction.
+    pub attack_frames: f32,
+    /// The time in frames for the gain reduction to recover towards a lesser reduction.
+    pub release_frames: f32,
+    gain_reduction_db: f32,
+}
+
+impl Compressor {
+    /// The default value for `threshold_db`.
+    pub const DEFAULT_THRESHOLD_DB: f32 = -12.0;
+    /// The default value for `ratio`.
+    pub const DEFAULT_RATIO: f32 = 4.0;
+    /// The default value for `knee_db`.
+    pub const DEFAULT_KNEE_DB: f32 = 6.0;
+    /// The default value for `attack_frames`, roughly 10ms at 44.1kHz.
+    pub const DEFAULT_ATTACK_FRAMES: f32 = 441.0;
+    /// The default value for `release_frames`, roughly 100ms at 44.1kHz.
+    pub const DEFAULT_RELEASE_FRAMES: f32 = 4410.0;
+
+    /// Create a new **Compressor** with the given threshold in dBFS and ratio.
+    pub fn new(threshold_db: f32, ratio: f32) -> Self {
+        Compressor {
+            threshold_db,
+            ratio,
+            knee_db: Self::DEFAULT_KNEE_DB,
+            attack_frames: Self::DEFAULT_ATTACK_FRAMES,
+            release_frames: Self::DEFAULT_RELEASE_FRAMES,
+            gain_reduction_db: 0.0,
+        }
+    }
+
+    /// The current smoothed gain reduction in dB.
+    ///
+    /// This is always less than or equal to `0.0`.
+    pub fn current_gain_reduction_db(&self) -> f32 {
+        self.gain_reduction_db
+    }
+
+    /// The static gain reduction in dB for the given input level in dBFS, before any attack or
+    /// release smoothing is applied.
+    ///
+    /// The curve is continuous and, for a non-zero `knee_db`, differentiable through the knee.
+    pub fn gain_reduction_db(&self, input_db: f32) -> f32 {
+        let over = input_db - self.threshold_db;
+        let slope = 1.0 / self.ratio - 1.0;
+        let half_knee = self.knee_db / 2.0;
+        if over <= -half_knee {
+            0.0
+        } else if over < half_knee {
+            let x = over + half_knee;
+            slope * x * x / (2.0 * self.knee_db)
+        } else {
+            slope * over
+        }
+    }
+
+    /// Reset the smoothed gain reduction to `0.0`.
+    pub fn reset(&mut self) {
+        self.gain_reduction_db = 0.0;
+    }
+}
+
+impl Default for Compressor {
+    fn default() -> Self {
+        Compressor::new(Self::DEFAULT_THRESHOLD_DB, Self::DEFAULT_RATIO)
+    }
+}
+
+impl Node for Compressor {
+    fn process(&mut self, inputs: &[Input], output: &mut [Buffer]) {
+        let input = match inputs.first() {
+            Some(input) => input,
+            None => {
+                output.iter_mut().for_each(|out_buf| out_buf.silence());
+                return;
+            }
+        };
+        let in_bufs = input.buffers();
+        let attack = smoothing_coefficient(self.attack_frames);
+        let release = smoothing_coefficient(self.release_frames);
+        for ix in 0..block_frames(output) {
+            let peak = in_bufs
+                .iter()
+                .fold(0.0f32, |peak, buf| peak.max(buf[ix].abs()));
+            let target = self.gain_reduction_db(linear_to_db(peak));
+            let coef = if target < self.gain_reduction_db {
+                attack
+            } else {
+                release
+            };
+            self.gain_reduction_db = target + coef * (self.gain_reduction_db - target);
+            let gain = db_to_linear(self.gain_reduction_db);
+            for (out_buf, in_buf) in output.iter_mut().zip(in_bufs) {
+                out_buf[ix] = in_buf[ix] * gain;
+            }
+        }
+        for out_buf in output.iter_mut().skip(in_bufs.len()) {
+            out_buf.silence();
+        }
+    }
+
+    clone_state!();
+}
+
+// The one-pole smoothing coefficient for the given time constant in frames.
+fn smoothing_coefficient(frames: f32) -> f32 {
+    if frames <= 0.0 {
+        0.0
+    } else {
+        (-1.0 / frames).exp()
+    }
+}
+
+fn linear_to_db(linear: f32) -> f32 {
+    20.0 * linear.max(1e-10).log10()
+}
+
+fn db_to_linear(db: f32) -> f32 {
+    10f32.powf(db / 20.0)
+}
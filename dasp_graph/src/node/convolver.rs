@@ -0,0 +1,205 @@
+use super::block_frames;
+use crate::{Buffer, Input, Node, Vec};
+use dasp_frame::Frame;
+
+/// Describes how the input channels of a **Convolver** are mapped to the channels of its impulse
+/// response.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConvolverRouting {
+    /// Each input channel `n` is convolved with IR channel `n % ir_channels` and written to output
+    /// channel `n`, i.e. L -> L and R -> R for a stereo IR. A mono IR is applied to every channel.
+    Parallel,
+    /// A true-stereo matrix, where a 4-channel IR describes the response from each of two input
+    /// channels to each of two output channels, ordered L -> L, L -> R, R -> L, R -> R.
+    ///
+    /// Each output channel is the sum of both input channels convolved with their respective IR
+    /// channels, capturing the cross-feed of a real stereo space.
+    TrueStereo,
+}
+
+/// Convolves its input with a user-supplied impulse response (IR), e.g. to apply the reverb of a
+/// recorded space or the response of a speaker cabinet.
+///
+/// The convolution is performed directly in the time domain, so the cost per frame is
+/// proportional to the length of the IR. This is well suited to short IRs such as cabinet or
+/// early reflection responses.
+///
+/// The routing of input channels to IR channels is described by `ConvolverRouting`. Input
+/// channels beyond those for which history was allocated during construction are passed through
+/// unchanged.
+///
+/// Assumes that there is one input node.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Convolver {
+    ir: Vec<Vec<f32>>,
+    sample_rate: f64,
+    routing: ConvolverRouting,
+    // The most recent input samples for each input channel, stored circularly.
+    histories: Vec<Vec<f32>>,
+    // The index of the most recent sample within each history.
+    head: usize,
+}
+
+impl Convolver {
+    /// Create a new **Convolver** from an IR where each `Vec` is a channel, allocating history for
+    /// the given number of input channels.
+    ///
+    /// The `sample_rate` is that at which the IR was recorded. No resampling is performed, so this
+    /// should match the sample rate of the graph.
+    ///
+    /// For `ConvolverRouting::TrueStereo`, history is always allocated for two input channels.
+    ///
+    /// **Panics** if `ir` is empty, if its channels differ in length, or if the routing is
+    /// `TrueStereo` and `ir` does not contain exactly 4 channels.
+    pub fn new(
+        channels: usize,
+        ir: Vec<Vec<f32>>,
+        sample_rate: f64,
+        routing: ConvolverRouting,
+    ) -> Self {
+        assert!(
+            !ir.is_empty(),
+            "the impulse response must have at least one channel"
+        );
+        let len = ir[0].len().max(1);
+        assert!(
+            ir.iter().all(|ch| ch.len() == ir[0].len()),
+            "all impulse response channels must be of equal length"
+        );
+        let channels = match routing {
+            ConvolverRouting::Parallel => channels,
+            ConvolverRouting::TrueStereo => {
+                assert_eq!(
+                    ir.len(),
+                    4,
+                    "a true-stereo impulse response requires 4 channels"
+                );
+                2
+            }
+        };
+        Convolver {
+            ir,
+            sample_rate,
+            routing,
+            histories: vec![vec![0.0; len]; channels],
+            head: 0,
+        }
+    }
+
+    /// Create a new **Convolver** from the frames of a decoded IR, e.g. as read from a WAV file.
+    ///
+    /// The routing is chosen from the number of channels in the IR:
+    ///
+    /// - A 4-channel IR is treated as a true-stereo IR, ordered L -> L, L -> R, R -> L, R -> R.
+    /// - Any other IR uses `ConvolverRouting::Parallel`, with history allocated for at least two
+    ///   channels so that a mono IR may be applied to a stereo input.
+    ///
+    /// **Panics** if `frames` is empty.
+    pub fn from_frames<F>(frames: &[F], sample_rate: f64) -> Self
+    where
+        F: Frame<Sample = f32>,
+    {
+        assert!(!frames.is_empty(), "the impulse response must not be empty");
+        let ir = (0..F::CHANNELS)
+            .map(|ch| {
+                frames
+                    .iter()
+                    .map(move |f| f.channel(ch).cloned().unwrap_or(0.0))
+            })
+            .map(|ch| ch.collect())
+            .collect();
+        match F::CHANNELS {
+            4 => Self::new(2, ir, sample_rate, ConvolverRouting::TrueStereo),
+            n => Self::new(n.max(2), ir, sample_rate, ConvolverRouting::Parallel),
+        }
+    }
+
+    /// The impulse response, where each `Vec` is a channel.
+    pub fn impulse_response(&self) -> &[Vec<f32>] {
+        &self.ir
+    }
+
+    /// The sample rate in Hz at which the IR was recorded.
+    pub fn sample_rate(&self) -> f64 {
+        self.sample_rate
+    }
+
+    /// The routing of input channels to IR channels.
+    pub fn routing(&self) -> ConvolverRouting {
+        self.routing
+    }
+
+    /// Clear the input history, silencing any remaining tail.
+    pub fn reset(&mut self) {
+        for history in &mut self.histories {
+            history.iter_mut().for_each(|s| *s = 0.0);
+        }
+    }
+
+    // Convolve the given history with the given IR channel, ending at the head.
+    fn convolve(ir: &[f32], history: &[f32], head: usize) -> f32 {
+        // The history is stored circularly, where `history[head]` is the most recent sample.
+        let (older, newer) = history.split_at(head + 1);
+        let recent = older.iter().rev().chain(newer.iter().rev());
+        ir.iter().zip(recent).map(|(&h, &x)| h * x).sum()
+    }
+}
+
+impl Node for Convolver {
+    fn process(&mut self, inputs: &[Input], output: &mut [Buffer]) {
+        let input = match inputs.first() {
+            Some(input) => input,
+            None => {
+                output.iter_mut().for_each(|out_buf| out_buf.silence());
+                return;
+            }
+        };
+        let in_bufs = input.buffers();
+        let len = self.histories.first().map_or(1, |h| h.len());
+        for ix in 0..block_frames(output) {
+            self.head = (self.head + 1) % len;
+            for (history, in_buf) in self.histories.iter_mut().zip(in_bufs) {
+                history[self.head] = in_buf[ix];
+            }
+            let head = self.head;
+            match self.routing {
+                ConvolverRouting::Parallel => {
+                    let ir_channels = self.ir.len();
+                    for (ch, out_buf) in output.iter_mut().enumerate() {
+                        out_buf[ix] = match (self.histories.get(ch), in_bufs.get(ch)) {
+                            (Some(history), Some(_)) => {
+                                Self::convolve(&self.ir[ch % ir_channels], history, head)
+                            }
+                            (None, Some(in_buf)) => in_buf[ix],
+                            _ => 0.0,
+                        };
+                    }
+                }
+                ConvolverRouting::TrueStereo => {
+                    for (ch, out_buf) in output.iter_mut().enumerate().take(2) {
+                        out_buf[ix] = self
+                            .histories
+                            .iter()
+                            .zip(in_bufs)
+                            .enumerate()
+                            .map(|(i, (history, _))| {
+                                Self::convolve(&self.ir[i * 2 + ch], history, head)
+                            })
+                            .sum();
+                    }
+                }
+            }
+        }
+        if self.routing == ConvolverRouting::TrueStereo {
+            for out_buf in output.iter_mut().skip(2) {
+                out_buf.silence();
+            }
+        }
+    }
+
+    fn tail_frames(&self) -> Option<usize> {
+        self.histories.first().map(|history| history.len() - 1)
+    }
+
+    clone_state!();
+}
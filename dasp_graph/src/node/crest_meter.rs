@@ -0,0 +1,152 @@
+use super::block_frames;
+use crate::{Buffer, Input, Node, Vec};
+#[cfg(not(feature = "std"))]
+use alloc::sync::Arc;
+use core::sync::atomic::{AtomicU32, Ordering};
+#[cfg(feature = "std")]
+use std::sync::Arc;
+
+/// A node that passes audio through while measuring the crest factor, the ratio of the peak level
+/// to the RMS level, over a sliding window.
+///
+/// The crest factor indicates how compressed a signal is. A sine wave has a crest factor of
+/// roughly `1.414` (3 dB) while a square wave has a crest factor of `1.0` (0 dB). Heavily limited
+/// material tends towards `1.0`, while dynamic material has a much greater crest factor.
+///
+/// The peak and mean square are measured across all channels over the last `window_frames`
+/// frames. The measurement is updated once per processed block and published to an atomic, so that
+/// it may be read from another thread via a **CrestMeterHandle** without locking.
+///
+/// The window is allocated once during construction.
+///
+/// Assumes that there is one input node.
+#[derive(Clone, Debug)]
+pub struct CrestMeter {
+    peaks: Vec<f32>,
+    squares: Vec<f32>,
+    next: usize,
+    sum_squares: f64,
+    crest: Arc<AtomicU32>,
+}
+
+/// A handle for reading the crest factor measured by a **CrestMeter** from any thread.
+#[derive(Clone, Debug)]
+pub struct CrestMeterHandle {
+    crest: Arc<AtomicU32>,
+}
+
+impl CrestMeter {
+    /// Create a new **CrestMeter** measuring over a sliding window of the given number of frames.
+    ///
+    /// **Panics** if `window_frames` is `0`.
+    pub fn new(window_frames: usize) -> Self {
+        assert!(
+            window_frames > 0,
+            "the crest meter window must not be empty"
+        );
+        CrestMeter {
+            peaks: vec![0.0; window_frames],
+            squares: vec![0.0; window_frames],
+            next: 0,
+            sum_squares: 0.0,
+            crest: Arc::new(AtomicU32::new(0f32.to_bits())),
+        }
+    }
+
+    /// The length of the sliding window in frames.
+    pub fn window_frames(&self) -> usize {
+        self.peaks.len()
+    }
+
+    /// A handle for reading the measured crest factor, e.g. from a UI thread.
+    pub fn handle(&self) -> CrestMeterHandle {
+        CrestMeterHandle {
+            crest: self.crest.clone(),
+        }
+    }
+
+    /// The most recently measured crest factor as a linear ratio.
+    ///
+    /// This is `0.0` while the window is silent.
+    pub fn crest(&self) -> f32 {
+        load(&self.crest)
+    }
+
+    /// The most recently measured crest factor in dB.
+    pub fn crest_db(&self) -> f32 {
+        linear_to_db(self.crest())
+    }
+
+    /// Clear the window and the measured crest factor.
+    pub fn reset(&mut self) {
+        self.peaks.iter_mut().for_each(|s| *s = 0.0);
+        self.squares.iter_mut().for_each(|s| *s = 0.0);
+        self.next = 0;
+        self.sum_squares = 0.0;
+        self.crest.store(0f32.to_bits(), Ordering::Relaxed);
+    }
+}
+
+impl CrestMeterHandle {
+    /// The most recently measured crest factor as a linear ratio.
+    ///
+    /// This is `0.0` while the window is silent.
+    pub fn crest(&self) -> f32 {
+        load(&self.crest)
+    }
+
+    /// The most recently measured crest factor in dB.
+    pub fn crest_db(&self) -> f32 {
+        linear_to_db(self.crest())
+    }
+}
+
+impl Node for CrestMeter {
+    fn process(&mut self, inputs: &[Input], output: &mut [Buffer]) {
+        // Pass the single input through to the output, ignoring any others.
+        let in_bufs = inputs.first().map_or(&[][..], |input| input.buffers());
+        for (out_buf, in_buf) in output.iter_mut().zip(in_bufs) {
+            out_buf.copy_from_slice(in_buf);
+        }
+        for out_buf in output.iter_mut().skip(in_bufs.len()) {
+            out_buf.silence();
+        }
+
+        let channels = in_bufs.len().max(1) as f32;
+        let frames = block_frames(output);
+        for ix in 0..frames {
+            let mut peak = 0.0f32;
+            let mut square = 0.0f32;
+            for in_buf in in_bufs {
+                let sample = in_buf[ix];
+                peak = peak.max(sample.abs());
+                square += sample * sample;
+            }
+            square /= channels;
+            self.sum_squares += square as f64 - self.squares[self.next] as f64;
+            self.peaks[self.next] = peak;
+            self.squares[self.next] = square;
+            self.next = (self.next + 1) % self.peaks.len();
+        }
+
+        // Recompute the sum once it wraps to avoid accumulating rounding error.
+        if self.next < frames {
+            self.sum_squares = self.squares.iter().map(|&s| s as f64).sum();
+        }
+        let mean_square = (self.sum_squares / self.peaks.len() as f64).max(0.0);
+        let rms = mean_square.sqrt() as f32;
+        let peak = self.peaks.iter().fold(0.0f32, |max, &p| max.max(p));
+        let crest = if rms > 0.0 { peak / rms } else { 0.0 };
+        self.crest.store(crest.to_bits(), Ordering::Relaxed);
+    }
+
+    clone_state!();
+}
+
+fn load(crest: &AtomicU32) -> f32 {
+    f32::from_bits(crest.load(Ordering::Relaxed))
+}
+
+fn linear_to_db(linear: f32) -> f32 {
+    20.0 * linear.max(1e-10).log10()
+}
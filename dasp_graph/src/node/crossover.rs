@@ -0,0 +1,139 @@
+use super::filter::{Biquad, BUTTERWORTH_Q};
+use crate::{Buffer, Input, Node, Vec};
+
+/// A Linkwitz-Riley crossover network that splits a signal into multiple frequency bands.
+///
+/// Given `n` crossover frequencies, the first channel of the input is split into `n + 1` bands,
+/// ordered from lowest to highest, and each band is written to its own output buffer. Each split
+/// uses a 4th-order Linkwitz-Riley low-pass and high-pass pair. Lower bands are passed through
+/// matching all-pass filters for each of the higher crossover frequencies, so that the sum of all
+/// bands reconstructs the input with a flat magnitude response.
+///
+/// All filter state is allocated during construction.
+///
+/// Assumes that there is one input node. Output buffers beyond the number of bands are silenced.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Crossover {
+    frequencies: Vec<f64>,
+    sample_rate: f64,
+    splits: Vec<Lr4>,
+    // The all-pass compensation filters for each band, in order of band then frequency.
+    allpasses: Vec<Lr4>,
+}
+
+// A 4th-order Linkwitz-Riley low-pass and high-pass pair at a single frequency.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Lr4 {
+    lows: [Biquad; 2],
+    highs: [Biquad; 2],
+}
+
+impl Crossover {
+    /// Create a new **Crossover** with the given sample rate and crossover frequencies in Hz.
+    ///
+    /// The frequencies are sorted in ascending order. The number of bands is always one more than
+    /// the number of frequencies.
+    pub fn new(sample_rate: f64, mut frequencies: Vec<f64>) -> Self {
+        frequencies.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let splits = frequencies
+            .iter()
+            .map(|&hz| Lr4::new(sample_rate, hz))
+            .collect();
+        let allpasses = (0..frequencies.len())
+            .flat_map(|band| frequencies[band + 1..].iter())
+            .map(|&hz| Lr4::new(sample_rate, hz))
+            .collect();
+        Crossover {
+            frequencies,
+            sample_rate,
+            splits,
+            allpasses,
+        }
+    }
+
+    /// The crossover frequencies in Hz, in ascending order.
+    pub fn frequencies(&self) -> &[f64] {
+        &self.frequencies
+    }
+
+    /// The sample rate in Hz.
+    pub fn sample_rate(&self) -> f64 {
+        self.sample_rate
+    }
+
+    /// The number of bands produced by the crossover.
+    pub fn bands(&self) -> usize {
+        self.frequencies.len() + 1
+    }
+
+    /// Reset the state of all filters to silence.
+    pub fn reset(&mut self) {
+        for lr4 in self.splits.iter_mut().chain(&mut self.allpasses) {
+            lr4.reset();
+        }
+    }
+}
+
+impl Lr4 {
+    pub(crate) fn new(sample_rate: f64, hz: f64) -> Self {
+        let low = Biquad::low_pass(sample_rate, hz, BUTTERWORTH_Q);
+        let high = Biquad::high_pass(sample_rate, hz, BUTTERWORTH_Q);
+        Lr4 {
+            lows: [low; 2],
+            highs: [high; 2],
+        }
+    }
+
+    // Returns the low and high bands of the given sample.
+    pub(crate) fn split(&mut self, x: f64) -> (f64, f64) {
+        let low = self.lows.iter_mut().fold(x, |s, f| f.process(s));
+        let high = self.highs.iter_mut().fold(x, |s, f| f.process(s));
+        (low, high)
+    }
+
+    fn reset(&mut self) {
+        for f in self.lows.iter_mut().chain(&mut self.highs) {
+            f.reset();
+        }
+    }
+}
+
+impl Node for Crossover {
+    fn process(&mut self, inputs: &[Input], output: &mut [Buffer]) {
+        let in_buf = match inputs.first().and_then(|input| input.buffers().first()) {
+            Some(in_buf) => in_buf,
+            None => {
+                output.iter_mut().for_each(|out_buf| out_buf.silence());
+                return;
+            }
+        };
+        let n_splits = self.splits.len();
+        for (ix, &sample) in in_buf.iter().enumerate() {
+            let mut rest = sample as f64;
+            let mut allpasses = self.allpasses.iter_mut();
+            for (band, split) in self.splits.iter_mut().enumerate() {
+                let (low, high) = split.split(rest);
+                rest = high;
+                // Compensate for the phase of the remaining crossovers.
+                let low = allpasses
+                    .by_ref()
+                    .take(n_splits - band - 1)
+                    .fold(low, |s, ap| {
+                        let (l, h) = ap.split(s);
+                        l + h
+                    });
+                if let Some(out_buf) = output.get_mut(band) {
+                    out_buf[ix] = low as f32;
+                }
+            }
+            if let Some(out_buf) = output.get_mut(n_splits) {
+                out_buf[ix] = rest as f32;
+            }
+        }
+        for out_buf in output.iter_mut().skip(n_splits + 1) {
+            out_buf.silence();
+        }
+    }
+
+    clone_state!();
+}
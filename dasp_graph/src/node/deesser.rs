@@ -0,0 +1,171 @@
+use super::block_frames;
+use super::filter::{Svf, BUTTERWORTH_Q};
+use crate::{Buffer, Input, Node, Vec};
+
+/// Selects how a **DeEsser** applies its gain reduction.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeEsserMode {
+    /// Only the band above `frequency` is attenuated, leaving lower content untouched.
+    SplitBand,
+    /// The whole signal is attenuated.
+    Broadband,
+}
+
+/// A de-esser for taming sibilance, e.g. on vocals.
+///
+/// Sibilance is detected by band-pass filtering each channel around `frequency` and measuring the
+/// peak level across all channels, so that the same reduction is applied to every channel. For
+/// every dB that the detected level exceeds `threshold_db`, the gain is reduced by a dB, up to a
+/// maximum reduction of `range_db`. The reduction is smoothed using separate attack and release
+/// times.
+///
+/// In `DeEsserMode::SplitBand`, each channel is split into complementary bands at `frequency` and
+/// the reduction is applied only to the upper band before the two are summed. As the bands sum to
+/// the original signal, the output is identical to the input while no reduction is applied.
+///
+/// Only the number of channels given to `new` are de-essed. Any further input channels are output
+/// unchanged and are ignored by the detector, so their sibilance never triggers a reduction.
+///
+/// Assumes that there is one input node.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeEsser {
+    /// Whether the reduction is applied to the upper band or to the whole signal.
+    pub mode: DeEsserMode,
+    /// The detected sibilance level in dBFS above which the gain is reduced.
+    pub threshold_db: f32,
+    /// The maximum gain reduction in dB.
+    pub range_db: f32,
+    /// The time in frames for the gain reduction to move towards a greater reduction.
+    pub attack_frames: f32,
+    /// The time in frames for the gain reduction to recover towards a lesser reduction.
+    pub release_frames: f32,
+    frequency: f64,
+    sample_rate: f64,
+    filters: Vec<Svf>,
+    gain_reduction_db: f32,
+}
+
+impl DeEsser {
+    /// The default value for `threshold_db`.
+    pub const DEFAULT_THRESHOLD_DB: f32 = -30.0;
+    /// The default value for `range_db`.
+    pub const DEFAULT_RANGE_DB: f32 = 12.0;
+    /// The default value for `attack_frames`, roughly 1ms at 44.1kHz.
+    pub const DEFAULT_ATTACK_FRAMES: f32 = 44.0;
+    /// The default value for `release_frames`, roughly 50ms at 44.1kHz.
+    pub const DEFAULT_RELEASE_FRAMES: f32 = 2205.0;
+
+    /// Create a new **DeEsser** in split-band mode for the given number of channels, sample rate
+    /// and sibilance frequency in Hz.
+    pub fn new(channels: usize, sample_rate: f64, frequency: f64) -> Self {
+        let filter = Svf::new(sample_rate, frequency, BUTTERWORTH_Q);
+        DeEsser {
+            mode: DeEsserMode::SplitBand,
+            threshold_db: Self::DEFAULT_THRESHOLD_DB,
+            range_db: Self::DEFAULT_RANGE_DB,
+            attack_frames: Self::DEFAULT_ATTACK_FRAMES,
+            release_frames: Self::DEFAULT_RELEASE_FRAMES,
+            frequency,
+            sample_rate,
+            filters: vec![filter; channels],
+            gain_reduction_db: 0.0,
+        }
+    }
+
+    /// The frequency in Hz around which sibilance is detected and above which the upper band is
+    /// split in `SplitBand` mode.
+    pub fn frequency(&self) -> f64 {
+        self.frequency
+    }
+
+    /// Set the sibilance frequency in Hz.
+    ///
+    /// The state of each filter is retained.
+    pub fn set_frequency(&mut self, frequency: f64) {
+        self.frequency = frequency;
+        for filter in &mut self.filters {
+            filter.set_coefficients(self.sample_rate, frequency, BUTTERWORTH_Q);
+        }
+    }
+
+    /// The current smoothed gain reduction in dB.
+    ///
+    /// This is always less than or equal to `0.0`.
+    pub fn current_gain_reduction_db(&self) -> f32 {
+        self.gain_reduction_db
+    }
+
+    /// Reset the filter state and the smoothed gain reduction.
+    pub fn reset(&mut self) {
+        for filter in &mut self.filters {
+            filter.reset();
+        }
+        self.gain_reduction_db = 0.0;
+    }
+}
+
+impl Node for DeEsser {
+    fn process(&mut self, inputs: &[Input], output: &mut [Buffer]) {
+        let input = match inputs.first() {
+            Some(input) => input,
+            None => {
+                output.iter_mut().for_each(|out_buf| out_buf.silence());
+                return;
+            }
+        };
+        let in_bufs = input.buffers();
+        let attack = smoothing_coefficient(self.attack_frames);
+        let release = smoothing_coefficient(self.release_frames);
+        let range_db = self.range_db.max(0.0);
+        for ix in 0..block_frames(output) {
+            // Filter each channel, writing the upper band to the output to be scaled below.
+            let mut peak = 0.0f32;
+            for ((out_buf, in_buf), filter) in output.iter_mut().zip(in_bufs).zip(&mut self.filters)
+            {
+                let x = in_buf[ix] as f64;
+                let (low, band, _) = filter.process(x);
+                peak = peak.max(band.abs() as f32);
+                out_buf[ix] = (x - low) as f32;
+            }
+            let over = linear_to_db(peak) - self.threshold_db;
+            let target = -over.clamp(0.0, range_db);
+            let coef = if target < self.gain_reduction_db {
+                attack
+            } else {
+                release
+            };
+            self.gain_reduction_db = target + coef * (self.gain_reduction_db - target);
+            let gain = db_to_linear(self.gain_reduction_db);
+            for (ch, (out_buf, in_buf)) in output.iter_mut().zip(in_bufs).enumerate() {
+                let dry = in_buf[ix];
+                out_buf[ix] = match (ch < self.filters.len(), self.mode) {
+                    (false, _) => dry,
+                    (true, DeEsserMode::SplitBand) => dry + (gain - 1.0) * out_buf[ix],
+                    (true, DeEsserMode::Broadband) => dry * gain,
+                };
+            }
+        }
+        for out_buf in output.iter_mut().skip(in_bufs.len()) {
+            out_buf.silence();
+        }
+    }
+
+    clone_state!();
+}
+
+// The one-pole smoothing coefficient for the given time constant in frames.
+fn smoothing_coefficient(frames: f32) -> f32 {
+    if frames <= 0.0 {
+        0.0
+    } else {
+        (-1.0 / frames).exp()
+    }
+}
+
+fn linear_to_db(linear: f32) -> f32 {
+    20.0 * linear.max(1e-10).log10()
+}
+
+fn db_to_linear(db: f32) -> f32 {
+    10f32.powf(db / 20.0)
+}
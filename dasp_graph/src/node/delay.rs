@@ -1,4 +1,8 @@
-use crate::{Buffer, Input, Node};
+#[cfg(feature = "alloc")]
+use crate::Box;
+use crate::{Buffer, Input, Node, Vec};
+#[cfg(feature = "alloc")]
+use core::any::Any;
 use dasp_ring_buffer as ring_buffer;
 
 /// A delay node, where the delay duration for each channel is equal to the length of the inner
@@ -6,6 +10,8 @@ use dasp_ring_buffer as ring_buffer;
 ///
 /// Assumes that there is one input node, and that the number of input buffers, output buffers and
 /// ring buffers all match.
+///
+/// The tail of the node is the length of its longest ring buffer.
 #[derive(Clone, Debug, PartialEq)]
 pub struct Delay<S>(pub Vec<ring_buffer::Fixed<S>>);
 
@@ -27,4 +33,31 @@ where
             }
         }
     }
+
+    fn tail_frames(&self) -> Option<usize> {
+        self.0.iter().map(|ring_buf| ring_buf.len()).max()
+    }
+
+    // The contents of each ring buffer from oldest to newest, as the ring buffer storage `S` is
+    // not necessarily owned.
+    #[cfg(feature = "alloc")]
+    fn save_state(&self) -> Option<Box<dyn Any + Send>> {
+        let state: Vec<Vec<f32>> = self
+            .0
+            .iter()
+            .map(|ring_buf| ring_buf.iter().cloned().collect())
+            .collect();
+        Some(Box::new(state))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn load_state(&mut self, state: &dyn Any) {
+        if let Some(state) = state.downcast_ref::<Vec<Vec<f32>>>() {
+            for (ring_buf, samples) in self.0.iter_mut().zip(state) {
+                for &sample in samples {
+                    ring_buf.push(sample);
+                }
+            }
+        }
+    }
 }
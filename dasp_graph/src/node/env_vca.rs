@@ -0,0 +1,149 @@
+use super::block_frames;
+use crate::{Buffer, Input, Node};
+
+/// The role of an input to an **EnvVca** node, given by the `Input` variant.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EnvVcaInput {
+    /// The audio signal to which the envelope is applied.
+    Audio,
+    /// The gate signal that triggers the envelope.
+    Gate,
+}
+
+/// A gate-triggered ADSR envelope applied directly to an audio input, i.e. an envelope generator
+/// and a VCA in a single node.
+///
+/// The envelope is driven by the first channel of the first input whose variant converts to
+/// `EnvVcaInput::Gate`. The gate is considered on while its value is greater than `0.0`. The
+/// attack segment begins at the exact frame the gate turns on, and the release segment at the
+/// exact frame it turns off. If there is no gate input, the gate is considered off.
+///
+/// Every channel of the first input whose variant converts to `EnvVcaInput::Audio` is multiplied
+/// by the envelope. If there is no audio input, the output is silent.
+///
+/// All segments are linear. Retriggering while the envelope is still sounding begins the attack
+/// from the current level rather than from `0.0`, so that retriggers are click-free.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EnvVca {
+    /// The time in frames to rise from `0.0` to `1.0`.
+    pub attack_frames: f32,
+    /// The time in frames to fall from `1.0` to the sustain level.
+    pub decay_frames: f32,
+    /// The level held while the gate remains on, in the range `0.0..=1.0`.
+    pub sustain: f32,
+    /// The time in frames to fall from the level at which the gate turned off to `0.0`.
+    pub release_frames: f32,
+    stage: Stage,
+    level: f32,
+    release_step: f32,
+    last_gate: f32,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Stage {
+    Idle,
+    Attack,
+    Decay,
+    Sustain,
+    Release,
+}
+
+impl EnvVca {
+    /// Create a new **EnvVca** with the given attack, decay and release times in frames and the
+    /// given sustain level.
+    pub fn new(attack_frames: f32, decay_frames: f32, sustain: f32, release_frames: f32) -> Self {
+        EnvVca {
+            attack_frames,
+            decay_frames,
+            sustain,
+            release_frames,
+            stage: Stage::Idle,
+            level: 0.0,
+            release_step: 0.0,
+            last_gate: 0.0,
+        }
+    }
+
+    /// The current level of the envelope.
+    pub fn level(&self) -> f32 {
+        self.level
+    }
+
+    /// Whether or not the envelope is currently sounding.
+    pub fn is_active(&self) -> bool {
+        self.stage != Stage::Idle
+    }
+
+    /// Immediately silence the envelope.
+    pub fn reset(&mut self) {
+        self.stage = Stage::Idle;
+        self.level = 0.0;
+        self.last_gate = 0.0;
+    }
+
+    // Advance the envelope by a single frame with the given gate value, returning the new level.
+    fn step(&mut self, gate: f32) -> f32 {
+        if self.last_gate <= 0.0 && gate > 0.0 {
+            self.stage = Stage::Attack;
+        } else if self.last_gate > 0.0 && gate <= 0.0 && self.stage != Stage::Idle {
+            self.stage = Stage::Release;
+            self.release_step = self.level / self.release_frames.max(1.0);
+        }
+        self.last_gate = gate;
+
+        let sustain = self.sustain.clamp(0.0, 1.0);
+        match self.stage {
+            Stage::Idle => (),
+            Stage::Attack => {
+                self.level += 1.0 / self.attack_frames.max(1.0);
+                if self.level >= 1.0 {
+                    self.level = 1.0;
+                    self.stage = Stage::Decay;
+                }
+            }
+            Stage::Decay => {
+                self.level -= (1.0 - sustain) / self.decay_frames.max(1.0);
+                if self.level <= sustain {
+                    self.level = sustain;
+                    self.stage = Stage::Sustain;
+                }
+            }
+            Stage::Sustain => self.level = sustain,
+            Stage::Release => {
+                self.level -= self.release_step;
+                if self.level <= 0.0 {
+                    self.level = 0.0;
+                    self.stage = Stage::Idle;
+                }
+            }
+        }
+        self.level
+    }
+}
+
+impl<I> Node<I> for EnvVca
+where
+    I: Clone + Into<EnvVcaInput>,
+{
+    fn process(&mut self, inputs: &[Input<I>], output: &mut [Buffer]) {
+        let find = |role| {
+            inputs
+                .iter()
+                .find(|input| input.variant.clone().into() == role)
+                .map(|input| input.buffers())
+        };
+        let audio = find(EnvVcaInput::Audio).unwrap_or(&[]);
+        let gate = find(EnvVcaInput::Gate).and_then(|bufs| bufs.first());
+        for ix in 0..block_frames(output) {
+            let level = self.step(gate.map_or(0.0, |buf| buf[ix]));
+            for (out_buf, in_buf) in output.iter_mut().zip(audio) {
+                out_buf[ix] = in_buf[ix] * level;
+            }
+        }
+        for out_buf in output.iter_mut().skip(audio.len()) {
+            out_buf.silence();
+        }
+    }
+
+    clone_state!();
+}
@@ -0,0 +1,92 @@
+use super::filter::{Biquad, BUTTERWORTH_Q};
+use crate::{Buffer, Input, Node, Vec};
+
+/// A simple exciter, or harmonic enhancer.
+///
+/// Each channel is high-passed at the given `frequency`, driven into a gentle `tanh` saturation to
+/// generate upper harmonics, and the result is mixed back with the dry signal. This adds "air" and
+/// presence without affecting the low end.
+///
+/// The output is `dry + mix * tanh(drive * high_passed)`.
+///
+/// Assumes that there is one input node.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Exciter {
+    /// The gain applied to the high-passed signal before saturation.
+    pub drive: f32,
+    /// The amount of the saturated signal mixed onto the dry signal.
+    pub mix: f32,
+    frequency: f64,
+    sample_rate: f64,
+    filters: Vec<Biquad>,
+}
+
+impl Exciter {
+    /// The default value for `drive`.
+    pub const DEFAULT_DRIVE: f32 = 4.0;
+    /// The default value for `mix`.
+    pub const DEFAULT_MIX: f32 = 0.25;
+
+    /// Create a new **Exciter** for the given number of channels, sample rate and high-pass
+    /// frequency in Hz.
+    pub fn new(channels: usize, sample_rate: f64, frequency: f64) -> Self {
+        let filter = Biquad::high_pass(sample_rate, frequency, BUTTERWORTH_Q);
+        Exciter {
+            drive: Self::DEFAULT_DRIVE,
+            mix: Self::DEFAULT_MIX,
+            frequency,
+            sample_rate,
+            filters: vec![filter; channels],
+        }
+    }
+
+    /// The high-pass frequency in Hz above which harmonics are generated.
+    pub fn frequency(&self) -> f64 {
+        self.frequency
+    }
+
+    /// Set the high-pass frequency in Hz.
+    ///
+    /// The state of each filter is retained.
+    pub fn set_frequency(&mut self, frequency: f64) {
+        self.frequency = frequency;
+        for filter in &mut self.filters {
+            filter.set_coefficients(Biquad::high_pass(
+                self.sample_rate,
+                frequency,
+                BUTTERWORTH_Q,
+            ));
+        }
+    }
+}
+
+impl Node for Exciter {
+    fn process(&mut self, inputs: &[Input], output: &mut [Buffer]) {
+        let input = match inputs.first() {
+            Some(input) => input,
+            None => {
+                output.iter_mut().for_each(|out_buf| out_buf.silence());
+                return;
+            }
+        };
+        let in_bufs = input.buffers();
+        for (ch, (out_buf, in_buf)) in output.iter_mut().zip(in_bufs).enumerate() {
+            let filter = match self.filters.get_mut(ch) {
+                Some(filter) => filter,
+                None => {
+                    out_buf.copy_from_slice(in_buf);
+                    continue;
+                }
+            };
+            for (out, &dry) in out_buf.iter_mut().zip(in_buf.iter()) {
+                let high = filter.process(dry as f64) as f32;
+                *out = dry + self.mix * (self.drive * high).tanh();
+            }
+        }
+        for out_buf in output.iter_mut().skip(in_bufs.len()) {
+            out_buf.silence();
+        }
+    }
+
+    clone_state!();
+}
@@ -0,0 +1,250 @@
+//! Filter primitives shared between node implementations.
+
+/// The Q factor of a second-order Butterworth filter.
+#[cfg(any(
+    feature = "node-bass-enhancer",
+    feature = "node-crossover",
+    feature = "node-deesser",
+    feature = "node-exciter",
+    feature = "node-ms-eq",
+    feature = "node-tilt"
+))]
+pub(crate) const BUTTERWORTH_Q: f64 = core::f64::consts::FRAC_1_SQRT_2;
+
+/// A second-order IIR filter in transposed direct form II.
+///
+/// Coefficients are derived from the RBJ "Audio EQ Cookbook".
+#[cfg(any(
+    feature = "node-bass-enhancer",
+    feature = "node-biquad",
+    feature = "node-crossover",
+    feature = "node-exciter",
+    feature = "node-ms-eq",
+    feature = "node-oversampled-clipper",
+    feature = "node-tilt"
+))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) struct Biquad {
+    b0: f64,
+    b1: f64,
+    b2: f64,
+    a1: f64,
+    a2: f64,
+    z1: f64,
+    z2: f64,
+}
+
+#[cfg(any(
+    feature = "node-bass-enhancer",
+    feature = "node-biquad",
+    feature = "node-crossover",
+    feature = "node-exciter",
+    feature = "node-ms-eq",
+    feature = "node-oversampled-clipper",
+    feature = "node-tilt"
+))]
+impl Biquad {
+    /// A filter with the given coefficients, normalized such that `a0` is `1.0`.
+    #[cfg(feature = "node-biquad")]
+    pub fn new(b0: f64, b1: f64, b2: f64, a1: f64, a2: f64) -> Self {
+        Self::normalized(b0, b1, b2, 1.0, a1, a2)
+    }
+
+    /// A low-pass filter with the given cutoff frequency and Q.
+    #[cfg(any(
+        feature = "node-bass-enhancer",
+        feature = "node-crossover",
+        feature = "node-oversampled-clipper"
+    ))]
+    pub fn low_pass(sample_rate: f64, hz: f64, q: f64) -> Self {
+        let (cos, alpha) = cos_alpha(sample_rate, hz, q);
+        let b1 = 1.0 - cos;
+        Self::normalized(b1 / 2.0, b1, b1 / 2.0, 1.0 + alpha, -2.0 * cos, 1.0 - alpha)
+    }
+
+    /// A high-pass filter with the given cutoff frequency and Q.
+    #[cfg(any(
+        feature = "node-bass-enhancer",
+        feature = "node-crossover",
+        feature = "node-exciter"
+    ))]
+    pub fn high_pass(sample_rate: f64, hz: f64, q: f64) -> Self {
+        let (cos, alpha) = cos_alpha(sample_rate, hz, q);
+        let b1 = -(1.0 + cos);
+        Self::normalized(
+            -b1 / 2.0,
+            b1,
+            -b1 / 2.0,
+            1.0 + alpha,
+            -2.0 * cos,
+            1.0 - alpha,
+        )
+    }
+
+    /// A low shelf filter with the given corner frequency and gain in dB.
+    ///
+    /// Uses a shelf slope of `1.0`, the steepest slope without overshoot.
+    #[cfg(any(feature = "node-ms-eq", feature = "node-tilt"))]
+    pub fn low_shelf(sample_rate: f64, hz: f64, gain_db: f64) -> Self {
+        let a = 10f64.powf(gain_db / 40.0);
+        let (cos, alpha) = cos_alpha(sample_rate, hz, BUTTERWORTH_Q);
+        let sqrt_a_alpha = 2.0 * a.sqrt() * alpha;
+        Self::normalized(
+            a * ((a + 1.0) - (a - 1.0) * cos + sqrt_a_alpha),
+            2.0 * a * ((a - 1.0) - (a + 1.0) * cos),
+            a * ((a + 1.0) - (a - 1.0) * cos - sqrt_a_alpha),
+            (a + 1.0) + (a - 1.0) * cos + sqrt_a_alpha,
+            -2.0 * ((a - 1.0) + (a + 1.0) * cos),
+            (a + 1.0) + (a - 1.0) * cos - sqrt_a_alpha,
+        )
+    }
+
+    /// A high shelf filter with the given corner frequency and gain in dB.
+    ///
+    /// Uses a shelf slope of `1.0`, the steepest slope without overshoot.
+    #[cfg(any(feature = "node-ms-eq", feature = "node-tilt"))]
+    pub fn high_shelf(sample_rate: f64, hz: f64, gain_db: f64) -> Self {
+        let a = 10f64.powf(gain_db / 40.0);
+        let (cos, alpha) = cos_alpha(sample_rate, hz, BUTTERWORTH_Q);
+        let sqrt_a_alpha = 2.0 * a.sqrt() * alpha;
+        Self::normalized(
+            a * ((a + 1.0) + (a - 1.0) * cos + sqrt_a_alpha),
+            -2.0 * a * ((a - 1.0) + (a + 1.0) * cos),
+            a * ((a + 1.0) + (a - 1.0) * cos - sqrt_a_alpha),
+            (a + 1.0) - (a - 1.0) * cos + sqrt_a_alpha,
+            2.0 * ((a - 1.0) - (a + 1.0) * cos),
+            (a + 1.0) - (a - 1.0) * cos - sqrt_a_alpha,
+        )
+    }
+
+    fn normalized(b0: f64, b1: f64, b2: f64, a0: f64, a1: f64, a2: f64) -> Self {
+        Biquad {
+            b0: b0 / a0,
+            b1: b1 / a0,
+            b2: b2 / a0,
+            a1: a1 / a0,
+            a2: a2 / a0,
+            z1: 0.0,
+            z2: 0.0,
+        }
+    }
+
+    /// Replace the coefficients of `self` with those of `other`, retaining the filter state.
+    #[cfg(any(
+        feature = "node-bass-enhancer",
+        feature = "node-biquad",
+        feature = "node-exciter",
+        feature = "node-ms-eq",
+        feature = "node-tilt"
+    ))]
+    pub fn set_coefficients(&mut self, other: Biquad) {
+        *self = Biquad {
+            z1: self.z1,
+            z2: self.z2,
+            ..other
+        };
+    }
+
+    /// The `[b0, b1, b2, a1, a2]` coefficients of the filter, normalized such that `a0` is `1.0`.
+    #[cfg(feature = "node-biquad")]
+    pub fn coefficients(&self) -> [f64; 5] {
+        [self.b0, self.b1, self.b2, self.a1, self.a2]
+    }
+
+    /// Filter a single sample.
+    pub fn process(&mut self, x: f64) -> f64 {
+        let y = self.b0 * x + self.z1;
+        self.z1 = self.b1 * x - self.a1 * y + self.z2;
+        self.z2 = self.b2 * x - self.a2 * y;
+        y
+    }
+
+    /// Reset the filter state to silence.
+    #[cfg(any(
+        feature = "node-bass-enhancer",
+        feature = "node-biquad",
+        feature = "node-crossover",
+        feature = "node-ms-eq",
+        feature = "node-tilt"
+    ))]
+    pub fn reset(&mut self) {
+        self.z1 = 0.0;
+        self.z2 = 0.0;
+    }
+}
+
+/// A second-order state variable filter in the topology-preserving transform form.
+///
+/// The low-pass, band-pass and high-pass responses are produced simultaneously. Unlike the
+/// direct form `Biquad`, the coefficients may be changed at any rate without the filter becoming
+/// unstable, making it suitable for modulation.
+#[cfg(any(feature = "node-deesser", feature = "node-filter-morph"))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) struct Svf {
+    k: f64,
+    a1: f64,
+    a2: f64,
+    a3: f64,
+    ic1eq: f64,
+    ic2eq: f64,
+}
+
+#[cfg(any(feature = "node-deesser", feature = "node-filter-morph"))]
+impl Svf {
+    /// A filter with the given cutoff frequency and Q.
+    pub fn new(sample_rate: f64, hz: f64, q: f64) -> Self {
+        let mut svf = Svf {
+            k: 0.0,
+            a1: 0.0,
+            a2: 0.0,
+            a3: 0.0,
+            ic1eq: 0.0,
+            ic2eq: 0.0,
+        };
+        svf.set_coefficients(sample_rate, hz, q);
+        svf
+    }
+
+    /// Update the cutoff frequency and Q, retaining the filter state.
+    ///
+    /// The frequency is clamped to just below the Nyquist frequency.
+    pub fn set_coefficients(&mut self, sample_rate: f64, hz: f64, q: f64) {
+        let hz = hz.clamp(0.0, sample_rate * 0.499);
+        let g = (core::f64::consts::PI * hz / sample_rate).tan();
+        self.k = 1.0 / q;
+        self.a1 = 1.0 / (1.0 + g * (g + self.k));
+        self.a2 = g * self.a1;
+        self.a3 = g * self.a2;
+    }
+
+    /// Filter a single sample, returning the low-pass, band-pass and high-pass outputs.
+    ///
+    /// The band-pass output is normalized to unity gain at the cutoff frequency.
+    pub fn process(&mut self, x: f64) -> (f64, f64, f64) {
+        let v3 = x - self.ic2eq;
+        let v1 = self.a1 * self.ic1eq + self.a2 * v3;
+        let v2 = self.ic2eq + self.a2 * self.ic1eq + self.a3 * v3;
+        self.ic1eq = 2.0 * v1 - self.ic1eq;
+        self.ic2eq = 2.0 * v2 - self.ic2eq;
+        (v2, self.k * v1, x - self.k * v1 - v2)
+    }
+
+    /// Reset the filter state to silence.
+    pub fn reset(&mut self) {
+        self.ic1eq = 0.0;
+        self.ic2eq = 0.0;
+    }
+}
+
+#[cfg(any(
+    feature = "node-bass-enhancer",
+    feature = "node-crossover",
+    feature = "node-exciter",
+    feature = "node-ms-eq",
+    feature = "node-oversampled-clipper",
+    feature = "node-tilt"
+))]
+fn cos_alpha(sample_rate: f64, hz: f64, q: f64) -> (f64, f64) {
+    let w0 = 2.0 * core::f64::consts::PI * hz / sample_rate;
+    (w0.cos(), w0.sin() / (2.0 * q))
+}
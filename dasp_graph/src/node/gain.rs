@@ -0,0 +1,117 @@
+use crate::{Buffer, Input, Node};
+
+/// The taper used to map a fader position in the range `0.0..=1.0` to a linear gain.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum FaderLaw {
+    /// The linear gain is equal to the position.
+    Linear,
+    /// An audio (exponential) taper, where the linear gain is the cube of the position.
+    ///
+    /// This approximates the logarithmic response of a typical audio fader, with the half-way
+    /// position at roughly -18 dB.
+    Audio,
+    /// The position is mapped linearly onto a decibel scale, from `min_db` at a position of `0.0`
+    /// to 0 dB at a position of `1.0`.
+    ///
+    /// A position of `0.0` is treated as silence rather than `min_db`.
+    Decibels { min_db: f32 },
+}
+
+/// A node that applies a gain to each channel of its input.
+///
+/// The gain may be set directly via `set_gain`, or via a fader `position` mapped through the
+/// node's `FaderLaw` via `set_position`.
+///
+/// Assumes that there is one input node.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gain {
+    gain: f32,
+    law: FaderLaw,
+}
+
+impl FaderLaw {
+    /// Map the given fader `position` to a linear gain.
+    ///
+    /// The position is clamped to the range `0.0..=1.0`.
+    pub fn gain(&self, position: f32) -> f32 {
+        let position = position.clamp(0.0, 1.0);
+        match *self {
+            FaderLaw::Linear => position,
+            FaderLaw::Audio => position * position * position,
+            FaderLaw::Decibels { min_db } => {
+                if position == 0.0 {
+                    0.0
+                } else {
+                    db_to_linear(min_db * (1.0 - position))
+                }
+            }
+        }
+    }
+}
+
+impl Gain {
+    /// Create a new **Gain** node with the given linear gain, using a `FaderLaw::Audio` taper for
+    /// `set_position`.
+    pub fn new(gain: f32) -> Self {
+        Gain {
+            gain,
+            law: FaderLaw::Audio,
+        }
+    }
+
+    /// The current linear gain.
+    pub fn gain(&self) -> f32 {
+        self.gain
+    }
+
+    /// Set the linear gain directly.
+    pub fn set_gain(&mut self, gain: f32) {
+        self.gain = gain;
+    }
+
+    /// The fader law used by `set_position`.
+    pub fn law(&self) -> FaderLaw {
+        self.law
+    }
+
+    /// Set the fader law used by `set_position`.
+    pub fn set_law(&mut self, law: FaderLaw) {
+        self.law = law;
+    }
+
+    /// Set the gain from a fader position in the range `0.0..=1.0`, mapped through the fader law.
+    pub fn set_position(&mut self, position: f32) {
+        self.gain = self.law.gain(position);
+    }
+}
+
+impl Default for Gain {
+    fn default() -> Self {
+        Gain::new(1.0)
+    }
+}
+
+impl Node for Gain {
+    fn process(&mut self, inputs: &[Input], output: &mut [Buffer]) {
+        let input = match inputs.first() {
+            Some(input) => input,
+            None => {
+                output.iter_mut().for_each(|out_buf| out_buf.silence());
+                return;
+            }
+        };
+        let in_bufs = input.buffers();
+        for (out_buf, in_buf) in output.iter_mut().zip(in_bufs) {
+            for (out, &sample) in out_buf.iter_mut().zip(in_buf.iter()) {
+                *out = sample * self.gain;
+            }
+        }
+        for out_buf in output.iter_mut().skip(in_bufs.len()) {
+            out_buf.silence();
+        }
+    }
+}
+
+fn db_to_linear(db: f32) -> f32 {
+    10f32.powf(db / 20.0)
+}
@@ -0,0 +1,135 @@
+use super::block_frames;
+use crate::{Buffer, Input, Node};
+
+/// A noise gate node with optional external sidechain keying.
+///
+/// The gate opens while the detected level is at or above `threshold_db`, and closes once the
+/// level has remained below the threshold for `hold_frames` frames. Transitions in gain are
+/// smoothed using separate attack (opening) and release (closing) times.
+///
+/// ### Inputs
+///
+/// The first input is the main signal to which the gate is applied. If a second input is
+/// connected, it is used as the key signal that drives the open and close detection in place of
+/// the main signal, allowing for classic sidechain gating, e.g. gating a pad with a drum loop.
+/// Inputs are ordered by the index of their source node, so the key source node must have a
+/// greater index than the main source node. When there is no second input, the gate is keyed by
+/// the main signal itself.
+///
+/// The level of each frame is detected as the peak absolute value across all channels of the key
+/// signal, and the resulting gain is applied to every channel of the main signal. A mono key is
+/// therefore broadcast across all channels of the main signal.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gate {
+    /// The key level in dBFS at or above which the gate opens.
+    pub threshold_db: f32,
+    /// The time in frames for the gain to rise towards fully open.
+    pub attack_frames: f32,
+    /// The number of frames the gate remains open after the key level falls below the threshold.
+    pub hold_frames: usize,
+    /// The time in frames for the gain to fall towards fully closed.
+    pub release_frames: f32,
+    gain: f32,
+    hold: usize,
+}
+
+impl Gate {
+    /// The default value for `threshold_db`.
+    pub const DEFAULT_THRESHOLD_DB: f32 = -40.0;
+    /// The default value for `attack_frames`, roughly 1ms at 44.1kHz.
+    pub const DEFAULT_ATTACK_FRAMES: f32 = 44.0;
+    /// The default value for `hold_frames`, roughly 10ms at 44.1kHz.
+    pub const DEFAULT_HOLD_FRAMES: usize = 441;
+    /// The default value for `release_frames`, roughly 100ms at 44.1kHz.
+    pub const DEFAULT_RELEASE_FRAMES: f32 = 4410.0;
+
+    /// Create a new **Gate** with the given threshold in dBFS.
+    pub fn new(threshold_db: f32) -> Self {
+        Gate {
+            threshold_db,
+            attack_frames: Self::DEFAULT_ATTACK_FRAMES,
+            hold_frames: Self::DEFAULT_HOLD_FRAMES,
+            release_frames: Self::DEFAULT_RELEASE_FRAMES,
+            gain: 0.0,
+            hold: 0,
+        }
+    }
+
+    /// Whether or not the key level is currently at or above the threshold, or within the hold
+    /// period following it.
+    pub fn is_open(&self) -> bool {
+        self.hold > 0
+    }
+
+    /// The current smoothed gain, from `0.0` when fully closed to `1.0` when fully open.
+    pub fn current_gain(&self) -> f32 {
+        self.gain
+    }
+
+    /// Close the gate immediately, resetting the gain and hold period.
+    pub fn reset(&mut self) {
+        self.gain = 0.0;
+        self.hold = 0;
+    }
+}
+
+impl Default for Gate {
+    fn default() -> Self {
+        Gate::new(Self::DEFAULT_THRESHOLD_DB)
+    }
+}
+
+impl Node for Gate {
+    fn process(&mut self, inputs: &[Input], output: &mut [Buffer]) {
+        let input = match inputs.first() {
+            Some(input) => input,
+            None => {
+                output.iter_mut().for_each(|out_buf| out_buf.silence());
+                return;
+            }
+        };
+        let in_bufs = input.buffers();
+        let key_bufs = inputs.get(1).map_or(in_bufs, |key| key.buffers());
+        let threshold = db_to_linear(self.threshold_db);
+        let attack = smoothing_coefficient(self.attack_frames);
+        let release = smoothing_coefficient(self.release_frames);
+        for ix in 0..block_frames(output) {
+            let peak = key_bufs
+                .iter()
+                .fold(0.0f32, |peak, buf| peak.max(buf[ix].abs()));
+            if peak >= threshold {
+                // Include the current frame within the hold period.
+                self.hold = self.hold_frames + 1;
+            } else {
+                self.hold = self.hold.saturating_sub(1);
+            }
+            let (target, coef) = if self.hold > 0 {
+                (1.0, attack)
+            } else {
+                (0.0, release)
+            };
+            self.gain = target + coef * (self.gain - target);
+            for (out_buf, in_buf) in output.iter_mut().zip(in_bufs) {
+                out_buf[ix] = in_buf[ix] * self.gain;
+            }
+        }
+        for out_buf in output.iter_mut().skip(in_bufs.len()) {
+            out_buf.silence();
+        }
+    }
+
+    clone_state!();
+}
+
+// The one-pole smoothing coefficient for the given time constant in frames.
+fn smoothing_coefficient(frames: f32) -> f32 {
+    if frames <= 0.0 {
+        0.0
+    } else {
+        (-1.0 / frames).exp()
+    }
+}
+
+fn db_to_linear(db: f32) -> f32 {
+    10f32.powf(db / 20.0)
+}
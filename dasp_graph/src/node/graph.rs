@@ -2,10 +2,17 @@
 //!
 //! Allows for nesting subgraphs within nodes of a graph.
 
-use crate::{Buffer, Input, Node, NodeData, Processor};
+use super::block_frames;
+use crate::{tail_frames, Buffer, Input, Node, NodeData, Processor, Vec};
+#[cfg(feature = "alloc")]
+use crate::{Box, Snapshot};
+#[cfg(feature = "alloc")]
+use core::any::Any;
 use core::marker::PhantomData;
 use petgraph::data::DataMapMut;
-use petgraph::visit::{Data, GraphBase, IntoEdgesDirected, Visitable};
+use petgraph::visit::{
+    Data, GraphBase, IntoEdgesDirected, IntoNodeIdentifiers, NodeIndexable, Visitable,
+};
 
 pub struct GraphNode<G, T>
 where
@@ -20,8 +27,10 @@ where
 
 impl<G, T, I> Node<I> for GraphNode<G, T>
 where
-    G: Data<NodeWeight = NodeData<T>, EdgeWeight = I> + DataMapMut + Visitable,
-    for<'a> &'a G: GraphBase<NodeId = G::NodeId, EdgeId = G::EdgeId> + IntoEdgesDirected,
+    G: Data<NodeWeight = NodeData<T>, EdgeWeight = I> + DataMapMut + NodeIndexable + Visitable,
+    for<'a> &'a G:
+        GraphBase<NodeId = G::NodeId, EdgeId = G::EdgeId> + IntoEdgesDirected + IntoNodeIdentifiers,
+    G::NodeId: Send + 'static,
     T: Node<I>,
     I: Clone
 {
@@ -34,6 +43,9 @@ where
             ..
         } = *self;
 
+        // Process the nested graph in blocks of the same length as the outer graph.
+        processor.block_frames = block_frames(output);
+
         // Write the input buffers to the input nodes.
         for (input, &in_n) in inputs.iter().zip(input_nodes) {
             let in_node_bufs = &mut graph
@@ -41,6 +53,7 @@ where
                 .expect("no node for graph node's input node ID")
                 .buffers;
             for (in_node_buf, in_buf) in in_node_bufs.iter_mut().zip(input.buffers()) {
+                in_node_buf.set_len(in_buf.len());
                 in_node_buf.copy_from_slice(in_buf);
             }
         }
@@ -57,4 +70,20 @@ where
             out_buf.copy_from_slice(out_node_buf);
         }
     }
+
+    fn tail_frames(&self) -> Option<usize> {
+        Some(tail_frames(&self.graph, self.output_node))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn save_state(&self) -> Option<Box<dyn Any + Send>> {
+        Some(Box::new(self.processor.snapshot(&self.graph)))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn load_state(&mut self, state: &dyn Any) {
+        if let Some(snapshot) = state.downcast_ref::<Snapshot<G::NodeId>>() {
+            self.processor.restore(&mut self.graph, snapshot);
+        }
+    }
 }
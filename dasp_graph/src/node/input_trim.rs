@@ -0,0 +1,80 @@
+use crate::{Buffer, Input, Node};
+
+/// The trim applied to an input of an **InputTrim** node in decibels, given by the `Input` variant.
+///
+/// An `f32` variant converts to a trim of the same number of decibels.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct Trim(pub f32);
+
+impl Trim {
+    /// A trim of `0` dB, leaving the input unchanged.
+    pub const UNITY: Self = Trim(0.0);
+
+    /// The linear gain equivalent to the trim.
+    pub fn gain(self) -> f32 {
+        10f32.powf(self.0 / 20.0)
+    }
+}
+
+impl From<f32> for Trim {
+    fn from(db: f32) -> Self {
+        Trim(db)
+    }
+}
+
+/// A stateless node that sums each of its inputs onto the output, first applying an independent
+/// trim in decibels to each input.
+///
+/// Unlike a gain applied after summing, this allows for gain staging each source feeding a single
+/// mixing node. The trim for each input is given by its `Input::variant`, which must convert to a
+/// **Trim**. When processed by a graph `Processor`, this is the weight of the edge from the input
+/// node, i.e. the trim of each connection is assigned when adding the edge via
+/// `graph.add_edge(a, trim, Trim(-6.0))` and may be changed at any time via the graph's edge
+/// weights.
+///
+/// ```
+/// use dasp_graph::{node::{InputTrim, Trim}, Buffer, Input, Node};
+///
+/// fn main() {
+///     let a = vec![Buffer::from([0.5; Buffer::LEN])];
+///     let b = vec![Buffer::from([0.5; Buffer::LEN])];
+///     let mut output = vec![Buffer::SILENT];
+///     // Sum `a` unchanged and `b` trimmed by -20 dB.
+///     let inputs = [Input::new(&a, Trim::UNITY), Input::new(&b, Trim(-20.0))];
+///     InputTrim::new().process(&inputs, &mut output);
+///     assert!((output[0][0] - 0.55).abs() < 1e-6);
+/// }
+/// ```
+///
+/// Output channels for which an input has no buffer receive nothing from that input. No limiting
+/// is applied to the sum, leaving headroom handling to the user.
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct InputTrim;
+
+impl InputTrim {
+    /// Create a new **InputTrim**.
+    pub fn new() -> Self {
+        InputTrim
+    }
+}
+
+impl<I> Node<I> for InputTrim
+where
+    I: Clone + Into<Trim>,
+{
+    fn process(&mut self, inputs: &[Input<I>], output: &mut [Buffer]) {
+        // Fill the output with silence.
+        for out_buffer in output.iter_mut() {
+            out_buffer.silence();
+        }
+        // Sum the trimmed inputs onto the output.
+        for input in inputs {
+            let gain = input.variant.clone().into().gain();
+            for (out_buffer, in_buffer) in output.iter_mut().zip(input.buffers()) {
+                for (out, &sample) in out_buffer.iter_mut().zip(in_buffer.iter()) {
+                    *out += sample * gain;
+                }
+            }
+        }
+    }
+}
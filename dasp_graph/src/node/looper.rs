@@ -0,0 +1,207 @@
+use super::block_frames;
+use crate::{Buffer, Input, Node, Vec};
+
+/// The current state of a **Looper** node.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LooperState {
+    /// The loop is neither recording nor playing.
+    Stopped,
+    /// The input is being recorded into the loop.
+    Recording,
+    /// The loop is playing back.
+    Playing,
+    /// The loop is playing back while the input is summed into it.
+    Overdubbing,
+}
+
+/// A simple looper node with overdub support.
+///
+/// The input is always passed through to the output. While playing or overdubbing, the loop is
+/// summed onto the output in sync with the loop position.
+///
+/// The loop buffer for each channel is allocated once during construction with room for
+/// `max_len` frames, so that none of the controls require dynamic allocation.
+///
+/// Assumes that there is one input node. Channels beyond those allocated during construction are
+/// passed through without being recorded.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Looper {
+    loop_buffers: Vec<Vec<f32>>,
+    loop_len: usize,
+    position: usize,
+    state: LooperState,
+}
+
+impl Looper {
+    /// Create a new **Looper** with the given number of channels and the maximum loop length in
+    /// frames.
+    pub fn new(channels: usize, max_len: usize) -> Self {
+        Looper {
+            loop_buffers: vec![vec![0.0; max_len]; channels],
+            loop_len: 0,
+            position: 0,
+            state: LooperState::Stopped,
+        }
+    }
+
+    /// The current state of the looper.
+    pub fn state(&self) -> LooperState {
+        self.state
+    }
+
+    /// The maximum loop length in frames.
+    pub fn max_len(&self) -> usize {
+        self.loop_buffers.first().map(|b| b.len()).unwrap_or(0)
+    }
+
+    /// The current loop length in frames.
+    ///
+    /// Returns `0` if no loop has been recorded and no length has been set.
+    pub fn loop_len(&self) -> usize {
+        self.loop_len
+    }
+
+    /// The current position within the loop in frames.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Set the loop length in frames, clamped to `max_len`.
+    ///
+    /// If a length is set before recording, recording will automatically switch to playback once
+    /// this length is reached. Otherwise, recording continues until `play` or `overdub` is called
+    /// or `max_len` is reached.
+    pub fn set_loop_len(&mut self, frames: usize) {
+        self.loop_len = frames.min(self.max_len());
+        if self.loop_len == 0 {
+            self.position = 0;
+            if self.state != LooperState::Recording {
+                self.state = LooperState::Stopped;
+            }
+        } else if self.state != LooperState::Recording {
+            self.position %= self.loop_len;
+        }
+    }
+
+    /// Begin recording a new loop from the start, replacing the existing loop contents.
+    ///
+    /// Has no effect if `max_len` is `0`.
+    pub fn record(&mut self) {
+        if self.max_len() == 0 {
+            return;
+        }
+        self.position = 0;
+        self.state = LooperState::Recording;
+    }
+
+    /// Begin playing back the loop.
+    ///
+    /// If currently recording, the loop length is set to the number of frames recorded so far.
+    pub fn play(&mut self) {
+        self.end_recording();
+        self.state = if self.loop_len == 0 {
+            LooperState::Stopped
+        } else {
+            LooperState::Playing
+        };
+    }
+
+    /// Begin summing the input into the loop during playback.
+    ///
+    /// If currently recording, the loop length is set to the number of frames recorded so far.
+    pub fn overdub(&mut self) {
+        self.end_recording();
+        self.state = if self.loop_len == 0 {
+            LooperState::Stopped
+        } else {
+            LooperState::Overdubbing
+        };
+    }
+
+    /// Stop recording or playback and return to the start of the loop.
+    ///
+    /// The loop contents are retained.
+    pub fn stop(&mut self) {
+        self.end_recording();
+        self.position = 0;
+        self.state = LooperState::Stopped;
+    }
+
+    /// Stop and erase the loop, resetting the loop length to `0`.
+    pub fn clear(&mut self) {
+        for buffer in &mut self.loop_buffers {
+            for s in buffer.iter_mut() {
+                *s = 0.0;
+            }
+        }
+        self.loop_len = 0;
+        self.position = 0;
+        self.state = LooperState::Stopped;
+    }
+
+    fn end_recording(&mut self) {
+        if self.state == LooperState::Recording {
+            if self.loop_len == 0 {
+                self.loop_len = self.position;
+            }
+            self.position = 0;
+        }
+    }
+}
+
+impl Node for Looper {
+    fn process(&mut self, inputs: &[Input], output: &mut [Buffer]) {
+        // Pass the single input through to the output, ignoring any others.
+        match inputs.first() {
+            None => output.iter_mut().for_each(|out_buf| out_buf.silence()),
+            Some(input) => {
+                let in_bufs = input.buffers();
+                for (ch, out_buf) in output.iter_mut().enumerate() {
+                    match in_bufs.get(ch) {
+                        Some(in_buf) => out_buf.copy_from_slice(in_buf),
+                        None => out_buf.silence(),
+                    }
+                }
+            }
+        }
+
+        let max_len = self.max_len();
+        for ix in 0..block_frames(output) {
+            match self.state {
+                LooperState::Stopped => return,
+                LooperState::Recording => {
+                    for (loop_buf, out_buf) in self.loop_buffers.iter_mut().zip(output.iter()) {
+                        loop_buf[self.position] = out_buf[ix];
+                    }
+                    self.position += 1;
+                    let target_len = if self.loop_len == 0 {
+                        max_len
+                    } else {
+                        self.loop_len
+                    };
+                    if self.position >= target_len {
+                        self.loop_len = target_len;
+                        self.position = 0;
+                        self.state = LooperState::Playing;
+                    }
+                }
+                LooperState::Playing => {
+                    for (loop_buf, out_buf) in self.loop_buffers.iter().zip(output.iter_mut()) {
+                        out_buf[ix] += loop_buf[self.position];
+                    }
+                    self.position = (self.position + 1) % self.loop_len;
+                }
+                LooperState::Overdubbing => {
+                    for (loop_buf, out_buf) in self.loop_buffers.iter_mut().zip(output.iter_mut()) {
+                        let dry = out_buf[ix];
+                        out_buf[ix] += loop_buf[self.position];
+                        loop_buf[self.position] += dry;
+                    }
+                    self.position = (self.position + 1) % self.loop_len;
+                }
+            }
+        }
+    }
+
+    clone_state!();
+}
@@ -0,0 +1,54 @@
+use crate::{Buffer, Input, Node};
+
+/// A stateless node that sums each of its inputs onto the output, applying a per-input linear gain.
+///
+/// The gain for each input is given by its `Input::variant`. When processed by a graph
+/// `Processor`, this is the `f32` weight of the edge from the input node to the mixer, i.e. the
+/// gain of each connection is assigned when adding the edge via `graph.add_edge(a, mixer, gain)`
+/// and may be changed at any time via the graph's edge weights. When processing the node
+/// directly, the gain is the variant given to `Input::new`.
+///
+/// ```
+/// use dasp_graph::{node::Mixer, Buffer, Input, Node};
+///
+/// fn main() {
+///     let a = vec![Buffer::from([1.0; Buffer::LEN])];
+///     let b = vec![Buffer::from([1.0; Buffer::LEN]), Buffer::from([1.0; Buffer::LEN])];
+///     let mut output = vec![Buffer::SILENT; 2];
+///     // Mix `a` at half gain and `b` at a quarter gain.
+///     let inputs = [Input::new(&a, 0.5), Input::new(&b, 0.25)];
+///     Mixer::new().process(&inputs, &mut output);
+///     assert_eq!(output[0][0], 0.75);
+///     assert_eq!(output[1][0], 0.25);
+/// }
+/// ```
+///
+/// Output channels for which an input has no buffer receive nothing from that input. No limiting
+/// is applied to the sum, leaving headroom handling to the user.
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct Mixer;
+
+impl Mixer {
+    /// Create a new **Mixer**.
+    pub fn new() -> Self {
+        Mixer
+    }
+}
+
+impl Node<f32> for Mixer {
+    fn process(&mut self, inputs: &[Input<f32>], output: &mut [Buffer]) {
+        // Fill the output with silence.
+        for out_buffer in output.iter_mut() {
+            out_buffer.silence();
+        }
+        // Sum the scaled inputs onto the output.
+        for input in inputs {
+            let gain = input.variant;
+            for (out_buffer, in_buffer) in output.iter_mut().zip(input.buffers()) {
+                for (out, &sample) in out_buffer.iter_mut().zip(in_buffer.iter()) {
+                    *out += sample * gain;
+                }
+            }
+        }
+    }
+}
@@ -1,29 +1,194 @@
 use crate::buffer::Buffer;
+#[cfg(feature = "alloc")]
+use crate::Box;
+#[cfg(feature = "alloc")]
+use core::any::Any;
 use core::fmt;
 
+// Implements `Node::save_state` and `Node::load_state` by cloning the entire node, for use within
+// the `Node` implementations of nodes that are `Clone + Send + 'static`.
+#[allow(unused_macros)]
+macro_rules! clone_state {
+    () => {
+        #[cfg(feature = "alloc")]
+        fn save_state(&self) -> Option<crate::Box<dyn core::any::Any + Send>> {
+            Some(crate::Box::new(self.clone()))
+        }
+
+        #[cfg(feature = "alloc")]
+        fn load_state(&mut self, state: &dyn core::any::Any) {
+            if let Some(state) = state.downcast_ref::<Self>() {
+                self.clone_from(state);
+            }
+        }
+    };
+}
+
+#[cfg(feature = "node-agc")]
+pub use agc::Agc;
+#[cfg(feature = "async")]
+pub use asynchronous::{AsyncNode, AsyncSource, Prefill};
+#[cfg(feature = "node-balance")]
+pub use balance::Balance;
+#[cfg(feature = "node-band-split")]
+pub use band_split::{BandMerge, BandSplit};
+#[cfg(feature = "node-bass-enhancer")]
+pub use bass_enhancer::BassEnhancer;
+#[cfg(feature = "node-biquad")]
+pub use biquad::Biquad;
 #[cfg(feature = "node-boxed")]
 pub use boxed::{BoxedNode, BoxedNodeSend};
+#[cfg(feature = "node-channel-delay")]
+pub use channel_delay::ChannelDelay;
+#[cfg(feature = "node-compressor")]
+pub use compressor::Compressor;
+#[cfg(feature = "node-convolver")]
+pub use convolver::{Convolver, ConvolverRouting};
+#[cfg(feature = "node-crest-meter")]
+pub use crest_meter::{CrestMeter, CrestMeterHandle};
+#[cfg(feature = "node-crossover")]
+pub use crossover::Crossover;
+#[cfg(feature = "node-deesser")]
+pub use deesser::{DeEsser, DeEsserMode};
 #[cfg(feature = "node-delay")]
 pub use delay::Delay;
+#[cfg(feature = "node-env-vca")]
+pub use env_vca::{EnvVca, EnvVcaInput};
+#[cfg(feature = "node-exciter")]
+pub use exciter::Exciter;
+#[cfg(feature = "node-filter-morph")]
+pub use filter_morph::{FilterMode, FilterMorph, FilterShape};
+#[cfg(feature = "node-gain")]
+pub use gain::{FaderLaw, Gain};
+#[cfg(feature = "node-gate")]
+pub use gate::Gate;
 #[cfg(feature = "node-graph")]
 pub use graph::GraphNode;
+#[cfg(feature = "node-input-trim")]
+pub use input_trim::{InputTrim, Trim};
+#[cfg(feature = "node-looper")]
+pub use looper::{Looper, LooperState};
+#[cfg(feature = "node-mixer")]
+pub use mixer::Mixer;
+#[cfg(feature = "node-mono")]
+pub use mono::{Mono, MonoAttenuation};
+#[cfg(feature = "node-ms-eq")]
+pub use ms_eq::{MidSide, MidSideEq, Shelf, ShelfKind};
+#[cfg(feature = "node-null-test")]
+pub use null_test::{NullTest, NullTestHandle};
+#[cfg(feature = "node-oversampled-clipper")]
+pub use oversampled_clipper::{ClipShape, Oversample, OversampledClipper};
 #[cfg(feature = "node-pass")]
 pub use pass::Pass;
+#[cfg(feature = "node-safe-gain")]
+pub use safe_gain::SafeGain;
+#[cfg(feature = "node-sample-player")]
+pub use sample_player::SamplePlayer;
+#[cfg(feature = "node-signal")]
+pub use signal::SignalNode;
+#[cfg(feature = "node-silence-detect")]
+pub use silence_detect::{SilenceDetect, SilenceDetectEvent};
+#[cfg(feature = "node-slew-limit")]
+pub use slew_limit::SlewLimit;
 #[cfg(feature = "node-sum")]
-pub use sum::{Sum, SumBuffers};
+pub use sum::{Sum, SumBroadcast, SumBuffers};
+#[cfg(feature = "node-table-shaper")]
+pub use table_shaper::TableShaper;
+#[cfg(feature = "node-tilt")]
+pub use tilt::Tilt;
+#[cfg(feature = "node-tremolo")]
+pub use tremolo::{Tremolo, TremoloRate, TremoloWaveform};
+#[cfg(feature = "node-vibrato")]
+pub use vibrato::{Vibrato, VibratoWaveform};
 
+#[cfg(feature = "node-agc")]
+mod agc;
+#[cfg(feature = "async")]
+mod asynchronous;
+#[cfg(feature = "node-balance")]
+mod balance;
+#[cfg(feature = "node-band-split")]
+mod band_split;
+#[cfg(feature = "node-bass-enhancer")]
+mod bass_enhancer;
+#[cfg(feature = "node-biquad")]
+mod biquad;
 #[cfg(feature = "node-boxed")]
 mod boxed;
+#[cfg(feature = "node-channel-delay")]
+mod channel_delay;
+#[cfg(feature = "node-compressor")]
+mod compressor;
+#[cfg(feature = "node-convolver")]
+mod convolver;
+#[cfg(feature = "node-crest-meter")]
+mod crest_meter;
+#[cfg(feature = "node-crossover")]
+mod crossover;
+#[cfg(feature = "node-deesser")]
+mod deesser;
 #[cfg(feature = "node-delay")]
 mod delay;
+#[cfg(feature = "node-env-vca")]
+mod env_vca;
+#[cfg(feature = "node-exciter")]
+mod exciter;
+#[cfg(any(
+    feature = "node-bass-enhancer",
+    feature = "node-biquad",
+    feature = "node-crossover",
+    feature = "node-deesser",
+    feature = "node-exciter",
+    feature = "node-filter-morph",
+    feature = "node-ms-eq",
+    feature = "node-oversampled-clipper",
+    feature = "node-tilt"
+))]
+mod filter;
+#[cfg(feature = "node-filter-morph")]
+mod filter_morph;
+#[cfg(feature = "node-gain")]
+mod gain;
+#[cfg(feature = "node-gate")]
+mod gate;
 #[cfg(feature = "node-graph")]
 mod graph;
+#[cfg(feature = "node-input-trim")]
+mod input_trim;
+#[cfg(feature = "node-looper")]
+mod looper;
+#[cfg(feature = "node-mixer")]
+mod mixer;
+#[cfg(feature = "node-mono")]
+mod mono;
+#[cfg(feature = "node-ms-eq")]
+mod ms_eq;
+#[cfg(feature = "node-null-test")]
+mod null_test;
+#[cfg(feature = "node-oversampled-clipper")]
+mod oversampled_clipper;
 #[cfg(feature = "node-pass")]
 mod pass;
+#[cfg(feature = "node-safe-gain")]
+mod safe_gain;
+#[cfg(feature = "node-sample-player")]
+mod sample_player;
 #[cfg(feature = "node-signal")]
 mod signal;
+#[cfg(feature = "node-silence-detect")]
+mod silence_detect;
+#[cfg(feature = "node-slew-limit")]
+mod slew_limit;
 #[cfg(feature = "node-sum")]
 mod sum;
+#[cfg(feature = "node-table-shaper")]
+mod table_shaper;
+#[cfg(feature = "node-tilt")]
+mod tilt;
+#[cfg(feature = "node-tremolo")]
+mod tremolo;
+#[cfg(feature = "node-vibrato")]
+mod vibrato;
 
 /// The `Node` type used within a dasp graph must implement this trait.
 ///
@@ -76,13 +241,81 @@ pub trait Node<I = ()> {
     /// [`Input`](./struct.Input.html) within the list can providee a reference to the output
     /// buffers of their corresponding node.
     ///
+    /// When called by the [`Processor`](../struct.Processor.html), the `inputs` are ordered by the
+    /// index of their source node within the graph, as given by petgraph's `NodeIndexable` trait.
+    /// This ordering is stable across calls and across rebuilds of the same graph, allowing nodes
+    /// to treat each input differently, e.g. a main input and a sidechain input. Multiple edges
+    /// from the same source node are ordered as they are yielded by the graph. The position of
+    /// each input within the list is also available via `Input::index`.
+    ///
     /// The `inputs` may be ignored if the implementation is for a source node. Alternatively, if
     /// the `Node` only supports a specific number of `input`s, it is up to the user to decide how
     /// they wish to enforce this or provide feedback at the time of graph and edge creation.
     ///
     /// This `process` method is called by the [`Processor`](../struct.Processor.html) as it
     /// traverses the graph during audio rendering.
+    ///
+    /// The number of frames to process is given by the length of the buffers, which is set by the
+    /// **Processor** to its block size at runtime. Implementations should read this length from
+    /// the given buffers, e.g. `output[0].len()`, rather than assuming `Buffer::LEN`. Nodes that
+    /// previously iterated over `0..Buffer::LEN` may simply iterate over `0..output[0].len()`, or
+    /// zip the input and output buffers together.
     fn process(&mut self, inputs: &[Input<I>], output: &mut [Buffer]);
+
+    /// The number of frames for which the node may continue to produce output after its inputs
+    /// become silent, e.g. the length of a delay line or the decay of a reverb.
+    ///
+    /// This allows for rendering exactly enough frames to capture the tail of a graph during an
+    /// offline bounce. See `Processor::tail_frames`.
+    ///
+    /// The default implementation returns `None`, indicating that the node has no tail.
+    fn tail_frames(&self) -> Option<usize> {
+        None
+    }
+
+    /// The exact number of inputs that the node expects.
+    ///
+    /// This allows for catching misconfigured graphs before rendering. See `dasp_graph::validate`.
+    ///
+    /// The default implementation returns `None`, indicating that any number of inputs is
+    /// accepted.
+    fn expected_inputs(&self) -> Option<usize> {
+        None
+    }
+
+    /// The number of buffers, i.e. channels, that the node expects of each of its inputs.
+    ///
+    /// This allows for catching misconfigured graphs before rendering. See `dasp_graph::validate`.
+    ///
+    /// The default implementation returns `None`, indicating that inputs with any number of
+    /// channels are accepted.
+    fn expected_input_channels(&self) -> Option<usize> {
+        None
+    }
+
+    /// Capture the internal state of the node, e.g. the contents of its delay lines, the history
+    /// of its filters and the phase of its oscillators.
+    ///
+    /// The returned state may later be passed to `load_state` in order to return the node to the
+    /// state at the time of capture. This allows for undo and redo, and for seeking within
+    /// deterministic offline renders. See `Processor::snapshot`.
+    ///
+    /// For nodes that are `Clone`, the simplest implementation is to return a clone of the node,
+    /// in which case the parameters of the node are restored along with its state.
+    ///
+    /// The default implementation returns `None`, indicating that the node has no state to save.
+    #[cfg(feature = "alloc")]
+    fn save_state(&self) -> Option<Box<dyn Any + Send>> {
+        None
+    }
+
+    /// Restore the internal state of the node from a state previously returned by `save_state`.
+    ///
+    /// States of an unexpected type should be ignored.
+    ///
+    /// The default implementation does nothing.
+    #[cfg(feature = "alloc")]
+    fn load_state(&mut self, _state: &dyn Any) {}
 }
 
 /// A reference to another node that is an input to the current node.
@@ -90,6 +323,7 @@ pub struct Input<T = ()> {
     pub variant: T,
     buffers_ptr: *const Buffer,
     buffers_len: usize,
+    pub(crate) index: usize,
 }
 
 impl<T> Input<T> {
@@ -101,15 +335,24 @@ impl<T> Input<T> {
             variant,
             buffers_ptr,
             buffers_len,
+            index: 0,
         }
     }
 
+    /// The position of this input within the `inputs` passed to `Node::process`.
+    ///
+    /// See the `Node::process` docs for the ordering of inputs. Inputs constructed directly via
+    /// `Input::new` have an index of `0`.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
     /// A reference to the buffers of the input node.
     pub fn buffers(&self) -> &[Buffer] {
         // As we know that an `Input` can only be constructed during a call to the graph `process`
         // function, we can be sure that our slice is still valid as long as the input itself is
         // alive.
-        unsafe { std::slice::from_raw_parts(self.buffers_ptr, self.buffers_len) }
+        unsafe { core::slice::from_raw_parts(self.buffers_ptr, self.buffers_len) }
     }
 }
 
@@ -124,6 +367,31 @@ impl fmt::Debug for Input {
     }
 }
 
+// The number of frames to process given the output buffers of a node.
+//
+// Nodes without any output buffers assume the default of `Buffer::LEN` frames.
+#[cfg(any(
+    feature = "node-agc",
+    feature = "node-compressor",
+    feature = "node-convolver",
+    feature = "node-crest-meter",
+    feature = "node-deesser",
+    feature = "node-env-vca",
+    feature = "node-gate",
+    feature = "node-graph",
+    feature = "node-looper",
+    feature = "node-null-test",
+    feature = "node-safe-gain",
+    feature = "node-sample-player",
+    feature = "node-signal",
+    feature = "node-silence-detect",
+    feature = "node-tremolo",
+    feature = "node-vibrato"
+))]
+pub(crate) fn block_frames(output: &[Buffer]) -> usize {
+    output.first().map_or(Buffer::LEN, |buf| buf.len())
+}
+
 impl<'a, T, I> Node<I> for &'a mut T
 where
     T: Node<I> + ?Sized,
@@ -131,8 +399,31 @@ where
     fn process(&mut self, inputs: &[Input<I>], output: &mut [Buffer]) {
         (**self).process(inputs, output)
     }
+
+    fn tail_frames(&self) -> Option<usize> {
+        (**self).tail_frames()
+    }
+
+    fn expected_inputs(&self) -> Option<usize> {
+        (**self).expected_inputs()
+    }
+
+    fn expected_input_channels(&self) -> Option<usize> {
+        (**self).expected_input_channels()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn save_state(&self) -> Option<Box<dyn Any + Send>> {
+        (**self).save_state()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn load_state(&mut self, state: &dyn Any) {
+        (**self).load_state(state)
+    }
 }
 
+#[cfg(feature = "alloc")]
 impl<T, I> Node<I> for Box<T>
 where
     T: Node<I> + ?Sized,
@@ -140,6 +431,28 @@ where
     fn process(&mut self, inputs: &[Input<I>], output: &mut [Buffer]) {
         (**self).process(inputs, output)
     }
+
+    fn tail_frames(&self) -> Option<usize> {
+        (**self).tail_frames()
+    }
+
+    fn expected_inputs(&self) -> Option<usize> {
+        (**self).expected_inputs()
+    }
+
+    fn expected_input_channels(&self) -> Option<usize> {
+        (**self).expected_input_channels()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn save_state(&self) -> Option<Box<dyn Any + Send>> {
+        (**self).save_state()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn load_state(&mut self, state: &dyn Any) {
+        (**self).load_state(state)
+    }
 }
 
 impl<I> Node<I> for dyn Fn(&[Input<I>], &mut [Buffer]) {
@@ -0,0 +1,153 @@
+use crate::{Buffer, Input, Node};
+
+/// The attenuation applied to the sum of the two channels by a **Mono** node.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MonoAttenuation {
+    /// The channels are summed without attenuation. Identical channels double in level.
+    ZeroDb,
+    /// The sum is attenuated by 3 dB, preserving the power of uncorrelated channels.
+    Minus3Db,
+    /// The sum is attenuated by 6 dB, i.e. the average of the channels. Identical channels retain
+    /// their level, so the output never exceeds the louder of the two inputs.
+    Minus6Db,
+}
+
+/// Sums a stereo input to mono.
+///
+/// The sum of the first two input channels is attenuated according to `attenuation` and written
+/// to every output buffer. If the input has a single channel, it is written to every output buffer
+/// unchanged.
+///
+/// Content that is in anti-phase between the channels cancels when summed, e.g. a stereo widener
+/// or a miswired microphone may cause instruments to disappear in mono. To detect this, the
+/// correlation between the channels is measured continuously, ranging from `1.0` for identical
+/// channels, through `0.0` for unrelated channels, to `-1.0` for channels in anti-phase. When
+/// the correlation falls below `anti_phase_threshold`, `is_anti_phase` returns `true`.
+///
+/// If `phase_safe` is enabled, the output is additionally corrected as the correlation falls below
+/// the threshold, crossfading from the sum towards the left channel alone so that anti-phase
+/// content is not lost. The crossfade follows the smoothed correlation and so is click-free.
+///
+/// Assumes that there is one input node.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mono {
+    /// The attenuation applied to the sum of the channels.
+    pub attenuation: MonoAttenuation,
+    /// Whether or not to correct the output when anti-phase content is detected.
+    pub phase_safe: bool,
+    /// The correlation below which the channels are considered to be in anti-phase.
+    pub anti_phase_threshold: f32,
+    /// The time constant in frames over which the correlation is measured.
+    pub correlation_frames: f32,
+    // The smoothed products of the channels, used to measure correlation.
+    lr: f32,
+    ll: f32,
+    rr: f32,
+}
+
+impl Mono {
+    /// The default value for `anti_phase_threshold`.
+    pub const DEFAULT_ANTI_PHASE_THRESHOLD: f32 = -0.5;
+    /// The default value for `correlation_frames`, roughly 100ms at 44.1kHz.
+    pub const DEFAULT_CORRELATION_FRAMES: f32 = 4_410.0;
+
+    /// Create a new **Mono** node with the given attenuation.
+    pub fn new(attenuation: MonoAttenuation) -> Self {
+        Mono {
+            attenuation,
+            phase_safe: false,
+            anti_phase_threshold: Self::DEFAULT_ANTI_PHASE_THRESHOLD,
+            correlation_frames: Self::DEFAULT_CORRELATION_FRAMES,
+            lr: 0.0,
+            ll: 0.0,
+            rr: 0.0,
+        }
+    }
+
+    /// The current correlation between the two channels in the range `-1.0..=1.0`.
+    ///
+    /// This is `0.0` while either channel is silent.
+    pub fn correlation(&self) -> f32 {
+        let power = (self.ll * self.rr).sqrt();
+        if power > 1e-12 {
+            (self.lr / power).clamp(-1.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// Whether or not the correlation is currently below `anti_phase_threshold`, indicating that
+    /// content will cancel when summed to mono.
+    pub fn is_anti_phase(&self) -> bool {
+        self.correlation() < self.anti_phase_threshold
+    }
+
+    /// Reset the correlation measurement.
+    pub fn reset(&mut self) {
+        self.lr = 0.0;
+        self.ll = 0.0;
+        self.rr = 0.0;
+    }
+
+    /// The gain applied to the sum of the channels.
+    pub fn gain(&self) -> f32 {
+        match self.attenuation {
+            MonoAttenuation::ZeroDb => 1.0,
+            MonoAttenuation::Minus3Db => core::f32::consts::FRAC_1_SQRT_2,
+            MonoAttenuation::Minus6Db => 0.5,
+        }
+    }
+}
+
+impl Default for Mono {
+    fn default() -> Self {
+        Mono::new(MonoAttenuation::Minus6Db)
+    }
+}
+
+impl Node for Mono {
+    fn process(&mut self, inputs: &[Input], output: &mut [Buffer]) {
+        let (out_buf, rest) = match output.split_first_mut() {
+            Some(split) => split,
+            None => return,
+        };
+        let in_bufs = inputs.first().map_or(&[][..], |input| input.buffers());
+        match in_bufs {
+            [] => out_buf.silence(),
+            [in_buf] => out_buf.copy_from_slice(in_buf),
+            [l_buf, r_buf, ..] => {
+                let coef = smoothing_coefficient(self.correlation_frames);
+                let gain = self.gain();
+                let threshold = self.anti_phase_threshold.clamp(-1.0, 1.0);
+                for (out, (&l, &r)) in out_buf.iter_mut().zip(l_buf.iter().zip(r_buf.iter())) {
+                    self.lr = l * r + coef * (self.lr - l * r);
+                    self.ll = l * l + coef * (self.ll - l * l);
+                    self.rr = r * r + coef * (self.rr - r * r);
+                    let sum = l + r;
+                    *out = if self.phase_safe && self.correlation() < threshold {
+                        // Fade towards the left channel alone as the correlation approaches -1.
+                        let w = (threshold - self.correlation()) / (threshold + 1.0).max(1e-6);
+                        let w = w.min(1.0);
+                        gain * (sum + w * (2.0 * l - sum))
+                    } else {
+                        gain * sum
+                    };
+                }
+            }
+        }
+        for buf in rest {
+            buf.copy_from_slice(out_buf);
+        }
+    }
+
+    clone_state!();
+}
+
+// The one-pole smoothing coefficient for the given time constant in frames.
+fn smoothing_coefficient(frames: f32) -> f32 {
+    if frames <= 0.0 {
+        0.0
+    } else {
+        (-1.0 / frames).exp()
+    }
+}
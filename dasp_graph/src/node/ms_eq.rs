@@ -0,0 +1,148 @@
+use super::filter::Biquad;
+use crate::{Buffer, Input, Node};
+
+/// One of the two components of a mid-side encoded stereo signal.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MidSide {
+    /// The content common to both channels, i.e. `(l + r) / 2`.
+    Mid,
+    /// The content that differs between the channels, i.e. `(l - r) / 2`.
+    Side,
+}
+
+/// Selects one of the two shelving filters applied to each component by a **MidSideEq**.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ShelfKind {
+    /// Boosts or cuts content below the shelf frequency.
+    Low,
+    /// Boosts or cuts content above the shelf frequency.
+    High,
+}
+
+/// The parameters of a single shelving filter.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Shelf {
+    /// The corner frequency of the shelf in Hz.
+    pub frequency: f64,
+    /// The gain applied beyond the corner frequency in dB.
+    pub gain_db: f64,
+}
+
+/// A stereo enhancer that applies independent EQ to the mid and side components of a signal.
+///
+/// The first two channels of the input are decoded from left/right to mid/side, a low shelf and a
+/// high shelf are applied to each of the two components and the result is encoded back to
+/// left/right. E.g. boosting the side high shelf widens the top end of a mix while leaving
+/// centred content, such as a lead vocal, untouched.
+///
+/// All shelves are flat by default, in which case the output is identical to the input.
+///
+/// Requires a 2-channel input and output. If the input has fewer than two channels it is passed
+/// through unchanged. Output channels beyond the first two are silenced.
+///
+/// Assumes that there is one input node.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MidSideEq {
+    sample_rate: f64,
+    // The shelves indexed by `[component][kind]`.
+    shelves: [[Shelf; 2]; 2],
+    filters: [[Biquad; 2]; 2],
+}
+
+impl MidSideEq {
+    /// The default low shelf frequency in Hz.
+    pub const DEFAULT_LOW_FREQUENCY: f64 = 200.0;
+    /// The default high shelf frequency in Hz.
+    pub const DEFAULT_HIGH_FREQUENCY: f64 = 5_000.0;
+
+    /// Create a new **MidSideEq** for the given sample rate with all shelves flat.
+    pub fn new(sample_rate: f64) -> Self {
+        let low = Shelf {
+            frequency: Self::DEFAULT_LOW_FREQUENCY,
+            gain_db: 0.0,
+        };
+        let high = Shelf {
+            frequency: Self::DEFAULT_HIGH_FREQUENCY,
+            gain_db: 0.0,
+        };
+        let filters = [
+            Biquad::low_shelf(sample_rate, low.frequency, low.gain_db),
+            Biquad::high_shelf(sample_rate, high.frequency, high.gain_db),
+        ];
+        MidSideEq {
+            sample_rate,
+            shelves: [[low, high]; 2],
+            filters: [filters; 2],
+        }
+    }
+
+    /// The sample rate in Hz.
+    pub fn sample_rate(&self) -> f64 {
+        self.sample_rate
+    }
+
+    /// The parameters of the given shelf of the given component.
+    pub fn shelf(&self, component: MidSide, kind: ShelfKind) -> Shelf {
+        self.shelves[component as usize][kind as usize]
+    }
+
+    /// Set the parameters of the given shelf of the given component.
+    ///
+    /// The state of the filter is retained.
+    pub fn set_shelf(&mut self, component: MidSide, kind: ShelfKind, shelf: Shelf) {
+        let (c, k) = (component as usize, kind as usize);
+        let coefficients = match kind {
+            ShelfKind::Low => Biquad::low_shelf(self.sample_rate, shelf.frequency, shelf.gain_db),
+            ShelfKind::High => Biquad::high_shelf(self.sample_rate, shelf.frequency, shelf.gain_db),
+        };
+        self.shelves[c][k] = shelf;
+        self.filters[c][k].set_coefficients(coefficients);
+    }
+
+    /// Reset the state of all filters.
+    pub fn reset(&mut self) {
+        for filter in self.filters.iter_mut().flatten() {
+            filter.reset();
+        }
+    }
+}
+
+impl Node for MidSideEq {
+    fn process(&mut self, inputs: &[Input], output: &mut [Buffer]) {
+        let input = match inputs.first() {
+            Some(input) => input,
+            None => {
+                output.iter_mut().for_each(|out_buf| out_buf.silence());
+                return;
+            }
+        };
+        let in_bufs = input.buffers();
+        if in_bufs.len() < 2 || output.len() < 2 {
+            for (out_buf, in_buf) in output.iter_mut().zip(in_bufs) {
+                out_buf.copy_from_slice(in_buf);
+            }
+            for out_buf in output.iter_mut().skip(in_bufs.len()) {
+                out_buf.silence();
+            }
+            return;
+        }
+        let [mid_filters, side_filters] = &mut self.filters;
+        let (out_l, out_r) = output.split_at_mut(1);
+        for ix in 0..out_l[0].len() {
+            let (l, r) = (in_bufs[0][ix] as f64, in_bufs[1][ix] as f64);
+            let mid = mid_filters
+                .iter_mut()
+                .fold((l + r) * 0.5, |x, filter| filter.process(x));
+            let side = side_filters
+                .iter_mut()
+                .fold((l - r) * 0.5, |x, filter| filter.process(x));
+            out_l[0][ix] = (mid + side) as f32;
+            out_r[0][ix] = (mid - side) as f32;
+        }
+        for out_buf in output.iter_mut().skip(2) {
+            out_buf.silence();
+        }
+    }
+
+    clone_state!();
+}
@@ -0,0 +1,172 @@
+use super::block_frames;
+use crate::{Buffer, Input, Node, Vec};
+#[cfg(not(feature = "std"))]
+use alloc::sync::Arc;
+use core::sync::atomic::{AtomicU32, Ordering};
+#[cfg(feature = "std")]
+use std::sync::Arc;
+
+/// A null test for verifying that processing is transparent, outputting the difference between a
+/// reference signal and a processed version of it.
+///
+/// The first input is the reference and the second input is the processed signal, ordered as
+/// described by `Node::process`. Before taking the difference, the two are aligned:
+///
+/// - The reference is delayed by `latency_frames` to compensate for the latency of the processing.
+/// - If `gain_match` is enabled, the processed signal is scaled by the least-squares gain that best
+///   matches it to the reference within each block. A negative gain corrects inverted polarity.
+///
+/// If the processing is transparent, the output is silent. The RMS of the residual across all
+/// channels is measured once per processed block and published to an atomic, so that it may be
+/// read from another thread via a **NullTestHandle** without locking.
+///
+/// The delay line for each channel is allocated during construction. Channels beyond those
+/// allocated are silenced and excluded from the measurement.
+#[derive(Clone, Debug)]
+pub struct NullTest {
+    /// Whether the gain of the processed signal is matched to the reference before taking the
+    /// difference.
+    pub gain_match: bool,
+    delays: Vec<Vec<f32>>,
+    next: usize,
+    gain: f32,
+    residual: Arc<AtomicU32>,
+}
+
+/// A handle for reading the residual measured by a **NullTest** from any thread.
+#[derive(Clone, Debug)]
+pub struct NullTestHandle {
+    residual: Arc<AtomicU32>,
+}
+
+impl NullTest {
+    /// Create a new **NullTest** for the given number of channels, delaying the reference by the
+    /// given latency in frames.
+    pub fn new(channels: usize, latency_frames: usize) -> Self {
+        NullTest {
+            gain_match: true,
+            delays: vec![vec![0.0; latency_frames]; channels],
+            next: 0,
+            gain: 1.0,
+            residual: Arc::new(AtomicU32::new(0f32.to_bits())),
+        }
+    }
+
+    /// The latency in frames by which the reference is delayed.
+    pub fn latency_frames(&self) -> usize {
+        self.delays.first().map_or(0, |delay| delay.len())
+    }
+
+    /// The gain applied to the processed signal during the most recent block.
+    ///
+    /// This is `1.0` while `gain_match` is disabled.
+    pub fn gain(&self) -> f32 {
+        self.gain
+    }
+
+    /// A handle for reading the measured residual, e.g. from a UI thread.
+    pub fn handle(&self) -> NullTestHandle {
+        NullTestHandle {
+            residual: self.residual.clone(),
+        }
+    }
+
+    /// The RMS of the residual during the most recent block.
+    pub fn residual_rms(&self) -> f32 {
+        load(&self.residual)
+    }
+
+    /// The RMS of the residual during the most recent block in dB.
+    pub fn residual_db(&self) -> f32 {
+        linear_to_db(self.residual_rms())
+    }
+
+    /// Clear the delay lines and the measured residual.
+    pub fn reset(&mut self) {
+        for delay in &mut self.delays {
+            delay.iter_mut().for_each(|s| *s = 0.0);
+        }
+        self.next = 0;
+        self.gain = 1.0;
+        self.residual.store(0f32.to_bits(), Ordering::Relaxed);
+    }
+}
+
+impl NullTestHandle {
+    /// The RMS of the residual during the most recent block.
+    pub fn residual_rms(&self) -> f32 {
+        load(&self.residual)
+    }
+
+    /// The RMS of the residual during the most recent block in dB.
+    pub fn residual_db(&self) -> f32 {
+        linear_to_db(self.residual_rms())
+    }
+}
+
+impl Node for NullTest {
+    fn process(&mut self, inputs: &[Input], output: &mut [Buffer]) {
+        let reference = inputs.first().map_or(&[][..], |input| input.buffers());
+        let processed = inputs.get(1).map_or(&[][..], |input| input.buffers());
+        let channels = output.len().min(self.delays.len());
+        let frames = block_frames(output);
+        let latency = self.latency_frames();
+
+        // Write the delayed reference to the output.
+        for (ch, out_buf) in output.iter_mut().take(channels).enumerate() {
+            let delay = &mut self.delays[ch];
+            let mut next = self.next;
+            for (ix, out) in out_buf.iter_mut().enumerate() {
+                let sample = reference.get(ch).map_or(0.0, |buf| buf[ix]);
+                *out = match delay.get_mut(next) {
+                    Some(delayed) => core::mem::replace(delayed, sample),
+                    None => sample,
+                };
+                next = (next + 1) % latency.max(1);
+            }
+        }
+        self.next = (self.next + frames) % latency.max(1);
+
+        // Find the gain that best matches the processed signal to the reference.
+        self.gain = 1.0;
+        if self.gain_match {
+            let (mut cross, mut energy) = (0.0, 0.0);
+            for (out_buf, in_buf) in output.iter().take(channels).zip(processed) {
+                for (&r, &p) in out_buf.iter().zip(in_buf.iter()) {
+                    cross += r as f64 * p as f64;
+                    energy += p as f64 * p as f64;
+                }
+            }
+            if energy > 0.0 {
+                self.gain = (cross / energy) as f32;
+            }
+        }
+
+        // Subtract the processed signal and measure the residual.
+        let mut sum_squares = 0.0;
+        for (out_buf, in_buf) in output.iter_mut().take(channels).zip(processed) {
+            for (out, &p) in out_buf.iter_mut().zip(in_buf.iter()) {
+                *out -= self.gain * p;
+            }
+        }
+        for out_buf in output.iter().take(channels) {
+            sum_squares += out_buf.iter().map(|&s| s as f64 * s as f64).sum::<f64>();
+        }
+        for out_buf in output.iter_mut().skip(channels) {
+            out_buf.silence();
+        }
+        let samples = (channels * frames).max(1) as f64;
+        let rms = (sum_squares / samples).sqrt() as f32;
+        self.residual.store(rms.to_bits(), Ordering::Relaxed);
+    }
+
+    clone_state!();
+}
+
+fn load(residual: &AtomicU32) -> f32 {
+    f32::from_bits(residual.load(Ordering::Relaxed))
+}
+
+fn linear_to_db(linear: f32) -> f32 {
+    20.0 * linear.max(1e-10).log10()
+}
@@ -0,0 +1,103 @@
+use super::block_frames;
+use crate::{Buffer, Input, Node};
+
+/// A gain with dezippered changes and an optional soft limit, suitable as the final stage of a
+/// channel or master output.
+///
+/// Changes to the gain via `set_gain` are not applied immediately, as a sudden jump in gain is
+/// heard as a click or "zipper" noise. Instead, the gain ramps linearly from its current value to
+/// the new target over `smoothing_frames`.
+///
+/// When `soft_limit` is enabled, samples whose magnitude exceeds `SafeGain::KNEE` are smoothly
+/// compressed towards a ceiling of `1.0`, so that out-of-range samples are never sent downstream.
+/// Samples below the knee are unaffected.
+///
+/// Assumes that there is one input node.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SafeGain {
+    /// The duration in frames of the ramp towards a new gain.
+    pub smoothing_frames: f32,
+    /// Whether or not to soft limit the output to the range `-1.0..=1.0`.
+    pub soft_limit: bool,
+    target: f32,
+    current: f32,
+    step: f32,
+}
+
+impl SafeGain {
+    /// The default value for `smoothing_frames`, roughly 10ms at 44.1kHz.
+    pub const DEFAULT_SMOOTHING_FRAMES: f32 = 441.0;
+    /// The magnitude above which the soft limit begins to take effect.
+    pub const KNEE: f32 = 0.9;
+
+    /// Create a new **SafeGain** with the given linear gain and the soft limit enabled.
+    pub fn new(gain: f32) -> Self {
+        SafeGain {
+            smoothing_frames: Self::DEFAULT_SMOOTHING_FRAMES,
+            soft_limit: true,
+            target: gain,
+            current: gain,
+            step: 0.0,
+        }
+    }
+
+    /// The target linear gain.
+    pub fn gain(&self) -> f32 {
+        self.target
+    }
+
+    /// Set the target linear gain, towards which the current gain ramps over `smoothing_frames`.
+    pub fn set_gain(&mut self, gain: f32) {
+        self.target = gain;
+        self.step = (gain - self.current).abs() / self.smoothing_frames.max(1.0);
+    }
+
+    /// The current linear gain, which differs from the target while ramping.
+    pub fn current_gain(&self) -> f32 {
+        self.current
+    }
+
+    /// Jump immediately to the target gain, ending any ramp in progress.
+    pub fn reset(&mut self) {
+        self.current = self.target;
+    }
+
+    /// Apply the soft limit to the given sample.
+    pub fn limit(x: f32) -> f32 {
+        let magnitude = x.abs();
+        if magnitude <= Self::KNEE {
+            return x;
+        }
+        let range = 1.0 - Self::KNEE;
+        let limited = Self::KNEE + range * ((magnitude - Self::KNEE) / range).tanh();
+        limited.min(1.0).copysign(x)
+    }
+}
+
+impl Default for SafeGain {
+    fn default() -> Self {
+        SafeGain::new(1.0)
+    }
+}
+
+impl Node for SafeGain {
+    fn process(&mut self, inputs: &[Input], output: &mut [Buffer]) {
+        let in_bufs = inputs.first().map_or(&[][..], |input| input.buffers());
+        for ix in 0..block_frames(output) {
+            if self.current < self.target {
+                self.current = (self.current + self.step).min(self.target);
+            } else if self.current > self.target {
+                self.current = (self.current - self.step).max(self.target);
+            }
+            for (out_buf, in_buf) in output.iter_mut().zip(in_bufs) {
+                let x = in_buf[ix] * self.current;
+                out_buf[ix] = if self.soft_limit { Self::limit(x) } else { x };
+            }
+        }
+        for out_buf in output.iter_mut().skip(in_bufs.len()) {
+            out_buf.silence();
+        }
+    }
+
+    clone_state!();
+}
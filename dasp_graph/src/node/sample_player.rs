@@ -0,0 +1,148 @@
+use super::block_frames;
+use crate::{Buffer, Input, Node, Vec};
+
+/// A one-shot sample player with polyphonic retriggering, e.g. for a drum sampler voice.
+///
+/// The first channel of the first input is treated as a trigger signal. Each time the trigger
+/// rises from `0.0` or below to above `0.0`, a new voice begins playing the sample from the start
+/// at that exact frame. The value of the trigger at this frame scales the gain of the voice,
+/// allowing for velocity. Voices may also be started via the `trigger` method.
+///
+/// Each voice plays the sample once at the playback rate given by `pitch`, using linear
+/// interpolation between frames. Voices overlap, up to the voice limit given at construction. If
+/// a trigger arrives while all voices are busy, the voice that has played for the longest is
+/// restarted.
+///
+/// Output channel `n` plays sample channel `n % sample_channels`, so that a mono sample is
+/// written to all output channels.
+///
+/// All voices are allocated during construction, so processing never requires dynamic
+/// allocation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SamplePlayer {
+    /// The playback rate, where `1.0` plays the sample at its original pitch and `2.0` plays it an
+    /// octave higher.
+    pub pitch: f64,
+    /// The gain applied to all voices.
+    pub gain: f32,
+    sample: Vec<Vec<f32>>,
+    voices: Vec<Voice>,
+    pending_triggers: usize,
+    last_trigger: f32,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct Voice {
+    position: f64,
+    gain: f32,
+    active: bool,
+}
+
+impl SamplePlayer {
+    /// Create a new **SamplePlayer** for the given sample, where each `Vec` is a channel, allowing
+    /// up to `max_voices` overlapping voices.
+    pub fn new(sample: Vec<Vec<f32>>, max_voices: usize) -> Self {
+        let voice = Voice {
+            position: 0.0,
+            gain: 0.0,
+            active: false,
+        };
+        SamplePlayer {
+            pitch: 1.0,
+            gain: 1.0,
+            sample,
+            voices: vec![voice; max_voices],
+            pending_triggers: 0,
+            last_trigger: 0.0,
+        }
+    }
+
+    /// The maximum number of overlapping voices.
+    pub fn max_voices(&self) -> usize {
+        self.voices.len()
+    }
+
+    /// The number of voices that are currently playing.
+    pub fn active_voices(&self) -> usize {
+        self.voices.iter().filter(|v| v.active).count()
+    }
+
+    /// Start a new voice at full velocity from the first frame of the next call to `process`.
+    pub fn trigger(&mut self) {
+        self.pending_triggers += 1;
+    }
+
+    /// Immediately stop all voices and discard any pending triggers.
+    pub fn stop_all(&mut self) {
+        for voice in &mut self.voices {
+            voice.active = false;
+        }
+        self.pending_triggers = 0;
+    }
+
+    fn start_voice(&mut self, gain: f32) {
+        let ix = match self.voices.iter().position(|v| !v.active) {
+            Some(ix) => ix,
+            None => {
+                let oldest = self
+                    .voices
+                    .iter()
+                    .enumerate()
+                    .max_by(|(_, a), (_, b)| a.position.partial_cmp(&b.position).unwrap());
+                match oldest {
+                    Some((ix, _)) => ix,
+                    None => return,
+                }
+            }
+        };
+        self.voices[ix] = Voice {
+            position: 0.0,
+            gain,
+            active: true,
+        };
+    }
+}
+
+impl<I> Node<I> for SamplePlayer {
+    fn process(&mut self, inputs: &[Input<I>], output: &mut [Buffer]) {
+        for out_buf in output.iter_mut() {
+            out_buf.silence();
+        }
+
+        for _ in 0..self.pending_triggers {
+            self.start_voice(1.0);
+        }
+        self.pending_triggers = 0;
+
+        let trigger_buf = inputs.first().and_then(|input| input.buffers().first());
+        let len = self.sample.first().map(|ch| ch.len()).unwrap_or(0);
+        for ix in 0..block_frames(output) {
+            if let Some(trigger_buf) = trigger_buf {
+                let trigger = trigger_buf[ix];
+                if self.last_trigger <= 0.0 && trigger > 0.0 {
+                    self.start_voice(trigger);
+                }
+                self.last_trigger = trigger;
+            }
+
+            for voice in self.voices.iter_mut().filter(|v| v.active) {
+                let whole = voice.position as usize;
+                if whole >= len {
+                    voice.active = false;
+                    continue;
+                }
+                let frac = (voice.position - whole as f64) as f32;
+                let gain = voice.gain * self.gain;
+                let channels = self.sample.iter().cycle();
+                for (out_buf, channel) in output.iter_mut().zip(channels) {
+                    let a = channel[whole];
+                    let b = channel.get(whole + 1).cloned().unwrap_or(0.0);
+                    out_buf[ix] += (a + (b - a) * frac) * gain;
+                }
+                voice.position += self.pitch;
+            }
+        }
+    }
+
+    clone_state!();
+}
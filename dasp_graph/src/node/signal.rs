@@ -1,14 +1,88 @@
+use super::block_frames;
 use crate::{Buffer, Input, Node};
 use dasp_frame::Frame;
 use dasp_signal::Signal;
 
+/// A source node that writes the frames of a `Signal` to its output buffers.
+///
+/// Each call to `process` pulls one frame from the signal for each sample of the output buffers,
+/// writing each channel of the frame to the output buffer of the same index. Output buffers beyond
+/// the number of channels of the frame are silenced.
+///
+/// Once the signal is exhausted, as indicated by `Signal::is_exhausted`, the remainder of the
+/// block and all following blocks are silent. Use `SignalNode::is_exhausted` to determine when
+/// the node may be removed from the graph.
+///
+/// Inputs are ignored.
+#[derive(Clone, Debug)]
+pub struct SignalNode<S> {
+    signal: S,
+    exhausted: bool,
+}
+
+impl<S> SignalNode<S> {
+    /// Wrap the given signal as a source node.
+    pub fn new(signal: S) -> Self {
+        SignalNode {
+            signal,
+            exhausted: false,
+        }
+    }
+
+    /// Whether or not the signal has been exhausted, after which the node only outputs silence.
+    pub fn is_exhausted(&self) -> bool {
+        self.exhausted
+    }
+
+    /// A reference to the inner signal.
+    pub fn signal(&self) -> &S {
+        &self.signal
+    }
+
+    /// A mutable reference to the inner signal.
+    pub fn signal_mut(&mut self) -> &mut S {
+        &mut self.signal
+    }
+
+    /// Consume the node, returning the inner signal.
+    pub fn into_signal(self) -> S {
+        self.signal
+    }
+}
+
+impl<S> Node for SignalNode<S>
+where
+    S: Signal,
+    S::Frame: Frame<Sample = f32>,
+{
+    fn process(&mut self, _inputs: &[Input], output: &mut [Buffer]) {
+        let channels = core::cmp::min(S::Frame::CHANNELS, output.len());
+        for ix in 0..block_frames(output) {
+            self.exhausted = self.exhausted || self.signal.is_exhausted();
+            if self.exhausted {
+                for out_buf in output[..channels].iter_mut() {
+                    out_buf[ix..].iter_mut().for_each(|s| *s = 0.0);
+                }
+                break;
+            }
+            let frame = self.signal.next();
+            for (out_buf, sample) in output.iter_mut().zip(frame.channels()) {
+                out_buf[ix] = sample;
+            }
+        }
+        for out_buf in output.iter_mut().skip(channels) {
+            out_buf.silence();
+        }
+    }
+}
+
 impl<F> Node for dyn Signal<Frame = F> + Send
 where
     F: Frame<Sample = f32>,
 {
     fn process(&mut self, _inputs: &[Input], output: &mut [Buffer]) {
-        let channels = std::cmp::min(F::CHANNELS, output.len());
-        for ix in 0..Buffer::LEN {
+        let channels = core::cmp::min(F::CHANNELS, output.len());
+        for ix in 0..block_frames(output) {
             let frame = self.next();
             for ch in 0..channels {
                 // Safe, as we verify the number of channels at the beginning of the function.
@@ -0,0 +1,168 @@
+use super::block_frames;
+use crate::{Buffer, Input, Node, Vec};
+
+/// An event reported by the **SilenceDetect** node.
+///
+/// Positions are given in frames since the node was created or last reset.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SilenceDetectEvent {
+    /// A run of silence across all channels at least `min_silence_len` frames long.
+    Silence { start: u64, len: u64 },
+    /// A sudden discontinuity on the given channel, where the difference between a frame and the
+    /// previous frame exceeded `slope_threshold`.
+    Dropout { position: u64, channel: usize },
+}
+
+/// A node that passes audio through while detecting runs of silence and dropouts.
+///
+/// This is useful for quality assurance of long batch renders. The detected events are collected
+/// in order and may be read via `events` after processing.
+///
+/// A frame is considered silent if the absolute value of every channel is less than or equal to
+/// `silence_threshold`. A run of silence is reported once it ends, or upon calling `flush`.
+///
+/// The event collector is allocated once during construction. If it becomes full, further events
+/// are counted via `dropped_events` but not stored.
+///
+/// Assumes that there is one input node.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SilenceDetect {
+    /// The amplitude at or below which a sample is considered silent.
+    pub silence_threshold: f32,
+    /// The minimum number of consecutive silent frames that are reported as silence.
+    pub min_silence_len: u64,
+    /// The difference between consecutive samples above which a dropout is reported.
+    pub slope_threshold: f32,
+    position: u64,
+    silence_start: Option<u64>,
+    last_frame: Vec<f32>,
+    events: Vec<SilenceDetectEvent>,
+    dropped_events: usize,
+}
+
+impl SilenceDetect {
+    /// The default value for `silence_threshold`, roughly -100 dBFS.
+    pub const DEFAULT_SILENCE_THRESHOLD: f32 = 0.00001;
+    /// The default value for `min_silence_len`.
+    pub const DEFAULT_MIN_SILENCE_LEN: u64 = 32;
+    /// The default value for `slope_threshold`.
+    pub const DEFAULT_SLOPE_THRESHOLD: f32 = 0.5;
+
+    /// Create a new **SilenceDetect** for the given number of channels, with room to collect up to
+    /// `max_events` events.
+    pub fn new(channels: usize, max_events: usize) -> Self {
+        SilenceDetect {
+            silence_threshold: Self::DEFAULT_SILENCE_THRESHOLD,
+            min_silence_len: Self::DEFAULT_MIN_SILENCE_LEN,
+            slope_threshold: Self::DEFAULT_SLOPE_THRESHOLD,
+            position: 0,
+            silence_start: None,
+            last_frame: vec![0.0; channels],
+            events: Vec::with_capacity(max_events),
+            dropped_events: 0,
+        }
+    }
+
+    /// The events collected so far, in order of occurrence.
+    pub fn events(&self) -> &[SilenceDetectEvent] {
+        &self.events
+    }
+
+    /// The number of events that were detected while the collector was full.
+    pub fn dropped_events(&self) -> usize {
+        self.dropped_events
+    }
+
+    /// The number of frames processed so far.
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Report any run of silence that is still in progress.
+    ///
+    /// This should be called at the end of a render to catch trailing silence.
+    pub fn flush(&mut self) {
+        if let Some(start) = self.silence_start.take() {
+            self.end_silence(start);
+        }
+    }
+
+    /// Clear the collected events and reset the frame position to `0`.
+    pub fn reset(&mut self) {
+        self.events.clear();
+        self.dropped_events = 0;
+        self.position = 0;
+        self.silence_start = None;
+        for s in &mut self.last_frame {
+            *s = 0.0;
+        }
+    }
+
+    fn end_silence(&mut self, start: u64) {
+        let len = self.position - start;
+        if len >= self.min_silence_len {
+            let event = SilenceDetectEvent::Silence { start, len };
+            push_event(&mut self.events, &mut self.dropped_events, event);
+        }
+    }
+}
+
+// Push the event if there is room in the collector, otherwise count it as dropped.
+fn push_event(
+    events: &mut Vec<SilenceDetectEvent>,
+    dropped: &mut usize,
+    event: SilenceDetectEvent,
+) {
+    if events.len() < events.capacity() {
+        events.push(event);
+    } else {
+        *dropped += 1;
+    }
+}
+
+impl Node for SilenceDetect {
+    fn process(&mut self, inputs: &[Input], output: &mut [Buffer]) {
+        // Pass the single input through to the output, ignoring any others.
+        let input = match inputs.first() {
+            Some(input) => input,
+            None => {
+                output.iter_mut().for_each(|out_buf| out_buf.silence());
+                return;
+            }
+        };
+        let in_bufs = input.buffers();
+        for (out_buf, in_buf) in output.iter_mut().zip(in_bufs) {
+            out_buf.copy_from_slice(in_buf);
+        }
+        for out_buf in output.iter_mut().skip(in_bufs.len()) {
+            out_buf.silence();
+        }
+
+        for ix in 0..block_frames(output) {
+            let mut silent = true;
+            for (ch, (last, in_buf)) in self.last_frame.iter_mut().zip(in_bufs).enumerate() {
+                let sample = in_buf[ix];
+                if (sample - *last).abs() > self.slope_threshold {
+                    let event = SilenceDetectEvent::Dropout {
+                        position: self.position,
+                        channel: ch,
+                    };
+                    push_event(&mut self.events, &mut self.dropped_events, event);
+                }
+                *last = sample;
+                silent &= sample.abs() <= self.silence_threshold;
+            }
+            match (silent, self.silence_start) {
+                (true, None) => self.silence_start = Some(self.position),
+                (false, Some(start)) => {
+                    self.silence_start = None;
+                    self.end_silence(start);
+                }
+                _ => (),
+            }
+            self.position += 1;
+        }
+    }
+
+    clone_state!();
+}
@@ -0,0 +1,89 @@
+use crate::{Buffer, Input, Node, Vec};
+
+/// A slew-rate limiter, limiting how fast each channel of the output may change.
+///
+/// Each output sample moves from the previous output sample toward the input sample by at most
+/// `rise_per_second / sample_rate` when rising and `fall_per_second / sample_rate` when falling.
+/// Applied to audio, this smooths sharp transients and distorts signals whose slope exceeds the
+/// limits. Applied to control signals, this turns steps into linear ramps, e.g. for removing
+/// zipper noise from stepped parameter changes.
+///
+/// The state for each channel is allocated during construction and persists between blocks.
+/// Channels beyond those allocated are passed through unchanged.
+///
+/// Assumes that there is one input node.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SlewLimit {
+    /// The maximum increase in the output per second.
+    pub rise_per_second: f32,
+    /// The maximum decrease in the output per second, given as a positive value.
+    pub fall_per_second: f32,
+    sample_rate: f64,
+    values: Vec<f32>,
+}
+
+impl SlewLimit {
+    /// Create a new **SlewLimit** for the given number of channels and sample rate, with the
+    /// given maximum rise and fall per second.
+    pub fn new(
+        channels: usize,
+        sample_rate: f64,
+        rise_per_second: f32,
+        fall_per_second: f32,
+    ) -> Self {
+        SlewLimit {
+            rise_per_second,
+            fall_per_second,
+            sample_rate,
+            values: vec![0.0; channels],
+        }
+    }
+
+    /// The sample rate in Hz.
+    pub fn sample_rate(&self) -> f64 {
+        self.sample_rate
+    }
+
+    /// The current output value of each channel.
+    pub fn values(&self) -> &[f32] {
+        &self.values
+    }
+
+    /// Reset the output value of each channel to `0.0`.
+    pub fn reset(&mut self) {
+        self.values.iter_mut().for_each(|value| *value = 0.0);
+    }
+}
+
+impl Node for SlewLimit {
+    fn process(&mut self, inputs: &[Input], output: &mut [Buffer]) {
+        let input = match inputs.first() {
+            Some(input) => input,
+            None => {
+                output.iter_mut().for_each(|out_buf| out_buf.silence());
+                return;
+            }
+        };
+        let max_rise = (self.rise_per_second.abs() as f64 / self.sample_rate) as f32;
+        let max_fall = (self.fall_per_second.abs() as f64 / self.sample_rate) as f32;
+        let in_bufs = input.buffers();
+        for (ch, (out_buf, in_buf)) in output.iter_mut().zip(in_bufs).enumerate() {
+            let value = match self.values.get_mut(ch) {
+                Some(value) => value,
+                None => {
+                    out_buf.copy_from_slice(in_buf);
+                    continue;
+                }
+            };
+            for (out, &x) in out_buf.iter_mut().zip(in_buf.iter()) {
+                *value += (x - *value).clamp(-max_fall, max_rise);
+                *out = *value;
+            }
+        }
+        for out_buf in output.iter_mut().skip(in_bufs.len()) {
+            out_buf.silence();
+        }
+    }
+
+    clone_state!();
+}
@@ -2,10 +2,25 @@ use crate::{Buffer, Input, Node};
 
 /// A stateless node that sums each of the inputs onto the output.
 ///
-/// Assumes that the number of buffers per input is equal to the number of output buffers.
+/// The number of output channels is given by the number of output buffers. Each input channel is
+/// summed onto the output channel of the same index. Input channels beyond the number of output
+/// channels are dropped, and output channels beyond the number of input channels receive nothing
+/// from that input. E.g. a mono input is only summed onto the first channel of a stereo output.
+///
+/// See **SumBroadcast** for broadcasting mono inputs across every output channel.
 #[derive(Default, Clone, Debug, PartialEq)]
 pub struct Sum;
 
+/// A stateless node that sums each of the inputs onto the output, broadcasting mono inputs across
+/// every output channel.
+///
+/// The number of output channels is given by the number of output buffers. Mono inputs are summed
+/// onto every output channel. Inputs with more channels than the output are folded down, with
+/// input channel `i` averaged onto output channel `i % output.len()`, e.g. a stereo input is summed
+/// onto a mono output as `(l + r) / 2`. All other inputs are summed as by **Sum**.
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct SumBroadcast;
+
 /// A stateless node that sums all of the buffers of all of the inputs onto each of the output
 /// buffers.
 ///
@@ -14,6 +29,7 @@ pub struct Sum;
 /// remaining output buffers.
 ///
 /// After a call to `Node::process`, each of the output buffers will always have the same contents.
+/// As such, the channel count of each input does not affect where its buffers are summed.
 ///
 /// Common use cases:
 ///
@@ -22,6 +38,14 @@ pub struct Sum;
 #[derive(Clone, Debug, PartialEq)]
 pub struct SumBuffers;
 
+impl Sum {
+    /// Create a **SumBroadcast** node, summing inputs as **Sum** does but broadcasting mono inputs
+    /// across every output channel.
+    pub fn with_mono_broadcast() -> SumBroadcast {
+        SumBroadcast
+    }
+}
+
 impl Node for Sum {
     fn process(&mut self, inputs: &[Input], output: &mut [Buffer]) {
         // Fill the output with silence.
@@ -40,6 +64,39 @@ impl Node for Sum {
     }
 }
 
+impl Node for SumBroadcast {
+    fn process(&mut self, inputs: &[Input], output: &mut [Buffer]) {
+        // Fill the output with silence.
+        for out_buffer in output.iter_mut() {
+            out_buffer.silence();
+        }
+        // Sum the inputs onto the output.
+        let channels = output.len();
+        for input in inputs {
+            let in_buffers = input.buffers();
+            if in_buffers.len() == 1 {
+                for out_buffer in output.iter_mut() {
+                    dasp_slice::add_in_place(out_buffer, &in_buffers[0]);
+                }
+            } else if in_buffers.len() > channels {
+                for (channel, out_buffer) in output.iter_mut().enumerate() {
+                    let folded = (in_buffers.len() - channel).div_ceil(channels);
+                    let scale = 1.0 / folded as f32;
+                    for in_buffer in in_buffers.iter().skip(channel).step_by(channels) {
+                        for (out, &sample) in out_buffer.iter_mut().zip(in_buffer.iter()) {
+                            *out += sample * scale;
+                        }
+                    }
+                }
+            } else {
+                for (out_buffer, in_buffer) in output.iter_mut().zip(in_buffers) {
+                    dasp_slice::add_in_place(out_buffer, in_buffer);
+                }
+            }
+        }
+    }
+}
+
 impl Node for SumBuffers {
     fn process(&mut self, inputs: &[Input], output: &mut [Buffer]) {
         // Get the first output buffer.
@@ -0,0 +1,94 @@
+use crate::{Buffer, Input, Node, Vec};
+
+/// A waveshaper whose transfer function is given by a user-supplied lookup table, e.g. a custom
+/// distortion curve exported from a curve designer.
+///
+/// The entries of the table are spaced evenly across the input range `-1.0..=1.0`, such that the
+/// first entry is the output for an input of `-1.0` and the last entry is the output for an input
+/// of `1.0`. Inputs between entries are linearly interpolated. Each sample is multiplied by
+/// `drive` before being looked up and inputs beyond the range of the table are clamped to it, so
+/// that the outermost entries determine the output for loud signals.
+///
+/// The output is `dry + mix * (shaped - dry)`.
+///
+/// Assumes that there is one input node.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableShaper {
+    /// The gain applied to the input before it is looked up within the table.
+    pub drive: f32,
+    /// The balance between the dry signal at `0.0` and the shaped signal at `1.0`.
+    pub mix: f32,
+    table: Vec<f32>,
+}
+
+impl TableShaper {
+    /// The default value for `drive`.
+    pub const DEFAULT_DRIVE: f32 = 1.0;
+    /// The default value for `mix`.
+    pub const DEFAULT_MIX: f32 = 1.0;
+
+    /// Create a new **TableShaper** from the given transfer function lookup table.
+    ///
+    /// **Panics** if the table has fewer than two entries.
+    pub fn new(table: Vec<f32>) -> Self {
+        assert_table(&table);
+        TableShaper {
+            drive: Self::DEFAULT_DRIVE,
+            mix: Self::DEFAULT_MIX,
+            table,
+        }
+    }
+
+    /// The transfer function lookup table.
+    pub fn table(&self) -> &[f32] {
+        &self.table
+    }
+
+    /// Replace the transfer function lookup table.
+    ///
+    /// **Panics** if the table has fewer than two entries.
+    pub fn set_table(&mut self, table: Vec<f32>) {
+        assert_table(&table);
+        self.table = table;
+    }
+
+    /// The output of the transfer function for the given input, before `drive` or `mix` are
+    /// applied.
+    pub fn shape(&self, x: f32) -> f32 {
+        let last = self.table.len() - 1;
+        let pos = (x.clamp(-1.0, 1.0) + 1.0) * 0.5 * last as f32;
+        let ix = (pos as usize).min(last - 1);
+        let frac = pos - ix as f32;
+        let (a, b) = (self.table[ix], self.table[ix + 1]);
+        a + (b - a) * frac
+    }
+}
+
+impl Node for TableShaper {
+    fn process(&mut self, inputs: &[Input], output: &mut [Buffer]) {
+        let input = match inputs.first() {
+            Some(input) => input,
+            None => {
+                output.iter_mut().for_each(|out_buf| out_buf.silence());
+                return;
+            }
+        };
+        let in_bufs = input.buffers();
+        for (out_buf, in_buf) in output.iter_mut().zip(in_bufs) {
+            for (out, &dry) in out_buf.iter_mut().zip(in_buf.iter()) {
+                let shaped = self.shape(dry * self.drive);
+                *out = dry + self.mix * (shaped - dry);
+            }
+        }
+        for out_buf in output.iter_mut().skip(in_bufs.len()) {
+            out_buf.silence();
+        }
+    }
+}
+
+fn assert_table(table: &[f32]) {
+    assert!(
+        table.len() >= 2,
+        "the lookup table must contain at least two entries"
+    );
+}
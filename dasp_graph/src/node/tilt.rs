@@ -0,0 +1,122 @@
+use super::filter::Biquad;
+use crate::{Buffer, Input, Node, Vec};
+
+/// A spectral tilt filter that boosts one end of the spectrum while cutting the other.
+///
+/// Each channel is filtered by a complementary low shelf and high shelf, both with their corner
+/// at the `pivot` frequency. A positive `tilt_db` cuts the low shelf and boosts the high shelf by
+/// half of `tilt_db` each, brightening the signal, while a negative `tilt_db` darkens it. The
+/// gain at the pivot frequency itself is unchanged. This makes for a simple one-knob tonal
+/// shaper.
+///
+/// Assumes that there is one input node.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tilt {
+    tilt_db: f64,
+    pivot: f64,
+    sample_rate: f64,
+    // The low shelf and high shelf for each channel.
+    filters: Vec<[Biquad; 2]>,
+}
+
+impl Tilt {
+    /// The default pivot frequency in Hz.
+    pub const DEFAULT_PIVOT: f64 = 1_000.0;
+
+    /// Create a new flat **Tilt** for the given number of channels and sample rate.
+    pub fn new(channels: usize, sample_rate: f64) -> Self {
+        let pivot = Self::DEFAULT_PIVOT;
+        Tilt {
+            tilt_db: 0.0,
+            pivot,
+            sample_rate,
+            filters: vec![shelves(sample_rate, pivot, 0.0); channels],
+        }
+    }
+
+    /// The sample rate in Hz.
+    pub fn sample_rate(&self) -> f64 {
+        self.sample_rate
+    }
+
+    /// The difference in gain between the high and low ends of the spectrum in dB.
+    pub fn tilt_db(&self) -> f64 {
+        self.tilt_db
+    }
+
+    /// Set the tilt in dB, where positive values brighten the signal and negative values darken
+    /// it.
+    ///
+    /// The state of each filter is retained.
+    pub fn set_tilt_db(&mut self, tilt_db: f64) {
+        self.tilt_db = tilt_db;
+        self.update();
+    }
+
+    /// The frequency in Hz around which the spectrum is tilted.
+    pub fn pivot(&self) -> f64 {
+        self.pivot
+    }
+
+    /// Set the frequency in Hz around which the spectrum is tilted.
+    ///
+    /// The state of each filter is retained.
+    pub fn set_pivot(&mut self, pivot: f64) {
+        self.pivot = pivot;
+        self.update();
+    }
+
+    /// Reset the state of all filters.
+    pub fn reset(&mut self) {
+        for filter in self.filters.iter_mut().flatten() {
+            filter.reset();
+        }
+    }
+
+    // Apply the current parameters to the filters of each channel.
+    fn update(&mut self) {
+        let [low, high] = shelves(self.sample_rate, self.pivot, self.tilt_db);
+        for [low_filter, high_filter] in &mut self.filters {
+            low_filter.set_coefficients(low);
+            high_filter.set_coefficients(high);
+        }
+    }
+}
+
+// The complementary shelves for the given pivot frequency and tilt.
+fn shelves(sample_rate: f64, pivot: f64, tilt_db: f64) -> [Biquad; 2] {
+    [
+        Biquad::low_shelf(sample_rate, pivot, -tilt_db / 2.0),
+        Biquad::high_shelf(sample_rate, pivot, tilt_db / 2.0),
+    ]
+}
+
+impl Node for Tilt {
+    fn process(&mut self, inputs: &[Input], output: &mut [Buffer]) {
+        let input = match inputs.first() {
+            Some(input) => input,
+            None => {
+                output.iter_mut().for_each(|out_buf| out_buf.silence());
+                return;
+            }
+        };
+        let in_bufs = input.buffers();
+        for (ch, (out_buf, in_buf)) in output.iter_mut().zip(in_bufs).enumerate() {
+            let [low, high] = match self.filters.get_mut(ch) {
+                Some(filters) => filters,
+                None => {
+                    out_buf.copy_from_slice(in_buf);
+                    continue;
+                }
+            };
+            for (out, &x) in out_buf.iter_mut().zip(in_buf.iter()) {
+                *out = high.process(low.process(x as f64)) as f32;
+            }
+        }
+        for out_buf in output.iter_mut().skip(in_bufs.len()) {
+            out_buf.silence();
+        }
+    }
+
+    clone_state!();
+}
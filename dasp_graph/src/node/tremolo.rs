@@ -0,0 +1,143 @@
+use super::block_frames;
+use crate::{Buffer, Input, Node};
+use core::f64::consts::PI;
+
+/// The shape of the LFO of a **Tremolo** node.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TremoloWaveform {
+    /// A smooth sine wave.
+    Sine,
+    /// A linear rise and fall.
+    Triangle,
+    /// Switches between full and reduced gain every half cycle, producing a choppy effect.
+    ///
+    /// The hard transitions may be heard as clicks.
+    Square,
+}
+
+/// The rate of the LFO of a **Tremolo** node.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TremoloRate {
+    /// A free-running rate in Hz.
+    Hz(f64),
+    /// A rate synced to a tempo, completing one LFO cycle every `beats` beats at `bpm` beats per
+    /// minute, e.g. `beats: 0.25` for sixteenth notes in 4/4.
+    Tempo {
+        /// The tempo in beats per minute.
+        bpm: f64,
+        /// The length of a single LFO cycle in beats.
+        beats: f64,
+    },
+}
+
+/// Modulates the amplitude of its input with a low frequency oscillator (LFO).
+///
+/// The gain varies between `1.0` and `1.0 - depth` over each LFO cycle. Each channel `n` reads
+/// the LFO offset by `n * stereo_phase` degrees, so that a `stereo_phase` of `0.0` applies the
+/// same tremolo to every channel while a `stereo_phase` of `180.0` modulates a stereo pair in
+/// anti-phase, resulting in an auto-pan.
+///
+/// The LFO phase persists across blocks and is advanced by a full block when there is no input.
+///
+/// Assumes that there is one input node.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tremolo {
+    /// The rate of the LFO.
+    pub rate: TremoloRate,
+    /// The depth of the modulation in the range `0.0..=1.0`.
+    pub depth: f32,
+    /// The shape of the LFO.
+    pub waveform: TremoloWaveform,
+    /// The phase offset in degrees applied to the LFO per channel.
+    pub stereo_phase: f32,
+    sample_rate: f64,
+    // The phase of the LFO for the first channel in the range `0.0..1.0`.
+    phase: f64,
+}
+
+impl Tremolo {
+    /// The default value for `depth`.
+    pub const DEFAULT_DEPTH: f32 = 0.5;
+
+    /// Create a new **Tremolo** at the given sample rate with the given LFO rate.
+    pub fn new(sample_rate: f64, rate: TremoloRate) -> Self {
+        Tremolo {
+            rate,
+            depth: Self::DEFAULT_DEPTH,
+            waveform: TremoloWaveform::Sine,
+            stereo_phase: 0.0,
+            sample_rate,
+            phase: 0.0,
+        }
+    }
+
+    /// The sample rate in Hz.
+    pub fn sample_rate(&self) -> f64 {
+        self.sample_rate
+    }
+
+    /// The frequency of the LFO in Hz, derived from `rate`.
+    pub fn hz(&self) -> f64 {
+        match self.rate {
+            TremoloRate::Hz(hz) => hz,
+            TremoloRate::Tempo { bpm, beats } => bpm / (60.0 * beats),
+        }
+    }
+
+    /// The current phase of the LFO for the first channel in the range `0.0..1.0`.
+    pub fn phase(&self) -> f64 {
+        self.phase
+    }
+
+    /// Reset the LFO phase, e.g. to restart the LFO on a downbeat.
+    pub fn reset(&mut self) {
+        self.phase = 0.0;
+    }
+
+    // The gain for the given LFO phase.
+    fn gain(&self, phase: f64) -> f32 {
+        let phase = phase - phase.floor();
+        // The LFO in the range `0.0..=1.0`, beginning each cycle at `1.0`.
+        let lfo = match self.waveform {
+            TremoloWaveform::Sine => 0.5 + 0.5 * (2.0 * PI * phase).cos(),
+            TremoloWaveform::Triangle => (2.0 * phase - 1.0).abs(),
+            TremoloWaveform::Square => {
+                if phase < 0.5 {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+        };
+        let depth = self.depth.clamp(0.0, 1.0);
+        1.0 - depth * (1.0 - lfo as f32)
+    }
+}
+
+impl Node for Tremolo {
+    fn process(&mut self, inputs: &[Input], output: &mut [Buffer]) {
+        let step = self.hz() / self.sample_rate;
+        let input = match inputs.first() {
+            Some(input) => input,
+            None => {
+                output.iter_mut().for_each(|out_buf| out_buf.silence());
+                self.phase = (self.phase + step * block_frames(output) as f64).rem_euclid(1.0);
+                return;
+            }
+        };
+        let in_bufs = input.buffers();
+        let offset = self.stereo_phase as f64 / 360.0;
+        for ix in 0..block_frames(output) {
+            for (ch, (out_buf, in_buf)) in output.iter_mut().zip(in_bufs).enumerate() {
+                let gain = self.gain(self.phase + ch as f64 * offset);
+                out_buf[ix] = in_buf[ix] * gain;
+            }
+            self.phase = (self.phase + step).rem_euclid(1.0);
+        }
+        for out_buf in output.iter_mut().skip(in_bufs.len()) {
+            out_buf.silence();
+        }
+    }
+
+    clone_state!();
+}
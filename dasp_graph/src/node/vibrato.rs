@@ -0,0 +1,141 @@
+use super::block_frames;
+use crate::{Buffer, Input, Node, Vec};
+use core::f64::consts::PI;
+use dasp_ring_buffer::{DelayLine, Interpolation};
+
+/// The shape of the LFO of a **Vibrato** node.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum VibratoWaveform {
+    /// A smooth sine wave, continuously varying the pitch.
+    Sine,
+    /// A linear rise and fall, alternating between a constant raised and lowered pitch.
+    Triangle,
+}
+
+/// Modulates the pitch of its input by sweeping a short fractional delay with a low frequency
+/// oscillator (LFO).
+///
+/// As the delay lengthens the pitch falls, and as it shortens the pitch rises. The delay sweeps
+/// between `0.0` and `depth_ms` milliseconds once per LFO cycle, such that the output wobbles in
+/// pitch around that of the input at the LFO `rate`. Unlike a chorus, only the delayed signal is
+/// output and no dry signal is mixed in. The delayed signal is read with cubic interpolation.
+///
+/// The LFO phase persists across blocks and is advanced by a full block when there is no input.
+///
+/// The delay line for each channel is allocated once during construction and is sized to fit the
+/// given maximum depth. `depth_ms` is clamped to this maximum.
+///
+/// Assumes that there is one input node. Output channels beyond those allocated are silenced.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Vibrato {
+    /// The rate of the LFO in Hz.
+    pub rate: f64,
+    /// The range of the delay sweep in milliseconds, determining the pitch deviation.
+    pub depth_ms: f32,
+    /// The shape of the LFO.
+    pub waveform: VibratoWaveform,
+    max_depth_ms: f32,
+    sample_rate: f64,
+    // The phase of the LFO in the range `0.0..1.0`.
+    phase: f64,
+    delay_lines: Vec<DelayLine<Vec<f32>>>,
+}
+
+impl Vibrato {
+    /// The default value for `rate` in Hz.
+    pub const DEFAULT_RATE: f64 = 5.0;
+    /// The default value for `depth_ms`.
+    pub const DEFAULT_DEPTH_MS: f32 = 2.0;
+
+    /// Create a new **Vibrato** for the given number of channels and sample rate, allocating delay
+    /// lines long enough for depths of up to `max_depth_ms` milliseconds.
+    pub fn new(channels: usize, sample_rate: f64, max_depth_ms: f32) -> Self {
+        let max_depth_ms = max_depth_ms.max(0.0);
+        let max_frames = (max_depth_ms as f64 * sample_rate / 1_000.0).ceil() as usize;
+        // Room for the minimum delay of one frame and the cubic interpolation stencil.
+        let len = max_frames + 4;
+        let delay_lines = (0..channels)
+            .map(|_| DelayLine::new(vec![0.0; len], Interpolation::Cubic))
+            .collect();
+        Vibrato {
+            rate: Self::DEFAULT_RATE,
+            depth_ms: Self::DEFAULT_DEPTH_MS.min(max_depth_ms),
+            waveform: VibratoWaveform::Sine,
+            max_depth_ms,
+            sample_rate,
+            phase: 0.0,
+            delay_lines,
+        }
+    }
+
+    /// The sample rate in Hz.
+    pub fn sample_rate(&self) -> f64 {
+        self.sample_rate
+    }
+
+    /// The maximum depth in milliseconds supported by the allocated delay lines.
+    pub fn max_depth_ms(&self) -> f32 {
+        self.max_depth_ms
+    }
+
+    /// The current phase of the LFO in the range `0.0..1.0`.
+    pub fn phase(&self) -> f64 {
+        self.phase
+    }
+
+    /// Reset the LFO phase and the contents of the delay lines.
+    pub fn reset(&mut self) {
+        self.phase = 0.0;
+        for delay_line in &mut self.delay_lines {
+            delay_line.clear();
+        }
+    }
+
+    // The delay in frames for the given LFO phase.
+    fn delay(&self, depth_frames: f64) -> f32 {
+        // The LFO in the range `0.0..=1.0`, beginning each cycle at `0.0`.
+        let lfo = match self.waveform {
+            VibratoWaveform::Sine => 0.5 - 0.5 * (2.0 * PI * self.phase).cos(),
+            VibratoWaveform::Triangle => 1.0 - (2.0 * self.phase - 1.0).abs(),
+        };
+        // A minimum delay of one frame keeps the cubic stencil centred around the read position.
+        (1.0 + depth_frames * lfo) as f32
+    }
+}
+
+impl Node for Vibrato {
+    fn process(&mut self, inputs: &[Input], output: &mut [Buffer]) {
+        let step = self.rate / self.sample_rate;
+        let input = match inputs.first() {
+            Some(input) => input,
+            None => {
+                output.iter_mut().for_each(|out_buf| out_buf.silence());
+                self.phase = (self.phase + step * block_frames(output) as f64).rem_euclid(1.0);
+                return;
+            }
+        };
+        let in_bufs = input.buffers();
+        let depth_ms = self.depth_ms.max(0.0).min(self.max_depth_ms);
+        let depth_frames = depth_ms as f64 * self.sample_rate / 1_000.0;
+        for ix in 0..block_frames(output) {
+            let delay = self.delay(depth_frames);
+            let channels = output.iter_mut().zip(in_bufs).zip(&mut self.delay_lines);
+            for ((out_buf, in_buf), delay_line) in channels {
+                delay_line.push(in_buf[ix]);
+                out_buf[ix] = delay_line.read(delay);
+            }
+            self.phase = (self.phase + step).rem_euclid(1.0);
+        }
+        let channels = in_bufs.len().min(self.delay_lines.len());
+        for out_buf in output.iter_mut().skip(channels) {
+            out_buf.silence();
+        }
+    }
+
+    fn tail_frames(&self) -> Option<usize> {
+        let depth_ms = self.depth_ms.max(0.0).min(self.max_depth_ms);
+        Some(1 + (depth_ms as f64 * self.sample_rate / 1_000.0).ceil() as usize)
+    }
+
+    clone_state!();
+}
@@ -0,0 +1,67 @@
+#![cfg(feature = "node-agc")]
+
+use dasp_graph::{node, Buffer, Input, Node};
+
+fn agc() -> node::Agc {
+    let mut agc = node::Agc::new(-18.0);
+    agc.rms_frames = 480.0;
+    agc.rise_frames = 4_800.0;
+    agc.fall_frames = 960.0;
+    agc
+}
+
+// Process the given number of blocks of a constant level, returning the last output value.
+fn process(agc: &mut node::Agc, level: f32, blocks: usize) -> f32 {
+    let bufs = vec![Buffer::from([level; Buffer::LEN]); 2];
+    let mut output = vec![Buffer::SILENT; 2];
+    for _ in 0..blocks {
+        agc.process(&[Input::new(&bufs, ())], &mut output);
+        assert_eq!(output[0], output[1]);
+    }
+    output[0][Buffer::LEN - 1]
+}
+
+fn db(linear: f32) -> f32 {
+    20.0 * linear.log10()
+}
+
+#[test]
+fn test_agc_restores_target_after_level_drop() {
+    let mut agc = agc();
+
+    // A loud signal at -6 dBFS settles at the target.
+    let out = process(&mut agc, 0.5, 1_000);
+    assert!((db(out) - -18.0).abs() < 0.1, "{}", db(out));
+    assert!((agc.current_gain_db() - -12.0).abs() < 0.1);
+
+    // The level drops by 20 dB. The gain rises slowly, without overshooting.
+    let mut gains = vec![];
+    for _ in 0..20 {
+        process(&mut agc, 0.05, 25);
+        gains.push(agc.current_gain_db());
+    }
+    assert!(gains.windows(2).all(|w| w[1] > w[0]), "{:?}", gains);
+    assert!(gains[0] < 0.0);
+    assert!(gains.iter().all(|&g| g <= 8.0 + 0.01));
+
+    // Eventually the gain settles such that the target is restored.
+    let out = process(&mut agc, 0.05, 1_000);
+    assert!((db(out) - -18.0).abs() < 0.1, "{}", db(out));
+    assert!((agc.current_gain_db() - 8.0).abs() < 0.1);
+}
+
+#[test]
+fn test_agc_max_gain_and_gate() {
+    let mut agc = agc();
+
+    // A quiet signal at -46 dBFS would require 28 dB of gain, limited to 24 dB.
+    let out = process(&mut agc, 0.005, 2_000);
+    assert!((agc.current_gain_db() - 24.0).abs() < 0.1);
+    assert!((db(out) - -22.0).abs() < 0.1, "{}", db(out));
+
+    // Below the gate, the gain is held rather than raised.
+    agc.reset();
+    let out = process(&mut agc, 0.001, 2_000);
+    assert_eq!(agc.current_gain_db(), 0.0);
+    assert_eq!(out, 0.001);
+}
@@ -0,0 +1,146 @@
+#![cfg(feature = "async")]
+
+use dasp_graph::node::{AsyncNode, AsyncSource};
+use dasp_graph::{Buffer, Node, NodeData};
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+// A minimal async channel of blocks, standing in for a network or disk stream.
+#[derive(Default)]
+struct Shared {
+    blocks: VecDeque<Buffer>,
+    waker: Option<Waker>,
+}
+
+struct Sender(Arc<Mutex<Shared>>);
+struct Receiver(Arc<Mutex<Shared>>);
+
+fn channel() -> (Sender, Receiver) {
+    let shared = Arc::new(Mutex::new(Shared::default()));
+    (Sender(shared.clone()), Receiver(shared))
+}
+
+impl Sender {
+    fn send(&self, value: f32) {
+        let mut shared = self.0.lock().unwrap();
+        shared.blocks.push_back(Buffer::from([value; Buffer::LEN]));
+        if let Some(waker) = shared.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl AsyncNode for Receiver {
+    fn poll_process(&mut self, cx: &mut Context, output: &mut [Buffer]) -> Poll<()> {
+        let mut shared = self.0.lock().unwrap();
+        match shared.blocks.pop_front() {
+            Some(block) => {
+                for out_buf in output.iter_mut() {
+                    out_buf.copy_from_slice(&block);
+                }
+                Poll::Ready(())
+            }
+            None => {
+                shared.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+// Drives a future that is expected to complete without needing to be woken.
+fn poll_once<F: Future + Unpin>(mut fut: F) -> Poll<F::Output> {
+    let waker = Waker::from(Arc::new(NoopWake));
+    let mut cx = Context::from_waker(&waker);
+    Pin::new(&mut fut).poll(&mut cx)
+}
+
+struct NoopWake;
+impl std::task::Wake for NoopWake {
+    fn wake(self: Arc<Self>) {}
+}
+
+#[test]
+fn test_async_source_buffers_blocks() {
+    let (tx, rx) = channel();
+    let mut source = AsyncSource::new(rx, 2, 4);
+    let mut output = vec![Buffer::SILENT; 2];
+
+    // Nothing has been sent yet, so we should underrun and output silence.
+    source.process(&[], &mut output);
+    assert_eq!(source.underruns(), 1);
+    assert_eq!(output, vec![Buffer::SILENT; 2]);
+
+    // Send a few blocks, they should be yielded in order.
+    tx.send(0.1);
+    tx.send(0.2);
+    tx.send(0.3);
+    source.process(&[], &mut output);
+    assert_eq!(output, vec![Buffer::from([0.1; Buffer::LEN]); 2]);
+    assert_eq!(source.len(), 2);
+    source.process(&[], &mut output);
+    assert_eq!(output, vec![Buffer::from([0.2; Buffer::LEN]); 2]);
+    source.process(&[], &mut output);
+    assert_eq!(output, vec![Buffer::from([0.3; Buffer::LEN]); 2]);
+    assert!(source.is_empty());
+
+    // The channel is dry again.
+    source.process(&[], &mut output);
+    assert_eq!(source.underruns(), 2);
+    assert_eq!(output, vec![Buffer::SILENT; 2]);
+}
+
+#[test]
+fn test_async_source_prefill() {
+    let (tx, rx) = channel();
+    let mut source = AsyncSource::new(rx, 1, 3);
+
+    // The prefill future remains pending until the queue is full.
+    tx.send(0.5);
+    assert_eq!(poll_once(source.prefill()), Poll::Pending);
+    assert_eq!(source.len(), 1);
+    tx.send(0.6);
+    tx.send(0.7);
+    tx.send(0.8);
+    assert_eq!(poll_once(source.prefill()), Poll::Ready(()));
+    assert_eq!(source.len(), source.capacity());
+
+    // Queued blocks are yielded first, and the remaining block is fetched as room is freed.
+    let mut output = vec![Buffer::SILENT];
+    for &expected in &[0.5, 0.6, 0.7, 0.8] {
+        source.process(&[], &mut output);
+        assert_eq!(output, vec![Buffer::from([expected; Buffer::LEN])]);
+    }
+    assert_eq!(source.underruns(), 0);
+}
+
+#[test]
+fn test_async_source_block_frames_mismatch() {
+    type Graph = petgraph::Graph<NodeData<AsyncSource<Receiver>>, (), petgraph::Directed, u32>;
+
+    // Blocks of `Buffer::LEN` frames within a processor of twice the block size.
+    let (tx, rx) = channel();
+    let mut g = Graph::new();
+    let mut p = dasp_graph::Processor::with_block_frames(1, Buffer::LEN * 2);
+    let source = g.add_node(NodeData::new1(AsyncSource::new(rx, 1, 2)));
+
+    tx.send(0.5);
+    p.process(&mut g, source);
+    let output = &g[source].buffers[0];
+    assert_eq!(output.len(), Buffer::LEN * 2);
+    assert!(output[..Buffer::LEN].iter().all(|&s| s == 0.5));
+    assert!(output[Buffer::LEN..].iter().all(|&s| s == 0.0));
+
+    // And within a processor of half the block size.
+    let (tx, rx) = channel();
+    let mut g = Graph::new();
+    let mut p = dasp_graph::Processor::with_block_frames(1, Buffer::LEN / 2);
+    let source = g.add_node(NodeData::new1(AsyncSource::new(rx, 1, 2)));
+
+    tx.send(0.5);
+    p.process(&mut g, source);
+    assert_eq!(g[source].buffers[0][..], [0.5; Buffer::LEN / 2][..]);
+}
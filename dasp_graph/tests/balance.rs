@@ -0,0 +1,39 @@
+#![cfg(feature = "node-balance")]
+
+use dasp_graph::{node, Buffer, Input, Node};
+
+fn process(balance: &mut node::Balance) -> Vec<Buffer> {
+    let bufs = vec![
+        Buffer::from([0.5; Buffer::LEN]),
+        Buffer::from([0.25; Buffer::LEN]),
+    ];
+    let mut output = vec![Buffer::SILENT; 2];
+    balance.process(&[Input::new(&bufs, ())], &mut output);
+    output
+}
+
+#[test]
+fn test_balance_center() {
+    let output = process(&mut node::Balance::default());
+    assert_eq!(output[0], Buffer::from([0.5; Buffer::LEN]));
+    assert_eq!(output[1], Buffer::from([0.25; Buffer::LEN]));
+}
+
+#[test]
+fn test_balance_full_right() {
+    let output = process(&mut node::Balance::new(1.0));
+    assert_eq!(output[0], Buffer::SILENT);
+    assert_eq!(output[1], Buffer::from([0.25; Buffer::LEN]));
+}
+
+#[test]
+fn test_balance_partial_left() {
+    let mut balance = node::Balance::new(-0.5);
+    assert_eq!(balance.gains(), [1.0, 0.5]);
+    let output = process(&mut balance);
+    assert_eq!(output[0], Buffer::from([0.5; Buffer::LEN]));
+    assert_eq!(output[1], Buffer::from([0.125; Buffer::LEN]));
+    // Positions beyond the range are clamped.
+    balance.set_position(-4.0);
+    assert_eq!(balance.position(), -1.0);
+}
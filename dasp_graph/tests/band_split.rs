@@ -0,0 +1,88 @@
+#![cfg(feature = "node-band-split")]
+
+use dasp_graph::{node, Buffer, Input, Node};
+
+const SAMPLE_RATE: f64 = 48_000.0;
+
+// Deterministic white noise in the range `-0.5..0.5`.
+fn noise(len: usize) -> Vec<f32> {
+    let mut state = 0x2545_f491u32;
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state as f32 / u32::MAX as f32 - 0.5
+        })
+        .collect()
+}
+
+// Split and merge the given signal, returning the merged output and the plain sum of the bands.
+fn split_merge(
+    split: &mut node::BandSplit,
+    merge: &mut node::BandMerge,
+    signal: &[f32],
+) -> (Vec<f32>, Vec<f32>) {
+    let mut bands = vec![Buffer::SILENT; split.bands()];
+    let mut merged = vec![Buffer::SILENT];
+    let (mut output, mut sum) = (vec![], vec![]);
+    for chunk in signal.chunks(Buffer::LEN) {
+        let mut in_buf = Buffer::SILENT;
+        in_buf[..chunk.len()].copy_from_slice(chunk);
+        split.process(&[Input::new(&[in_buf], ())], &mut bands);
+        merge.process(&[Input::new(&bands, ())], &mut merged);
+        output.extend(merged[0].iter().cloned());
+        sum.extend((0..Buffer::LEN).map(|ix| bands.iter().map(|b| b[ix]).sum::<f32>()));
+    }
+    (output, sum)
+}
+
+#[test]
+fn test_split_merge_reconstruction() {
+    let mut split = node::BandSplit::new(SAMPLE_RATE, vec![8_000.0, 200.0, 2_000.0]);
+    assert_eq!(split.bands(), 4);
+    assert_eq!(split.frequencies(), &[200.0, 2_000.0, 8_000.0]);
+    let mut merge = split.merge();
+    let latency = merge.latency();
+    assert!(latency > 0 && latency < 4_800, "{}", latency);
+
+    let input = noise(Buffer::LEN * 200);
+    let (output, sum) = split_merge(&mut split, &mut merge, &input);
+
+    // The merged output matches the latency-compensated input.
+    let mut max_err = 0.0f32;
+    for (x, y) in input.iter().zip(&output[latency..]) {
+        max_err = max_err.max((x - y).abs());
+    }
+    assert!(max_err < 1e-4, "{}", max_err);
+
+    // Whereas the plain sum of the bands is phase shifted.
+    let sum_err = input
+        .iter()
+        .zip(&sum)
+        .fold(0.0f32, |err, (x, y)| err.max((x - y).abs()));
+    assert!(sum_err > 0.1, "{}", sum_err);
+}
+
+#[test]
+fn test_merge_sums_separate_inputs() {
+    let split = node::BandSplit::new(SAMPLE_RATE, vec![1_000.0]);
+    let mut merge = split.merge();
+    let mut low = Buffer::SILENT;
+    let mut high = Buffer::SILENT;
+    low[0] = 0.25;
+    high[0] = 0.5;
+    let mut output = vec![Buffer::SILENT; 2];
+    let inputs = [
+        Input::new(std::slice::from_ref(&low), ()),
+        Input::new(std::slice::from_ref(&high), ()),
+    ];
+    merge.process(&inputs, &mut output);
+    let mut expected = node::BandMerge::new(SAMPLE_RATE, &[1_000.0]);
+    let mut combined = Buffer::SILENT;
+    combined[0] = 0.75;
+    let mut expected_output = vec![Buffer::SILENT];
+    expected.process(&[Input::new(&[combined], ())], &mut expected_output);
+    assert_eq!(output[0], expected_output[0]);
+    assert_eq!(output[1], Buffer::SILENT);
+}
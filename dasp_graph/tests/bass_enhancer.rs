@@ -0,0 +1,53 @@
+#![cfg(feature = "node-bass-enhancer")]
+
+mod common;
+
+use common::{magnitude, SAMPLE_RATE};
+use dasp_graph::node::BassEnhancer;
+
+// Frequencies are chosen to fall exactly on the bins of the measured length to avoid leakage.
+const FUNDAMENTAL_HZ: f64 = 50.0;
+
+// Render a low sine through the enhancer, returning the input and output of the first channel
+// once the filters have settled.
+fn render(enhancer: &mut BassEnhancer) -> (Vec<f32>, Vec<f32>) {
+    let (mut input, mut output) = common::render(enhancer, 2, 300, 150, |block| {
+        vec![common::sine(block, FUNDAMENTAL_HZ, 0.5)]
+    });
+    assert!(output[1].iter().all(|&s| s == 0.0));
+    (input.remove(0), output.remove(0))
+}
+
+#[test]
+fn test_bass_enhancer_harmonics() {
+    let mut enhancer = BassEnhancer::new(2, SAMPLE_RATE, 120.0);
+    let (input, output) = render(&mut enhancer);
+    let fundamental = magnitude(&input, FUNDAMENTAL_HZ);
+
+    // Harmonics above the fundamental are added to the output.
+    for harmonic in 2..5 {
+        let hz = FUNDAMENTAL_HZ * harmonic as f64;
+        assert!(magnitude(&input, hz) < fundamental * 1e-4);
+        assert!(
+            magnitude(&output, hz) > fundamental * 0.01,
+            "harmonic {}",
+            harmonic
+        );
+    }
+
+    // The fundamental remains.
+    let ratio = magnitude(&output, FUNDAMENTAL_HZ) / fundamental;
+    assert!(ratio > 0.9 && ratio < 1.5, "{}", ratio);
+}
+
+#[test]
+fn test_bass_enhancer_dry() {
+    let mut enhancer = BassEnhancer::new(2, SAMPLE_RATE, 120.0);
+    enhancer.mix = 0.0;
+    let (input, output) = render(&mut enhancer);
+    assert_eq!(input, output);
+
+    enhancer.set_frequency(80.0);
+    assert_eq!(enhancer.frequency(), 80.0);
+    assert_eq!(enhancer.sample_rate(), SAMPLE_RATE);
+}
@@ -0,0 +1,117 @@
+#![cfg(feature = "node-biquad")]
+
+use dasp_graph::node::Biquad;
+use dasp_graph::{Buffer, Input, Node};
+
+const B0: f64 = 0.5;
+const B1: f64 = 0.25;
+const B2: f64 = 0.125;
+const A1: f64 = -0.5;
+const A2: f64 = 0.25;
+
+// The impulse response of the filter, computed directly from the difference equation.
+fn expected_impulse_response(len: usize) -> Vec<f64> {
+    let mut y = vec![0.0; len];
+    for n in 0..len {
+        let x = |i: usize| if i == 0 { 1.0 } else { 0.0 };
+        y[n] = B0 * x(n);
+        if n >= 1 {
+            y[n] += B1 * x(n - 1) - A1 * y[n - 1];
+        }
+        if n >= 2 {
+            y[n] += B2 * x(n - 2) - A2 * y[n - 2];
+        }
+    }
+    y
+}
+
+fn impulse() -> Buffer {
+    let mut buf = Buffer::SILENT;
+    buf[0] = 1.0;
+    buf
+}
+
+#[test]
+fn test_biquad_impulse_response() {
+    let mut biquad = Biquad::new(B0, B1, B2, A1, A2);
+    let expected = expected_impulse_response(Buffer::LEN * 2);
+    assert_eq!(&expected[..5], &[0.5, 0.5, 0.25, 0.0, -0.0625]);
+
+    let mut output = [Buffer::SILENT, Buffer::SILENT];
+    let in_bufs = [impulse(), impulse()];
+    biquad.process(&[Input::new(&in_bufs, ())], &mut output);
+    let mut actual: Vec<f32> = output[0].to_vec();
+    assert_eq!(output[0], output[1]);
+
+    // The response continues into the following block.
+    let in_bufs = [Buffer::SILENT, Buffer::SILENT];
+    biquad.process(&[Input::new(&in_bufs, ())], &mut output);
+    actual.extend(output[0].iter().cloned());
+
+    for (a, e) in actual.iter().zip(&expected) {
+        assert!((*a as f64 - e).abs() < 1e-6, "{} != {}", a, e);
+    }
+}
+
+#[test]
+fn test_biquad_set_coefficients() {
+    let mut biquad = Biquad::new(1.0, 0.0, 0.0, 0.0, 0.0);
+    assert_eq!(biquad.coefficients(), [1.0, 0.0, 0.0, 0.0, 0.0]);
+
+    // An identity filter passes the input through unchanged.
+    let mut output = [Buffer::SILENT];
+    let in_bufs = [impulse()];
+    biquad.process(&[Input::new(&in_bufs, ())], &mut output);
+    assert_eq!(output[0], in_bufs[0]);
+
+    biquad.set_coefficients(B0, B1, B2, A1, A2);
+    assert_eq!(biquad.coefficients(), [B0, B1, B2, A1, A2]);
+    biquad.process(&[Input::new(&in_bufs, ())], &mut output);
+    let expected = expected_impulse_response(Buffer::LEN);
+    for (a, e) in output[0].iter().zip(&expected) {
+        assert!((*a as f64 - e).abs() < 1e-6, "{} != {}", a, e);
+    }
+}
+
+#[test]
+fn test_biquad_reset() {
+    let mut biquad = Biquad::new(B0, B1, B2, A1, A2);
+    let mut output = [Buffer::SILENT];
+
+    // Excite the filter at the end of the block so that the history is non-zero.
+    let mut in_buf = Buffer::SILENT;
+    in_buf[Buffer::LEN - 1] = 1.0;
+    biquad.process(&[Input::new(&[in_buf], ())], &mut output);
+    assert_ne!(output[0], Buffer::SILENT);
+
+    biquad.reset();
+    biquad.process(&[Input::new(&[Buffer::SILENT], ())], &mut output);
+    assert_eq!(output[0], Buffer::SILENT);
+}
+
+#[test]
+fn test_biquad_channels() {
+    let mut biquad = Biquad::new(B0, B1, B2, A1, A2);
+
+    // State is sized lazily, so the number of channels may grow between blocks.
+    let mut output = vec![Buffer::SILENT];
+    let mut in_buf = Buffer::SILENT;
+    in_buf[Buffer::LEN - 1] = 1.0;
+    biquad.process(&[Input::new(&[in_buf], ())], &mut output);
+    let mut output = vec![Buffer::SILENT; 3];
+    let in_bufs = [Buffer::SILENT, impulse()];
+    biquad.process(&[Input::new(&in_bufs, ())], &mut output);
+
+    // The first channel continues to ring while the new channel starts from silence.
+    assert_eq!(output[0][0], 0.5);
+    assert_eq!(output[1][0], B0 as f32);
+    assert_eq!(output[1][1], 0.5);
+
+    // Output channels without a matching input channel are silenced.
+    assert_eq!(output[2], Buffer::SILENT);
+
+    // Without an input, all outputs are silenced.
+    output.iter_mut().for_each(|buf| buf[0] = 1.0);
+    biquad.process(&[], &mut output);
+    assert!(output.iter().all(|buf| *buf == Buffer::SILENT));
+}
@@ -0,0 +1,112 @@
+use dasp_graph::{Buffer, Input, Node, NodeData, ProcessorAdapter};
+
+// A node that either writes a ramp continuing across blocks or scales the sum of its inputs.
+enum TestNode {
+    Ramp(f32),
+    Scale(f32),
+}
+
+impl Node for TestNode {
+    fn process(&mut self, inputs: &[Input], output: &mut [Buffer]) {
+        match self {
+            TestNode::Ramp(value) => {
+                for s in output[0].iter_mut() {
+                    *s = *value;
+                    *value += 1.0;
+                }
+            }
+            TestNode::Scale(scale) => {
+                for (ix, out) in output[0].iter_mut().enumerate() {
+                    *out = *scale * inputs.iter().map(|i| i.buffers()[0][ix]).sum::<f32>();
+                }
+            }
+        }
+    }
+}
+
+type Graph = petgraph::Graph<NodeData<TestNode>, (), petgraph::Directed, u32>;
+type Processor = dasp_graph::Processor<Graph>;
+
+fn graph() -> (Graph, petgraph::graph::NodeIndex) {
+    let mut g = Graph::new();
+    let a = g.add_node(NodeData::new1(TestNode::Ramp(0.0)));
+    let b = g.add_node(NodeData::new1(TestNode::Ramp(1_000.0)));
+    let out = g.add_node(NodeData::new1(TestNode::Scale(0.5)));
+    g.add_edge(a, out, ());
+    g.add_edge(b, out, ());
+    (g, out)
+}
+
+// Render the given number of frames with a processor of the given block size.
+fn render(block_frames: usize, frames: usize) -> Vec<f32> {
+    let (mut g, out) = graph();
+    let mut p = Processor::with_block_frames(4, block_frames);
+    assert_eq!(p.block_frames(), block_frames);
+    let mut output = vec![];
+    while output.len() < frames {
+        p.process(&mut g, out);
+        assert_eq!(g[out].buffers[0].len(), block_frames);
+        output.extend(g[out].buffers[0].iter().cloned());
+    }
+    output
+}
+
+#[test]
+fn test_block_frames_64_and_512() {
+    let small = render(64, 1_024);
+    let large = render(512, 1_024);
+    assert_eq!(small.len(), 1_024);
+    assert_eq!(small, large);
+    assert_eq!(small[0], 500.0);
+    assert_eq!(small[600], 1_100.0);
+}
+
+#[test]
+fn test_default_block_frames() {
+    assert_eq!(Processor::with_capacity(4).block_frames(), Buffer::LEN);
+}
+
+#[test]
+fn test_adapter_block_frames() {
+    let (mut g, out) = graph();
+    let p = Processor::with_block_frames(4, 256);
+    let mut adapter = ProcessorAdapter::new(p, 1);
+    let mut output = vec![0.0; 100];
+    adapter.process_interleaved(&mut g, out, &mut output);
+    assert_eq!(adapter.buffered_frames(), 156);
+    assert_eq!(output, render(256, 100)[..100].to_vec());
+}
+
+#[test]
+#[should_panic]
+fn test_zero_block_frames() {
+    Processor::with_block_frames(4, 0);
+}
+
+#[test]
+#[cfg(all(feature = "node-boxed", feature = "node-graph"))]
+fn test_nested_graph_block_frames() {
+    use dasp_graph::node::GraphNode;
+    use dasp_graph::BoxedNode;
+
+    // The nested processor is constructed with the default block size, yet must follow that of the
+    // outer processor.
+    let (graph, output_node) = graph();
+    let nested = GraphNode {
+        processor: Processor::with_capacity(4),
+        graph,
+        input_nodes: vec![],
+        output_node,
+        node_type: std::marker::PhantomData,
+    };
+    let mut g = petgraph::Graph::<NodeData<BoxedNode<()>>, (), petgraph::Directed, u32>::new();
+    let out = g.add_node(NodeData::boxed1(nested));
+    let mut p = dasp_graph::Processor::with_block_frames(4, 512);
+    let mut output = vec![];
+    while output.len() < 1_024 {
+        p.process(&mut g, out);
+        assert_eq!(g[out].buffers[0].len(), 512);
+        output.extend(g[out].buffers[0].iter().cloned());
+    }
+    assert_eq!(output, render(64, 1_024));
+}
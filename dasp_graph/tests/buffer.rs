@@ -0,0 +1,50 @@
+use dasp_graph::Buffer;
+
+#[test]
+fn test_is_silent() {
+    let mut buffer = Buffer::SILENT;
+    assert!(buffer.is_silent());
+    assert!(buffer.is_silent_eps(0.0));
+
+    buffer[Buffer::LEN / 2] = 0.5;
+    assert!(!buffer.is_silent());
+    assert!(!buffer.is_silent_eps(0.1));
+    assert!(buffer.is_silent_eps(0.5));
+
+    buffer[Buffer::LEN / 2] = -1e-9;
+    assert!(!buffer.is_silent());
+    assert!(buffer.is_silent_eps(1e-6));
+}
+
+#[test]
+fn test_set_len() {
+    let mut buffer = Buffer::SILENT;
+    assert_eq!(buffer.len(), Buffer::LEN);
+    buffer.iter_mut().for_each(|s| *s = 1.0);
+
+    // Shrinking retains the remaining samples while growing exposes silence.
+    buffer.set_len(16);
+    assert_eq!(&buffer[..], &[1.0; 16][..]);
+    buffer.set_len(512);
+    assert_eq!(buffer.len(), 512);
+    assert!(buffer[..16].iter().all(|&s| s == 1.0));
+    assert!(buffer[16..].iter().all(|&s| s == 0.0));
+
+    // The same holds for buffers longer than `Buffer::LEN`, including when shrinking below it.
+    buffer.set_len(256);
+    buffer.set_len(512);
+    assert!(buffer[..16].iter().all(|&s| s == 1.0));
+    assert!(buffer[16..].iter().all(|&s| s == 0.0));
+    buffer[100] = 1.0;
+    buffer.set_len(200);
+    buffer.set_len(32);
+    assert_eq!(&buffer[..16], &[1.0; 16][..]);
+    buffer.set_len(Buffer::LEN);
+    assert!(buffer[16..].iter().all(|&s| s == 0.0));
+    buffer.set_len(128);
+    assert!(buffer[16..].iter().all(|&s| s == 0.0));
+
+    buffer.silence();
+    assert_eq!(buffer, Buffer::silent(128));
+    assert_ne!(buffer, Buffer::SILENT);
+}
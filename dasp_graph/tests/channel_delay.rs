@@ -0,0 +1,71 @@
+#![cfg(all(feature = "node-boxed", feature = "node-channel-delay"))]
+
+use dasp_graph::{node, Buffer, Input, NodeData};
+
+type BoxedNode = dasp_graph::BoxedNode<()>;
+
+// A source node that writes a rising ramp to each of its output buffers.
+fn ramp_node(_inputs: &[Input], output: &mut [Buffer]) {
+    for o in output {
+        for (i, s) in o.iter_mut().enumerate() {
+            *s = (i + 1) as f32;
+        }
+    }
+}
+
+#[test]
+fn test_channel_delay() {
+    type Graph = petgraph::Graph<NodeData<BoxedNode>, (), petgraph::Directed, u32>;
+    type Processor = dasp_graph::Processor<Graph>;
+
+    let mut g = Graph::new();
+    let mut p = Processor::with_capacity(2);
+
+    // Delay only the second channel by 10 frames.
+    let ramp_node_ptr = ramp_node as fn(&[Input], &mut [Buffer]);
+    let src = g.add_node(NodeData::new2(BoxedNode::new(ramp_node_ptr)));
+    let delay = node::ChannelDelay::new(vec![0.0, 10.0]);
+    let delay = g.add_node(NodeData::new2(BoxedNode::new(delay)));
+    g.add_edge(src, delay, ());
+
+    p.process(&mut g, delay);
+
+    // Channel 0 is untouched.
+    assert_eq!(g[delay].buffers[0], g[src].buffers[0]);
+
+    // Channel 1 is silent for the first 10 frames, followed by the delayed ramp.
+    let ch1 = &g[delay].buffers[1];
+    assert!(ch1[..10].iter().all(|&s| s == 0.0));
+    for (i, &s) in ch1[10..].iter().enumerate() {
+        assert_eq!(s, (i + 1) as f32);
+    }
+
+    // The tail of the previous block carries over into the next.
+    p.process(&mut g, delay);
+    let ch1 = &g[delay].buffers[1];
+    let last = Buffer::LEN as f32;
+    assert_eq!(ch1[0], last - 9.0);
+    assert_eq!(ch1[9], last);
+    assert_eq!(ch1[10], 1.0);
+}
+
+#[test]
+fn test_channel_delay_fractional() {
+    type Graph = petgraph::Graph<NodeData<BoxedNode>, (), petgraph::Directed, u32>;
+    type Processor = dasp_graph::Processor<Graph>;
+
+    let mut g = Graph::new();
+    let mut p = Processor::with_capacity(2);
+
+    let ramp_node_ptr = ramp_node as fn(&[Input], &mut [Buffer]);
+    let src = g.add_node(NodeData::new1(BoxedNode::new(ramp_node_ptr)));
+    let delay = node::ChannelDelay::new(vec![2.5]);
+    let delay = g.add_node(NodeData::new1(BoxedNode::new(delay)));
+    g.add_edge(src, delay, ());
+
+    p.process(&mut g, delay);
+
+    // A delay of 2.5 frames lands half way between the ramp values.
+    let ch0 = &g[delay].buffers[0];
+    assert_eq!(&ch0[..5], &[0.0, 0.0, 0.5, 1.5, 2.5]);
+}
@@ -0,0 +1,62 @@
+// Helpers shared by the tests of nodes that are measured with sine waves.
+//
+// Each test crate only uses some of these helpers.
+#![allow(dead_code)]
+
+use dasp_graph::{Buffer, Input, Node};
+
+pub const SAMPLE_RATE: f64 = 48_000.0;
+
+// The magnitude of the given frequency within the signal via the Goertzel algorithm.
+pub fn magnitude(signal: &[f32], hz: f64) -> f64 {
+    let coeff = 2.0 * (2.0 * std::f64::consts::PI * hz / SAMPLE_RATE).cos();
+    let (mut s1, mut s2) = (0.0, 0.0);
+    for &x in signal {
+        let s0 = x as f64 + coeff * s1 - s2;
+        s2 = s1;
+        s1 = s0;
+    }
+    (s1 * s1 + s2 * s2 - coeff * s1 * s2).sqrt() / signal.len() as f64
+}
+
+// The given block of a sine of the given frequency and amplitude at `SAMPLE_RATE`.
+pub fn sine(block: usize, hz: f64, amp: f64) -> Buffer {
+    let mut buf = Buffer::SILENT;
+    for (i, s) in buf.iter_mut().enumerate() {
+        let t = (block * Buffer::LEN + i) as f64 / SAMPLE_RATE;
+        *s = (amp * (2.0 * std::f64::consts::PI * hz * t).sin()) as f32;
+    }
+    buf
+}
+
+// Process `blocks` blocks through the node with `outputs` output buffers, where `input` yields
+// the input buffers of each block. Returns the input and output of each channel from block
+// `settle` onwards, once any filters have settled.
+pub fn render<N, F>(
+    node: &mut N,
+    outputs: usize,
+    blocks: usize,
+    settle: usize,
+    mut input: F,
+) -> (Vec<Vec<f32>>, Vec<Vec<f32>>)
+where
+    N: Node,
+    F: FnMut(usize) -> Vec<Buffer>,
+{
+    let (mut ins, mut outs) = (vec![], vec![vec![]; outputs]);
+    let mut out_bufs = vec![Buffer::SILENT; outputs];
+    for block in 0..blocks {
+        let in_bufs = input(block);
+        node.process(&[Input::new(&in_bufs, ())], &mut out_bufs);
+        if block >= settle {
+            ins.resize(in_bufs.len(), vec![]);
+            for (ch, buf) in ins.iter_mut().zip(&in_bufs) {
+                ch.extend(buf.iter().cloned());
+            }
+            for (ch, buf) in outs.iter_mut().zip(&out_bufs) {
+                ch.extend(buf.iter().cloned());
+            }
+        }
+    }
+    (ins, outs)
+}
@@ -0,0 +1,57 @@
+#![cfg(feature = "node-compressor")]
+
+use dasp_graph::{node, Buffer, Input, Node};
+
+#[test]
+fn test_soft_knee_is_smooth() {
+    let mut comp = node::Compressor::new(-20.0, 4.0);
+    comp.knee_db = 10.0;
+
+    // Below the knee there is no reduction, above it the full ratio applies.
+    assert_eq!(comp.gain_reduction_db(-30.0), 0.0);
+    assert!((comp.gain_reduction_db(-5.0) - -11.25).abs() < 1e-4);
+
+    // Step across the knee region, checking the curve and its slope change gradually.
+    let step = 0.01;
+    let mut prev_reduction = comp.gain_reduction_db(-30.0);
+    let mut prev_slope = 0.0;
+    let mut db = -30.0 + step;
+    while db < -5.0 {
+        let reduction = comp.gain_reduction_db(db);
+        let slope = (reduction - prev_reduction) / step;
+        assert!(reduction <= prev_reduction);
+        assert!((reduction - prev_reduction).abs() < 0.01);
+        assert!((slope - prev_slope).abs() < 0.01, "{} {}", db, slope);
+        prev_reduction = reduction;
+        prev_slope = slope;
+        db += step;
+    }
+    // The slope settles at that of the ratio.
+    assert!((prev_slope - (1.0 / 4.0 - 1.0)).abs() < 0.01);
+}
+
+#[test]
+fn test_hard_knee() {
+    let mut comp = node::Compressor::new(-20.0, 2.0);
+    comp.knee_db = 0.0;
+    assert_eq!(comp.gain_reduction_db(-20.0), 0.0);
+    assert_eq!(comp.gain_reduction_db(-10.0), -5.0);
+}
+
+#[test]
+fn test_compressor_process() {
+    let mut comp = node::Compressor::new(-20.0, 2.0);
+    comp.knee_db = 0.0;
+    comp.attack_frames = 0.0;
+
+    // A constant signal at -10 dBFS is reduced by 5 dB.
+    let level = 10f32.powf(-10.0 / 20.0);
+    let bufs = vec![Buffer::from([level; Buffer::LEN]); 2];
+    let mut output = vec![Buffer::SILENT; 2];
+    comp.process(&[Input::new(&bufs, ())], &mut output);
+    let expected = 10f32.powf(-15.0 / 20.0);
+    for out_buf in &output {
+        assert!(out_buf.iter().all(|&s| (s - expected).abs() < 1e-5));
+    }
+    assert!((comp.current_gain_reduction_db() - -5.0).abs() < 1e-4);
+}
@@ -0,0 +1,60 @@
+#![cfg(feature = "node-convolver")]
+
+use dasp_graph::node::{Convolver, ConvolverRouting};
+use dasp_graph::{Buffer, Input, Node};
+
+// A buffer containing an impulse of the given amplitude at the first frame.
+fn impulse(amp: f32) -> Buffer {
+    let mut buf = Buffer::SILENT;
+    buf[0] = amp;
+    buf
+}
+
+#[test]
+fn test_convolver_stereo_ir() {
+    // The left IR is a two-tap echo, the right IR a single delayed tap.
+    let ir = [[1.0f32, 0.0], [0.0, 0.0], [0.5, 0.0], [0.0, 0.25]];
+    let mut convolver = Convolver::from_frames(&ir, 48_000.0);
+    assert_eq!(convolver.routing(), ConvolverRouting::Parallel);
+
+    let input = [impulse(1.0), impulse(-1.0)];
+    let mut output = [Buffer::SILENT, Buffer::SILENT];
+    convolver.process(&[Input::new(&input, ())], &mut output);
+    assert_eq!(&output[0][..5], &[1.0, 0.0, 0.5, 0.0, 0.0]);
+    assert_eq!(&output[1][..5], &[0.0, 0.0, 0.0, -0.25, 0.0]);
+}
+
+#[test]
+fn test_convolver_tail_across_blocks() {
+    let mut ir = vec![0.0; Buffer::LEN + 2];
+    ir[Buffer::LEN + 1] = 0.5;
+    let mut convolver = Convolver::new(1, vec![ir], 48_000.0, ConvolverRouting::Parallel);
+    let mut output = [Buffer::SILENT];
+    convolver.process(&[Input::new(&[impulse(1.0)], ())], &mut output);
+    assert_eq!(output[0], Buffer::SILENT);
+    convolver.process(&[Input::new(&[Buffer::SILENT], ())], &mut output);
+    assert_eq!(output[0][1], 0.5);
+    assert_eq!(output[0].iter().filter(|&&s| s != 0.0).count(), 1);
+}
+
+#[test]
+fn test_convolver_true_stereo() {
+    // Ordered L -> L, L -> R, R -> L, R -> R.
+    let ir = [[1.0f32, 0.5, 0.25, 0.125]];
+    let mut convolver = Convolver::from_frames(&ir, 48_000.0);
+    assert_eq!(convolver.routing(), ConvolverRouting::TrueStereo);
+
+    // A left-only impulse feeds both outputs via the L -> L and L -> R channels.
+    let input = [impulse(1.0), Buffer::SILENT];
+    let mut output = [Buffer::SILENT, Buffer::SILENT];
+    convolver.process(&[Input::new(&input, ())], &mut output);
+    assert_eq!(output[0][0], 1.0);
+    assert_eq!(output[1][0], 0.5);
+
+    // A right-only impulse feeds both outputs via the R -> L and R -> R channels.
+    convolver.reset();
+    let input = [Buffer::SILENT, impulse(1.0)];
+    convolver.process(&[Input::new(&input, ())], &mut output);
+    assert_eq!(output[0][0], 0.25);
+    assert_eq!(output[1][0], 0.125);
+}
@@ -0,0 +1,124 @@
+use dasp_graph::{Buffer, Input, Node, NodeData};
+
+type Graph = petgraph::Graph<NodeData<Ramp>, (), petgraph::Directed, u32>;
+type Processor = dasp_graph::Processor<Graph>;
+
+// A source that writes a rising ramp to each channel, offset by the channel index.
+struct Ramp(f32);
+
+impl Node for Ramp {
+    fn process(&mut self, _inputs: &[Input], output: &mut [Buffer]) {
+        for (ch, out_buf) in output.iter_mut().enumerate() {
+            for (i, s) in out_buf.iter_mut().enumerate() {
+                *s = self.0 + i as f32 + ch as f32 * 1000.0;
+            }
+        }
+        self.0 += 100.0;
+    }
+}
+
+#[test]
+fn test_copy_output() {
+    let mut g = Graph::new();
+    let mut p = Processor::with_capacity(2);
+    let src = g.add_node(NodeData::new2(Ramp(0.0)));
+
+    let mut dst = [Buffer::SILENT, Buffer::SILENT];
+    for _ in 0..3 {
+        p.process(&mut g, src);
+        assert_eq!(p.copy_output(&g, src, &mut dst), 2);
+        assert_eq!(&dst[..], &g[src].buffers[..]);
+    }
+    assert_eq!(dst[1][1], 1201.0);
+
+    // Only the channels that fit are copied.
+    let mut dst = [Buffer::SILENT];
+    assert_eq!(p.copy_output(&g, src, &mut dst), 1);
+    assert_eq!(dst[0], g[src].buffers[0]);
+
+    // Excess channels are silenced.
+    let mut dst = vec![Buffer::from([1.0; Buffer::LEN]); 3];
+    assert_eq!(p.copy_output(&g, src, &mut dst), 2);
+    assert_eq!(&dst[..2], &g[src].buffers[..]);
+    assert_eq!(dst[2], Buffer::SILENT);
+}
+
+#[test]
+fn test_copy_output_unprocessed() {
+    let mut g = Graph::new();
+    let mut p = Processor::with_capacity(2);
+    let a = g.add_node(NodeData::new1(Ramp(1.0)));
+    let b = g.add_node(NodeData::new1(Ramp(1.0)));
+    p.process(&mut g, a);
+
+    // `b` was not part of the processed subgraph.
+    let mut dst = [Buffer::from([1.0; Buffer::LEN])];
+    assert_eq!(p.copy_output(&g, b, &mut dst), 0);
+    assert_eq!(dst[0], Buffer::SILENT);
+}
+
+#[test]
+fn test_copy_output_block_frames() {
+    let mut g = Graph::new();
+    let mut p = Processor::with_block_frames(2, 16);
+    let src = g.add_node(NodeData::new1(Ramp(0.0)));
+    p.process(&mut g, src);
+
+    // The destination is sized to the block size of the processor up front.
+    let mut dst = [Buffer::silent(p.block_frames())];
+    assert_eq!(p.copy_output(&g, src, &mut dst), 1);
+    assert_eq!(dst[0].len(), 16);
+    assert_eq!(dst[0][15], 15.0);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "block_frames")]
+fn test_copy_output_block_frames_mismatch() {
+    let mut g = Graph::new();
+    let mut p = Processor::with_block_frames(2, 16);
+    let src = g.add_node(NodeData::new1(Ramp(0.0)));
+    p.process(&mut g, src);
+
+    // The destination is not resized, which could allocate.
+    let mut dst = [Buffer::SILENT];
+    p.copy_output(&g, src, &mut dst);
+}
+
+#[test]
+fn test_copy_output_after_invalidate() {
+    let mut g = Graph::new();
+    let mut p = Processor::with_capacity(2);
+    let src = g.add_node(NodeData::new1(Ramp(0.0)));
+    p.process(&mut g, src);
+
+    // The buffers still hold the most recent render after the order is discarded.
+    p.invalidate();
+    let mut dst = [Buffer::default()];
+    assert_eq!(p.copy_output(&g, src, &mut dst), 1);
+    assert_eq!(dst[0], g[src].buffers[0]);
+
+    let other = g.add_node(NodeData::new1(Ramp(0.0)));
+    p.crossfade_from(other, 128);
+    assert_eq!(p.copy_output(&g, src, &mut dst), 1);
+}
+
+#[test]
+fn test_copy_output_prepared_unprocessed() {
+    let mut g = Graph::new();
+    let mut p = Processor::with_capacity(2);
+    let a = g.add_node(NodeData::new1(Ramp(0.0)));
+    let b = g.add_node(NodeData::new1(Ramp(0.0)));
+    p.process(&mut g, a);
+
+    // Preparing the order of `b` does not render it.
+    p.prepare(&g, b);
+    let mut dst = [Buffer::from([1.0; Buffer::LEN])];
+    assert_eq!(p.copy_output(&g, b, &mut dst), 0);
+    assert_eq!(dst[0], Buffer::default());
+    assert_eq!(p.copy_output(&g, a, &mut dst), 1);
+
+    p.process_prepared(&mut g);
+    assert_eq!(p.copy_output(&g, b, &mut dst), 1);
+    assert_eq!(p.copy_output(&g, a, &mut dst), 0);
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "node-crest-meter")]
+
+use dasp_graph::node::CrestMeter;
+use dasp_graph::{Buffer, Input, Node};
+
+const WINDOW: usize = 4400;
+
+// Process enough blocks of the given periodic waveform to fill the window.
+fn measure(meter: &mut CrestMeter, wave: impl Fn(usize) -> f32) {
+    let mut frame = 0;
+    let mut output = vec![Buffer::SILENT; 2];
+    for _ in 0..2 * WINDOW / Buffer::LEN {
+        let mut buf = Buffer::SILENT;
+        for s in buf.iter_mut() {
+            *s = wave(frame);
+            frame += 1;
+        }
+        let bufs = vec![buf.clone(), buf];
+        meter.process(&[Input::new(&bufs, ())], &mut output);
+        assert_eq!(output, bufs);
+    }
+}
+
+#[test]
+fn test_crest_meter_sine_and_square() {
+    // 441Hz at 44.1kHz, such that the window holds a whole number of periods.
+    let period = 100;
+    let mut meter = CrestMeter::new(WINDOW);
+    let handle = meter.handle();
+    assert_eq!(handle.crest(), 0.0);
+
+    let sine = |i: usize| 0.5 * (2.0 * std::f32::consts::PI * i as f32 / period as f32).sin();
+    measure(&mut meter, sine);
+    assert!(
+        (handle.crest() - 2f32.sqrt()).abs() < 0.01,
+        "{}",
+        handle.crest()
+    );
+    assert!((handle.crest_db() - 3.01).abs() < 0.05);
+
+    let square = |i: usize| if i % period < period / 2 { 0.5 } else { -0.5 };
+    measure(&mut meter, square);
+    assert!((meter.crest() - 1.0).abs() < 0.001, "{}", meter.crest());
+    assert!(meter.crest_db().abs() < 0.01);
+
+    meter.reset();
+    assert_eq!(handle.crest(), 0.0);
+}
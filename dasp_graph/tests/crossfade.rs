@@ -0,0 +1,94 @@
+#![cfg(all(feature = "node-boxed", feature = "node-gain", feature = "node-pass"))]
+
+use dasp_graph::{node, Buffer, Input, Node, NodeData};
+
+type BoxedNode = dasp_graph::BoxedNode<()>;
+
+const STEP: f32 = 0.001;
+
+// A source node that writes a continuous rising ramp across blocks.
+struct Ramp(f32);
+
+impl Node for Ramp {
+    fn process(&mut self, _inputs: &[Input], output: &mut [Buffer]) {
+        for s in output[0].iter_mut() {
+            self.0 += STEP;
+            *s = self.0;
+        }
+    }
+}
+
+#[test]
+fn test_crossfade_insert_gain() {
+    type Graph = petgraph::Graph<NodeData<BoxedNode>, (), petgraph::Directed, u32>;
+    type Processor = dasp_graph::Processor<Graph>;
+
+    let mut g = Graph::new();
+    let mut p = Processor::with_capacity(8);
+
+    // The initial topology: src -> out_a.
+    let src = g.add_node(NodeData::boxed1(Ramp(0.0)));
+    let out_a = g.add_node(NodeData::boxed1(node::Pass));
+    g.add_edge(src, out_a, ());
+
+    let mut output = vec![];
+    for _ in 0..4 {
+        p.process(&mut g, out_a);
+        output.extend(g[out_a].buffers[0].iter().cloned());
+    }
+
+    // Insert a gain node mid-stream via a new path: src -> gain -> out_b.
+    let gain = g.add_node(NodeData::boxed1(node::Gain::new(0.5)));
+    let out_b = g.add_node(NodeData::boxed1(node::Pass));
+    g.add_edge(src, gain, ());
+    g.add_edge(gain, out_b, ());
+
+    let fade_len = Buffer::LEN * 4;
+    p.crossfade_from(out_a, fade_len);
+    for _ in 0..4 {
+        assert!(p.is_crossfading());
+        p.process(&mut g, out_b);
+        output.extend(g[out_b].buffers[0].iter().cloned());
+    }
+    assert!(!p.is_crossfading());
+
+    // The old path may now be removed.
+    // Removing a node from a `Graph` moves the last node into the removed node's index.
+    g.remove_node(out_a);
+    let out_b = out_a;
+    p.process(&mut g, out_b);
+    output.extend(g[out_b].buffers[0].iter().cloned());
+
+    // The shared source is only processed once per block, so the ramp is continuous, and the
+    // crossfade ensures the change in gain introduces no discontinuity.
+    for w in output.windows(2) {
+        assert!((w[1] - w[0]).abs() < STEP * 2.0, "{:?}", w);
+    }
+
+    // Following the crossfade, the output is the gain applied to the ramp.
+    let last = output[output.len() - 1];
+    let expected = 0.5 * STEP * (Buffer::LEN * 9) as f32;
+    assert!((last - expected).abs() < 1e-4);
+}
+
+#[test]
+fn test_crossfade_without_edit_is_transparent() {
+    type Graph = petgraph::Graph<NodeData<BoxedNode>, (), petgraph::Directed, u32>;
+    type Processor = dasp_graph::Processor<Graph>;
+
+    let mut g = Graph::new();
+    let mut p = Processor::with_capacity(4);
+    let src = g.add_node(NodeData::boxed1(Ramp(0.0)));
+    let out = g.add_node(NodeData::boxed1(node::Pass));
+    g.add_edge(src, out, ());
+
+    // Crossfading from the node being processed has no effect on the output.
+    p.crossfade_from(out, Buffer::LEN);
+    p.process(&mut g, out);
+    assert!(!p.is_crossfading());
+    assert_eq!(g[out].buffers[0], g[src].buffers[0]);
+
+    // A zero-length crossfade is cancelled immediately.
+    p.crossfade_from(src, 0);
+    assert!(!p.is_crossfading());
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "node-crossover")]
+
+use dasp_graph::{node, Buffer, Input, Node};
+
+const SAMPLE_RATE: f64 = 48_000.0;
+
+// Process a sine of the given frequency through the crossover, returning the amplitude of each
+// band and of the sum of all bands once the filters have settled.
+//
+// The amplitude is measured via RMS, as the sampled peaks of high frequencies depend on phase.
+fn amplitudes(crossover: &mut node::Crossover, hz: f64) -> (Vec<f32>, f32) {
+    let bands = crossover.bands();
+    let mut output = vec![Buffer::SILENT; bands];
+    let mut band_squares = vec![0.0f64; bands];
+    let mut sum_squares = 0.0f64;
+    let mut phase = 0.0f64;
+    let mut count = 0;
+    for block in 0..200 {
+        let mut input = Buffer::SILENT;
+        for s in input.iter_mut() {
+            *s = (phase * 2.0 * std::f64::consts::PI).sin() as f32;
+            phase = (phase + hz / SAMPLE_RATE) % 1.0;
+        }
+        crossover.process(&[Input::new(&[input], ())], &mut output);
+        if block < 100 {
+            continue;
+        }
+        for ix in 0..Buffer::LEN {
+            let sum: f32 = output.iter().map(|buf| buf[ix]).sum();
+            sum_squares += (sum * sum) as f64;
+            for (squares, buf) in band_squares.iter_mut().zip(&output) {
+                *squares += (buf[ix] * buf[ix]) as f64;
+            }
+            count += 1;
+        }
+    }
+    // Scale the RMS of each so that the input sine has an amplitude of `1.0`.
+    let amp = |squares: f64| ((squares / count as f64).sqrt() * 2f64.sqrt()) as f32;
+    (
+        band_squares.into_iter().map(amp).collect(),
+        amp(sum_squares),
+    )
+}
+
+#[test]
+fn test_two_band_reconstruction() {
+    for &hz in &[100.0, 500.0, 1_000.0, 2_000.0, 8_000.0] {
+        let mut crossover = node::Crossover::new(SAMPLE_RATE, vec![1_000.0]);
+        let (_, sum) = amplitudes(&mut crossover, hz);
+        assert!((sum - 1.0).abs() < 0.01, "{} Hz: {}", hz, sum);
+    }
+}
+
+#[test]
+fn test_two_band_split() {
+    let mut crossover = node::Crossover::new(SAMPLE_RATE, vec![1_000.0]);
+    let (low, _) = amplitudes(&mut crossover, 100.0);
+    assert!(low[0] > 0.99 && low[1] < 0.01);
+    crossover.reset();
+    let (high, _) = amplitudes(&mut crossover, 10_000.0);
+    assert!(high[0] < 0.01 && high[1] > 0.99);
+    // Both bands are 6 dB down at the crossover frequency.
+    crossover.reset();
+    let (mid, _) = amplitudes(&mut crossover, 1_000.0);
+    assert!((mid[0] - 0.5).abs() < 0.01 && (mid[1] - 0.5).abs() < 0.01);
+}
+
+#[test]
+fn test_three_band_reconstruction() {
+    for &hz in &[50.0, 200.0, 800.0, 3_000.0, 12_000.0] {
+        let mut crossover = node::Crossover::new(SAMPLE_RATE, vec![4_000.0, 300.0]);
+        assert_eq!(crossover.frequencies(), &[300.0, 4_000.0]);
+        let (_, sum) = amplitudes(&mut crossover, hz);
+        assert!((sum - 1.0).abs() < 0.01, "{} Hz: {}", hz, sum);
+    }
+}
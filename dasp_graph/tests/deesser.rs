@@ -0,0 +1,68 @@
+#![cfg(feature = "node-deesser")]
+
+mod common;
+
+use common::{magnitude, SAMPLE_RATE};
+use dasp_graph::node::{DeEsser, DeEsserMode};
+use dasp_graph::{Buffer, Input, Node};
+
+// Frequencies are chosen to fall exactly on the bins of the measured length to avoid leakage.
+const LOW_HZ: f64 = 187.5;
+const SIBILANT_HZ: f64 = 7_500.0;
+
+// Render a continuous low tone with a loud sibilant burst starting at block 16, returning the
+// input and output during the burst once the de-esser has settled.
+fn render(deesser: &mut DeEsser) -> (Vec<f32>, Vec<f32>) {
+    let (mut input, mut output) = common::render(deesser, 1, 96, 32, |block| {
+        let mut buf = common::sine(block, LOW_HZ, 0.4);
+        if block >= 16 {
+            let high = common::sine(block, SIBILANT_HZ, 0.4);
+            buf.iter_mut().zip(high.iter()).for_each(|(s, h)| *s += h);
+        }
+        vec![buf]
+    });
+    (input.remove(0), output.remove(0))
+}
+
+fn deesser(mode: DeEsserMode) -> DeEsser {
+    let mut deesser = DeEsser::new(1, SAMPLE_RATE, 4_000.0);
+    deesser.mode = mode;
+    deesser.threshold_db = -24.0;
+    deesser.range_db = 12.0;
+    deesser
+}
+
+#[test]
+fn test_deesser_split_band() {
+    let mut deesser = deesser(DeEsserMode::SplitBand);
+    let (input, output) = render(&mut deesser);
+    // The sibilant band is attenuated by roughly the full range.
+    let high = magnitude(&output, SIBILANT_HZ) / magnitude(&input, SIBILANT_HZ);
+    assert!(high < 0.25, "{}", high);
+    assert!((deesser.current_gain_reduction_db() + 12.0).abs() < 0.5);
+    // The low content is untouched.
+    let low = magnitude(&output, LOW_HZ) / magnitude(&input, LOW_HZ);
+    assert!((low - 1.0).abs() < 0.01, "{}", low);
+}
+
+#[test]
+fn test_deesser_broadband() {
+    let mut deesser = deesser(DeEsserMode::Broadband);
+    let (input, output) = render(&mut deesser);
+    // The whole signal is attenuated by the full range.
+    let low = magnitude(&output, LOW_HZ) / magnitude(&input, LOW_HZ);
+    assert!((low - 0.251).abs() < 0.01, "{}", low);
+}
+
+#[test]
+fn test_deesser_transparent_below_threshold() {
+    let mut deesser = deesser(DeEsserMode::SplitBand);
+    let mut out_bufs = vec![Buffer::SILENT];
+    for _ in 0..4 {
+        let in_bufs = [Buffer::from([0.01; Buffer::LEN])];
+        deesser.process(&[Input::new(&in_bufs, ())], &mut out_bufs);
+        for (&out, &x) in out_bufs[0].iter().zip(in_bufs[0].iter()) {
+            assert!((out - x).abs() < 1e-6);
+        }
+    }
+}
@@ -0,0 +1,67 @@
+#![cfg(feature = "node-env-vca")]
+
+use dasp_graph::node::{EnvVca, EnvVcaInput};
+use dasp_graph::{Buffer, Input, Node};
+
+// Process a single block of constant audio with the gate held at the given value.
+fn process(env: &mut EnvVca, gate: f32) -> Buffer {
+    let audio = [Buffer::from([1.0; Buffer::LEN])];
+    let gate = [Buffer::from([gate; Buffer::LEN])];
+    let inputs = [
+        Input::new(&gate, EnvVcaInput::Gate),
+        Input::new(&audio, EnvVcaInput::Audio),
+    ];
+    let mut output = [Buffer::SILENT];
+    env.process(&inputs, &mut output);
+    output[0].clone()
+}
+
+#[test]
+fn test_env_vca_attack_release() {
+    let len = Buffer::LEN as f32;
+    let mut env = EnvVca::new(len, 1.0, 0.5, 2.0 * len);
+
+    // The attack rises linearly over the first block.
+    let attack = process(&mut env, 1.0);
+    for (i, &s) in attack.iter().enumerate() {
+        assert!((s - (i + 1) as f32 / len).abs() < 1e-5);
+    }
+    // The decay falls to the sustain level within a frame, where it is held.
+    let sustain = process(&mut env, 1.0);
+    assert!(sustain.iter().all(|&s| s == 0.5));
+
+    // The release falls linearly over two blocks from the sustain level.
+    let release = process(&mut env, 0.0);
+    for (i, &s) in release.iter().enumerate() {
+        assert!((s - (0.5 - 0.5 * (i + 1) as f32 / (2.0 * len))).abs() < 1e-5);
+    }
+    process(&mut env, 0.0);
+    assert!(!env.is_active());
+    assert_eq!(process(&mut env, 0.0), Buffer::SILENT);
+}
+
+#[test]
+fn test_env_vca_retrigger_click_free() {
+    let len = Buffer::LEN as f32;
+    let mut env = EnvVca::new(len, 1.0, 1.0, 2.0 * len);
+    process(&mut env, 1.0);
+    process(&mut env, 1.0);
+    let release = process(&mut env, 0.0);
+    let retrigger = process(&mut env, 1.0);
+    // The attack resumes from the current level rather than jumping back to silence.
+    assert!(release[Buffer::LEN - 1] > 0.25);
+    let mut last = release[Buffer::LEN - 1];
+    for &s in retrigger.iter() {
+        assert!(s >= last && s - last <= 1.0 / len + 1e-5);
+        last = s;
+    }
+}
+
+#[test]
+fn test_env_vca_no_gate() {
+    let mut env = EnvVca::new(1.0, 1.0, 1.0, 1.0);
+    let audio = [Buffer::from([1.0; Buffer::LEN])];
+    let mut output = [Buffer::from([1.0; Buffer::LEN])];
+    env.process(&[Input::new(&audio, EnvVcaInput::Audio)], &mut output);
+    assert_eq!(output[0], Buffer::SILENT);
+}
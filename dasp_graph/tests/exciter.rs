@@ -0,0 +1,35 @@
+#![cfg(feature = "node-exciter")]
+
+mod common;
+
+use common::{magnitude, SAMPLE_RATE};
+use dasp_graph::node;
+
+// Render a sine of the given frequency through the exciter, returning the input and output.
+fn render(exciter: &mut node::Exciter, hz: f64) -> (Vec<f32>, Vec<f32>) {
+    let (mut input, mut output) = common::render(exciter, 1, 64, 0, |block| {
+        vec![common::sine(block, hz, 0.5)]
+    });
+    (input.remove(0), output.remove(0))
+}
+
+#[test]
+fn test_exciter_adds_harmonics() {
+    let mut exciter = node::Exciter::new(1, SAMPLE_RATE, 1_000.0);
+    // Frequencies are chosen to fall exactly on the bins of the rendered length to avoid leakage.
+    let (input, output) = render(&mut exciter, 1_875.0);
+    // The saturation generates odd harmonics that were not present in the input.
+    for &hz in &[5_625.0, 9_375.0] {
+        assert!(magnitude(&input, hz) < 1e-4);
+        assert!(magnitude(&output, hz) > 1e-3, "{} Hz", hz);
+    }
+}
+
+#[test]
+fn test_exciter_leaves_low_end() {
+    let mut exciter = node::Exciter::new(1, SAMPLE_RATE, 2_000.0);
+    exciter.mix = 0.5;
+    let (input, output) = render(&mut exciter, 50.0);
+    let diff = (magnitude(&output, 50.0) - magnitude(&input, 50.0)).abs();
+    assert!(diff < 0.01 * magnitude(&input, 50.0));
+}
@@ -0,0 +1,135 @@
+use dasp_graph::{Buffer, Input, Node, NodeData};
+
+// A node that writes a constant, sums its inputs or scales the sum of its inputs.
+enum TestNode {
+    Constant(f32),
+    Sum,
+    Scale(f32),
+}
+
+impl Node for TestNode {
+    fn process(&mut self, inputs: &[Input], output: &mut [Buffer]) {
+        let value = match *self {
+            TestNode::Constant(value) => value,
+            TestNode::Sum => inputs.iter().map(|input| input.buffers()[0][0]).sum(),
+            TestNode::Scale(scale) => {
+                scale
+                    * inputs
+                        .iter()
+                        .map(|input| input.buffers()[0][0])
+                        .sum::<f32>()
+            }
+        };
+        output[0].iter_mut().for_each(|s| *s = value);
+    }
+}
+
+type Graph = petgraph::Graph<NodeData<TestNode>, (), petgraph::Directed, u32>;
+type Processor = dasp_graph::Processor<Graph>;
+
+// Process `blocks` blocks, collecting the first sample of `node` after each.
+fn render(g: &mut Graph, node: petgraph::graph::NodeIndex, blocks: usize) -> Vec<f32> {
+    let mut p = Processor::with_capacity(8);
+    (0..blocks)
+        .map(|_| {
+            p.process(g, node);
+            g[node].buffers[0][0]
+        })
+        .collect()
+}
+
+#[test]
+fn test_feedback_cycle() {
+    // A constant input summed with half of the sum from the previous block.
+    let mut g = Graph::new();
+    let input = g.add_node(NodeData::new1(TestNode::Constant(1.0)));
+    let sum = g.add_node(NodeData::new1(TestNode::Sum));
+    let feedback = g.add_node(NodeData::new1(TestNode::Scale(0.5)).with_feedback());
+    g.add_edge(input, sum, ());
+    g.add_edge(sum, feedback, ());
+    g.add_edge(feedback, sum, ());
+
+    assert_eq!(render(&mut g, sum, 4), vec![1.0, 1.5, 1.75, 1.875]);
+    assert_eq!(g[feedback].buffers[0][0], 0.9375);
+}
+
+#[test]
+fn test_feedback_output_node() {
+    // The feedback node may also be the output, in which case it is visited last as usual.
+    let mut g = Graph::new();
+    let input = g.add_node(NodeData::new1(TestNode::Constant(1.0)));
+    let sum = g.add_node(NodeData::new1(TestNode::Sum));
+    let feedback = g.add_node(NodeData::new1(TestNode::Scale(0.5)).with_feedback());
+    g.add_edge(input, sum, ());
+    g.add_edge(sum, feedback, ());
+    g.add_edge(feedback, sum, ());
+
+    assert_eq!(render(&mut g, feedback, 3), vec![0.5, 0.75, 0.875]);
+}
+
+#[test]
+fn test_feedback_self_loop() {
+    // A node that adds its own output from the previous block to a constant input.
+    let mut g = Graph::new();
+    let input = g.add_node(NodeData::new1(TestNode::Constant(1.0)));
+    let acc = g.add_node(NodeData::new1(TestNode::Sum).with_feedback());
+    g.add_edge(input, acc, ());
+    g.add_edge(acc, acc, ());
+
+    assert_eq!(render(&mut g, acc, 4), vec![1.0, 2.0, 3.0, 4.0]);
+}
+
+#[test]
+fn test_self_loop_without_feedback_is_skipped() {
+    let mut g = Graph::new();
+    let input = g.add_node(NodeData::new1(TestNode::Constant(1.0)));
+    let sum = g.add_node(NodeData::new1(TestNode::Sum));
+    g.add_edge(input, sum, ());
+    g.add_edge(sum, sum, ());
+
+    assert_eq!(render(&mut g, sum, 3), vec![1.0, 1.0, 1.0]);
+}
+
+#[test]
+fn test_feedback_only_input() {
+    // A node whose only input is a feedback node fed by an unrelated source, which must still be
+    // processed in order to deliver its output one block later.
+    let mut g = Graph::new();
+    let mut values = vec![];
+    let input = g.add_node(NodeData::new1(TestNode::Constant(2.0)));
+    let feedback = g.add_node(NodeData::new1(TestNode::Scale(1.0)).with_feedback());
+    let out = g.add_node(NodeData::new1(TestNode::Sum));
+    g.add_edge(input, feedback, ());
+    g.add_edge(feedback, out, ());
+
+    let mut p = Processor::with_capacity(8);
+    for _ in 0..3 {
+        p.process(&mut g, out);
+        values.push(g[out].buffers[0][0]);
+        if let TestNode::Constant(ref mut value) = g[input].node {
+            *value += 1.0;
+        }
+    }
+    assert_eq!(values, vec![0.0, 2.0, 3.0]);
+}
+
+#[test]
+fn test_feedback_prepared() {
+    let mut g = Graph::new();
+    let input = g.add_node(NodeData::new1(TestNode::Constant(1.0)));
+    let sum = g.add_node(NodeData::new1(TestNode::Sum));
+    let feedback = g.add_node(NodeData::new1(TestNode::Scale(0.5)).with_feedback());
+    g.add_edge(input, sum, ());
+    g.add_edge(sum, feedback, ());
+    g.add_edge(feedback, sum, ());
+
+    let mut p = Processor::with_capacity(8);
+    p.prepare(&g, sum);
+    let values: Vec<f32> = (0..3)
+        .map(|_| {
+            p.process_prepared(&mut g);
+            g[sum].buffers[0][0]
+        })
+        .collect();
+    assert_eq!(values, vec![1.0, 1.5, 1.75]);
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "node-filter-morph")]
+
+mod common;
+
+use common::{magnitude, SAMPLE_RATE};
+use dasp_graph::node::{FilterMode, FilterMorph, FilterShape};
+
+// Frequencies are chosen to fall exactly on the bins of the measured length to avoid leakage.
+const LOW_HZ: f64 = 93.75;
+const CUTOFF_HZ: f64 = 1_031.25;
+const HIGH_HZ: f64 = 11_250.0;
+
+// The gain of the filter at the given frequency, measured once the filter has settled.
+fn gain(filter: &mut FilterMorph, hz: f64) -> f64 {
+    filter.reset();
+    let (input, output) = common::render(filter, 1, 80, 16, |block| {
+        vec![common::sine(block, hz, 0.5)]
+    });
+    magnitude(&output[0], hz) / magnitude(&input[0], hz)
+}
+
+fn low_to_high_pass() -> FilterMorph {
+    let q = std::f64::consts::FRAC_1_SQRT_2;
+    let a = FilterShape::new(FilterMode::LowPass, CUTOFF_HZ, q);
+    let b = FilterShape::new(FilterMode::HighPass, CUTOFF_HZ, q);
+    FilterMorph::new(1, SAMPLE_RATE, a, b)
+}
+
+#[test]
+fn test_filter_morph_endpoints() {
+    let mut filter = low_to_high_pass();
+    assert!((gain(&mut filter, LOW_HZ) - 1.0).abs() < 0.01);
+    assert!(gain(&mut filter, HIGH_HZ) < 0.01);
+    filter.morph = 1.0;
+    assert!(gain(&mut filter, LOW_HZ) < 0.01);
+    assert!((gain(&mut filter, HIGH_HZ) - 1.0).abs() < 0.01);
+}
+
+#[test]
+fn test_filter_morph_midpoint() {
+    let mut filter = low_to_high_pass();
+    filter.morph = 0.5;
+    // Half of each response passes at the extremes, while they cancel at the cutoff.
+    assert!((gain(&mut filter, LOW_HZ) - 0.5).abs() < 0.01);
+    assert!((gain(&mut filter, HIGH_HZ) - 0.5).abs() < 0.02);
+    assert!(gain(&mut filter, CUTOFF_HZ) < 0.01);
+}
+
+#[test]
+fn test_filter_morph_frequency() {
+    let a = FilterShape::new(FilterMode::LowPass, 100.0, 0.5);
+    let b = FilterShape::new(FilterMode::LowPass, 10_000.0, 2.0);
+    let mut filter = FilterMorph::new(1, SAMPLE_RATE, a, b);
+    filter.morph = 0.5;
+    let shape = filter.shape();
+    assert!((shape.frequency - 1_000.0).abs() < 1e-9);
+    assert!((shape.q - 1.0).abs() < 1e-9);
+}
@@ -0,0 +1,75 @@
+#![cfg(all(feature = "ftz", feature = "node-boxed"))]
+
+use dasp_graph::{Buffer, Input, Node, NodeData};
+
+type BoxedNode = dasp_graph::BoxedNode<()>;
+
+// A source node that writes a single impulse at the very first frame it renders.
+struct Impulse(bool);
+
+impl Node for Impulse {
+    fn process(&mut self, _inputs: &[Input], output: &mut [Buffer]) {
+        output[0].silence();
+        if !self.0 {
+            output[0][0] = 1.0;
+            self.0 = true;
+        }
+    }
+}
+
+// A decaying "reverb" tail that feeds back the last sample of its own previous output.
+struct Decay;
+
+impl Node for Decay {
+    fn process(&mut self, inputs: &[Input], output: &mut [Buffer]) {
+        let mut y = output[0][Buffer::LEN - 1];
+        for (out, x) in output[0].iter_mut().zip(inputs[0].buffers()[0].iter()) {
+            y = x + y * 0.9;
+            *out = y;
+        }
+    }
+}
+
+// Render the decay of an impulse, returning whether any denormals were output.
+fn render(flush: bool) -> (bool, Buffer) {
+    type Graph = petgraph::Graph<NodeData<BoxedNode>, (), petgraph::Directed, u32>;
+    type Processor = dasp_graph::Processor<Graph>;
+
+    let mut g = Graph::new();
+    let mut p = Processor::with_capacity(2);
+    assert!(p.flushes_denormals());
+    p.set_flush_denormals(flush);
+    let src = g.add_node(NodeData::boxed1(Impulse(false)));
+    let decay = g.add_node(NodeData::boxed1(Decay));
+    g.add_edge(src, decay, ());
+
+    let mut denormals = false;
+    for _ in 0..32 {
+        p.process(&mut g, decay);
+        denormals |= g[decay].buffers[0].iter().any(|s| s.is_subnormal());
+    }
+    (denormals, g[decay].buffers[0].clone())
+}
+
+#[test]
+fn test_flush_denormals() {
+    // Without flushing, the tail decays into denormals.
+    let (denormals, _) = render(false);
+    assert!(denormals);
+
+    // With flushing, the tail drops straight from the smallest normal values to silence.
+    let (denormals, last) = render(true);
+    assert!(!denormals);
+    assert!(last.is_silent());
+}
+
+#[test]
+fn test_buffer_flush_denormals() {
+    let mut buffer = Buffer::SILENT;
+    buffer[0] = f32::MIN_POSITIVE;
+    buffer[1] = f32::MIN_POSITIVE / 2.0;
+    buffer[2] = -f32::MIN_POSITIVE / 4.0;
+    buffer[3] = 0.5;
+    buffer.flush_denormals();
+    assert_eq!(&buffer[..4], &[f32::MIN_POSITIVE, 0.0, 0.0, 0.5]);
+}
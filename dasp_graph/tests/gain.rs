@@ -0,0 +1,41 @@
+#![cfg(feature = "node-gain")]
+
+use dasp_graph::{node, Buffer, Input, Node};
+
+#[test]
+fn test_gain() {
+    let mut gain = node::Gain::new(0.5);
+    let mut bufs = vec![Buffer::default(); 2];
+    bufs.iter_mut()
+        .for_each(|b| b.iter_mut().for_each(|s| *s = 1.0));
+    let mut output = vec![Buffer::default(); 2];
+    gain.process(&[Input::new(&bufs, ())], &mut output);
+    for out_buf in &output {
+        assert!(out_buf.iter().all(|&s| s == 0.5));
+    }
+}
+
+#[test]
+fn test_fader_position_audio_taper() {
+    let mut gain = node::Gain::default();
+    assert_eq!(gain.law(), node::FaderLaw::Audio);
+    gain.set_position(0.5);
+    assert_eq!(gain.gain(), 0.125);
+    gain.set_position(1.0);
+    assert_eq!(gain.gain(), 1.0);
+    gain.set_position(0.0);
+    assert_eq!(gain.gain(), 0.0);
+    // Positions outside the range are clamped.
+    gain.set_position(2.0);
+    assert_eq!(gain.gain(), 1.0);
+}
+
+#[test]
+fn test_fader_laws() {
+    assert_eq!(node::FaderLaw::Linear.gain(0.5), 0.5);
+    let db = node::FaderLaw::Decibels { min_db: -60.0 };
+    assert_eq!(db.gain(0.0), 0.0);
+    assert_eq!(db.gain(1.0), 1.0);
+    // Half way is -30 dB.
+    assert!((db.gain(0.5) - 0.031_622_776).abs() < 1e-6);
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "node-gate")]
+
+use dasp_graph::{node, Buffer, Input, Node};
+
+fn gate() -> node::Gate {
+    let mut gate = node::Gate::new(-20.0);
+    gate.attack_frames = 0.0;
+    gate.hold_frames = 0;
+    gate.release_frames = 0.0;
+    gate
+}
+
+#[test]
+fn test_gate_sidechain_key() {
+    let mut gate = gate();
+    let main = [Buffer::from([0.5; Buffer::LEN])];
+    let mut out = [Buffer::SILENT; 2];
+    // The key pulses open on every other block, while the main input is continuous.
+    for block in 0..8 {
+        let level = if block % 2 == 0 { 1.0 } else { 0.0 };
+        let key = [Buffer::from([level; Buffer::LEN])];
+        let inputs = [Input::new(&main, ()), Input::new(&key, ())];
+        gate.process(&inputs, &mut out);
+        let expected = if block % 2 == 0 { 0.5 } else { 0.0 };
+        assert!(out[0].iter().all(|&s| s == expected), "block {}", block);
+        assert_eq!(gate.is_open(), block % 2 == 0);
+        // The mono key is broadcast, while channels without input are silenced.
+        assert_eq!(out[1], Buffer::SILENT);
+    }
+}
+
+#[test]
+fn test_gate_self_keyed() {
+    let mut gate = gate();
+    let mut out = [Buffer::SILENT];
+    let loud = [Buffer::from([0.5; Buffer::LEN])];
+    gate.process(&[Input::new(&loud, ())], &mut out);
+    assert_eq!(out[0], loud[0]);
+    let quiet = [Buffer::from([0.01; Buffer::LEN])];
+    gate.process(&[Input::new(&quiet, ())], &mut out);
+    assert_eq!(out[0], Buffer::SILENT);
+}
+
+#[test]
+fn test_gate_hold_and_release() {
+    let mut gate = node::Gate::new(-20.0);
+    gate.attack_frames = 0.0;
+    gate.hold_frames = 8;
+    let main = [Buffer::from([1.0; Buffer::LEN])];
+    let mut key = [Buffer::SILENT];
+    key[0][0] = 1.0;
+    let mut out = [Buffer::SILENT];
+    gate.process(&[Input::new(&main, ()), Input::new(&key, ())], &mut out);
+    // Fully open throughout the hold period, then smoothly decaying.
+    assert!(out[0][..9].iter().all(|&s| s == 1.0));
+    assert!(out[0][9] < 1.0 && out[0][9] > 0.99);
+    assert!(!gate.is_open());
+}
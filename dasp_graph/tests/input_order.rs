@@ -0,0 +1,74 @@
+use dasp_graph::{Buffer, Input, Node, NodeData};
+
+// A node that either writes a constant value or records the index and value of each input.
+enum TestNode {
+    Source(f32),
+    Record(Vec<(usize, f32)>),
+}
+
+impl Node for TestNode {
+    fn process(&mut self, inputs: &[Input], output: &mut [Buffer]) {
+        match self {
+            TestNode::Source(value) => {
+                for out_buf in output.iter_mut() {
+                    out_buf.iter_mut().for_each(|s| *s = *value);
+                }
+            }
+            TestNode::Record(record) => {
+                record.clear();
+                for input in inputs {
+                    record.push((input.index(), input.buffers()[0][0]));
+                }
+            }
+        }
+    }
+}
+
+type Graph = petgraph::Graph<NodeData<TestNode>, (), petgraph::Directed, u32>;
+type Processor = dasp_graph::Processor<Graph>;
+
+// Build a graph with three sources connected to a recording node, adding the edges in the given
+// order of source positions.
+fn record_inputs(edge_order: &[usize]) -> Vec<(usize, f32)> {
+    let mut g = Graph::new();
+    let mut p = Processor::with_capacity(4);
+    let sources: Vec<_> = (0..3)
+        .map(|i| g.add_node(NodeData::new1(TestNode::Source(i as f32))))
+        .collect();
+    let rec = g.add_node(NodeData::new1(TestNode::Record(Vec::with_capacity(3))));
+    for &i in edge_order {
+        g.add_edge(sources[i], rec, ());
+    }
+    p.process(&mut g, rec);
+    match g[rec].node {
+        TestNode::Record(ref record) => record.clone(),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn test_input_order_by_source_index() {
+    let expected = vec![(0, 0.0), (1, 1.0), (2, 2.0)];
+    assert_eq!(record_inputs(&[0, 1, 2]), expected);
+    // The order is stable regardless of the order in which edges are added.
+    assert_eq!(record_inputs(&[2, 0, 1]), expected);
+    assert_eq!(record_inputs(&[2, 1, 0]), expected);
+}
+
+#[test]
+fn test_input_order_across_processing() {
+    let mut g = Graph::new();
+    let mut p = Processor::with_capacity(4);
+    let main = g.add_node(NodeData::new1(TestNode::Source(0.5)));
+    let sidechain = g.add_node(NodeData::new1(TestNode::Source(0.25)));
+    let rec = g.add_node(NodeData::new1(TestNode::Record(Vec::with_capacity(2))));
+    g.add_edge(sidechain, rec, ());
+    g.add_edge(main, rec, ());
+    for _ in 0..3 {
+        p.process(&mut g, rec);
+        match g[rec].node {
+            TestNode::Record(ref record) => assert_eq!(record, &[(0, 0.5), (1, 0.25)]),
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+#![cfg(feature = "node-input-trim")]
+
+use dasp_graph::node::{InputTrim, Trim};
+use dasp_graph::{Buffer, Input, Node};
+
+#[test]
+fn test_input_trim_sum() {
+    let a = [
+        Buffer::from([0.5; Buffer::LEN]),
+        Buffer::from([0.25; Buffer::LEN]),
+    ];
+    let b = [Buffer::from([1.0; Buffer::LEN])];
+    let mut output = [Buffer::SILENT, Buffer::SILENT];
+    let inputs = [Input::new(&a, Trim(0.0)), Input::new(&b, Trim(-6.0))];
+    InputTrim::new().process(&inputs, &mut output);
+
+    let minus_6db = 10f32.powf(-6.0 / 20.0);
+    assert!((minus_6db - 0.501).abs() < 1e-3);
+    for &sample in output[0].iter() {
+        assert!((sample - (0.5 + minus_6db)).abs() < 1e-6);
+    }
+    // Only `a` has a second channel.
+    assert_eq!(output[1], a[1]);
+}
+
+#[test]
+fn test_input_trim_f32_variant() {
+    // `f32` variants are treated as trims in decibels.
+    let a = [Buffer::from([1.0; Buffer::LEN])];
+    let mut output = [Buffer::from([1.0; Buffer::LEN])];
+    let inputs = [Input::new(&a, -20.0f32)];
+    InputTrim::new().process(&inputs, &mut output);
+    assert!((output[0][0] - 0.1).abs() < 1e-6);
+
+    // Without inputs, the output is silent.
+    InputTrim::new().process(&[] as &[Input<f32>], &mut output);
+    assert_eq!(output[0], Buffer::SILENT);
+}
+
+#[test]
+fn test_trim_gain() {
+    assert_eq!(Trim::UNITY.gain(), 1.0);
+    assert_eq!(Trim::default(), Trim::UNITY);
+    assert!((Trim(20.0).gain() - 10.0).abs() < 1e-5);
+    assert_eq!(Trim(f32::NEG_INFINITY).gain(), 0.0);
+}
@@ -0,0 +1,67 @@
+#![cfg(feature = "node-looper")]
+
+use dasp_graph::node::{Looper, LooperState};
+use dasp_graph::{Buffer, Input, Node};
+
+// Process a single block through the looper with the given input buffer.
+fn process(looper: &mut Looper, input: &Buffer) -> Buffer {
+    let in_bufs = [input.clone()];
+    let inputs = [Input::new(&in_bufs, ())];
+    let mut output = [Buffer::SILENT];
+    looper.process(&inputs, &mut output);
+    output[0].clone()
+}
+
+#[test]
+fn test_looper_record_and_play() {
+    let phrase_len = 10;
+    let mut looper = Looper::new(1, 1024);
+    looper.set_loop_len(phrase_len);
+    looper.record();
+
+    // Record a short ramp phrase, followed by silence.
+    let mut phrase = Buffer::SILENT;
+    for (i, s) in phrase.iter_mut().take(phrase_len).enumerate() {
+        *s = (i + 1) as f32;
+    }
+    let out = process(&mut looper, &phrase);
+
+    // The input is passed through while recording, after which the loop begins playing.
+    assert_eq!(looper.state(), LooperState::Playing);
+    assert_eq!(looper.loop_len(), phrase_len);
+    assert_eq!(&out[..phrase_len], &phrase[..phrase_len]);
+    for (i, &s) in out[phrase_len..].iter().enumerate() {
+        assert_eq!(s, phrase[i % phrase_len]);
+    }
+
+    // The loop keeps repeating in sync over following blocks.
+    let offset = (Buffer::LEN - phrase_len) % phrase_len;
+    let out = process(&mut looper, &Buffer::SILENT);
+    for (i, &s) in out.iter().enumerate() {
+        assert_eq!(s, phrase[(offset + i) % phrase_len]);
+    }
+}
+
+#[test]
+fn test_looper_overdub_and_clear() {
+    let mut looper = Looper::new(1, 1024);
+    looper.record();
+    process(&mut looper, &Buffer::from([0.25; Buffer::LEN]));
+    looper.overdub();
+    assert_eq!(looper.loop_len(), Buffer::LEN);
+
+    // While overdubbing, the output is the dry input plus the existing loop.
+    let out = process(&mut looper, &Buffer::from([0.5; Buffer::LEN]));
+    assert_eq!(out, Buffer::from([0.75; Buffer::LEN]));
+
+    // The overdubbed input has been summed into the loop.
+    looper.play();
+    let out = process(&mut looper, &Buffer::SILENT);
+    assert_eq!(out, Buffer::from([0.75; Buffer::LEN]));
+
+    // Clearing erases the loop.
+    looper.clear();
+    assert_eq!(looper.state(), LooperState::Stopped);
+    let out = process(&mut looper, &Buffer::SILENT);
+    assert_eq!(out, Buffer::SILENT);
+}
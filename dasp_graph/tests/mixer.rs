@@ -0,0 +1,42 @@
+#![cfg(all(feature = "node-boxed", feature = "node-mixer"))]
+
+use dasp_graph::{node, Buffer, Input, Node, NodeData};
+
+type BoxedNode = dasp_graph::BoxedNode<f32>;
+
+// A source node that writes a constant value to each of its buffers.
+struct Constant(f32);
+
+impl Node<f32> for Constant {
+    fn process(&mut self, _inputs: &[Input<f32>], output: &mut [Buffer]) {
+        for buffer in output.iter_mut() {
+            buffer.iter_mut().for_each(|s| *s = self.0);
+        }
+    }
+}
+
+#[test]
+fn test_mixer_edge_gains() {
+    type Graph = petgraph::Graph<NodeData<BoxedNode>, f32, petgraph::Directed, u32>;
+    type Processor = dasp_graph::Processor<Graph>;
+
+    let mut g = Graph::new();
+    let mut p = Processor::with_capacity(3);
+
+    // A mono source and a stereo source mixed onto a stereo output.
+    let a = g.add_node(NodeData::boxed1(Constant(1.0)));
+    let b = g.add_node(NodeData::boxed2(Constant(-0.5)));
+    let mixer = g.add_node(NodeData::boxed2(node::Mixer::new()));
+    g.add_edge(a, mixer, 0.5);
+    let b_edge = g.add_edge(b, mixer, 0.25);
+
+    p.process(&mut g, mixer);
+    assert!(g[mixer].buffers[0].iter().all(|&s| s == 0.5 - 0.125));
+    assert!(g[mixer].buffers[1].iter().all(|&s| s == -0.125));
+
+    // Gains are read from the edge weights on each call and the sum is not limited.
+    g[b_edge] = -4.0;
+    p.process(&mut g, mixer);
+    assert!(g[mixer].buffers[0].iter().all(|&s| s == 2.5));
+    assert!(g[mixer].buffers[1].iter().all(|&s| s == 2.0));
+}
@@ -0,0 +1,57 @@
+#![cfg(feature = "node-mono")]
+
+mod common;
+
+use dasp_graph::node::{Mono, MonoAttenuation};
+
+// Process a 1kHz sine, with the right channel scaled by `right`, returning the peak of the mono
+// output once the correlation measurement has settled.
+fn render(mono: &mut Mono, right: f32) -> f32 {
+    let (_, output) = common::render(mono, 2, 200, 100, |block| {
+        let l = common::sine(block, 1_000.0, 0.5);
+        let mut r = l.clone();
+        r.iter_mut().for_each(|s| *s *= right);
+        vec![l, r]
+    });
+    assert_eq!(output[0], output[1]);
+    output[0].iter().fold(0.0f32, |peak, s| peak.max(s.abs()))
+}
+
+#[test]
+fn test_mono_in_phase() {
+    let mut mono = Mono::new(MonoAttenuation::Minus6Db);
+    mono.correlation_frames = 480.0;
+    assert!((render(&mut mono, 1.0) - 0.5).abs() < 1e-3);
+    assert!(mono.correlation() > 0.99);
+    assert!(!mono.is_anti_phase());
+
+    mono.attenuation = MonoAttenuation::Minus3Db;
+    assert!((render(&mut mono, 1.0) - 0.5 * 2f32.sqrt()).abs() < 1e-3);
+    mono.attenuation = MonoAttenuation::ZeroDb;
+    assert!((render(&mut mono, 1.0) - 1.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_mono_anti_phase() {
+    let mut mono = Mono::default();
+    mono.correlation_frames = 480.0;
+
+    // The channels cancel and the anti-phase content is detected.
+    assert!(render(&mut mono, -1.0) < 1e-6);
+    assert!(mono.correlation() < -0.99);
+    assert!(mono.is_anti_phase());
+
+    // In phase-safe mode, the level is restored.
+    mono.phase_safe = true;
+    assert!((render(&mut mono, -1.0) - 0.5).abs() < 1e-2);
+
+    // Uncorrelated content is summed as usual.
+    mono.reset();
+    assert_eq!(mono.correlation(), 0.0);
+    let mut quiet_right = Mono::default();
+    quiet_right.correlation_frames = 480.0;
+    quiet_right.phase_safe = true;
+    let peak = render(&mut quiet_right, 0.0);
+    assert!((peak - 0.25).abs() < 1e-3);
+    assert!(!quiet_right.is_anti_phase());
+}
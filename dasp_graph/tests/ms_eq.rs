@@ -0,0 +1,98 @@
+#![cfg(feature = "node-ms-eq")]
+
+mod common;
+
+use common::{magnitude, SAMPLE_RATE};
+use dasp_graph::node::{MidSide, MidSideEq, Shelf, ShelfKind};
+use dasp_graph::{Buffer, Input, Node};
+
+// Frequencies are chosen to fall exactly on the bins of the measured length to avoid leakage.
+const LOW_HZ: f64 = 187.5;
+const HIGH_HZ: f64 = 7_500.0;
+
+// Render a low and a high tone, where the high tone is either in phase (centred) or in
+// anti-phase (side) between the channels. Returns the input and output of both channels once the
+// filters have settled.
+fn render(eq: &mut MidSideEq, high_in_side: bool) -> (Vec<Vec<f32>>, Vec<Vec<f32>>) {
+    common::render(eq, 2, 96, 32, |block| {
+        let (low, high) = (
+            common::sine(block, LOW_HZ, 0.4),
+            common::sine(block, HIGH_HZ, 0.4),
+        );
+        let side = if high_in_side { -1.0 } else { 1.0 };
+        let (mut l, mut r) = (low.clone(), low);
+        l.iter_mut().zip(high.iter()).for_each(|(s, h)| *s += h);
+        r.iter_mut()
+            .zip(high.iter())
+            .for_each(|(s, h)| *s += side * h);
+        vec![l, r]
+    })
+}
+
+fn side_high_boost() -> MidSideEq {
+    let mut eq = MidSideEq::new(SAMPLE_RATE);
+    let shelf = Shelf {
+        frequency: 2_000.0,
+        gain_db: 6.0,
+    };
+    eq.set_shelf(MidSide::Side, ShelfKind::High, shelf);
+    eq
+}
+
+#[test]
+fn test_ms_eq_flat_is_transparent() {
+    let mut eq = MidSideEq::new(SAMPLE_RATE);
+    let (input, output) = render(&mut eq, true);
+    for ch in 0..2 {
+        for (a, b) in input[ch].iter().zip(&output[ch]) {
+            assert!((a - b).abs() < 1e-5);
+        }
+    }
+}
+
+#[test]
+fn test_ms_eq_side_boost_leaves_centre_unchanged() {
+    let mut eq = side_high_boost();
+    let (input, output) = render(&mut eq, false);
+    for ch in 0..2 {
+        for (a, b) in input[ch].iter().zip(&output[ch]) {
+            assert!((a - b).abs() < 1e-5);
+        }
+    }
+}
+
+#[test]
+fn test_ms_eq_side_boost_brightens_side() {
+    let mut eq = side_high_boost();
+    let (input, output) = render(&mut eq, true);
+    for ch in 0..2 {
+        // The centred low tone is unchanged.
+        let low_ratio = magnitude(&output[ch], LOW_HZ) / magnitude(&input[ch], LOW_HZ);
+        assert!((low_ratio - 1.0).abs() < 0.01, "low ratio {}", low_ratio);
+        // The side high tone is boosted by close to the shelf gain.
+        let high_db =
+            20.0 * (magnitude(&output[ch], HIGH_HZ) / magnitude(&input[ch], HIGH_HZ)).log10();
+        assert!(high_db > 5.0 && high_db < 6.5, "high gain {} dB", high_db);
+    }
+    assert_eq!(
+        eq.shelf(MidSide::Side, ShelfKind::High),
+        Shelf {
+            frequency: 2_000.0,
+            gain_db: 6.0,
+        }
+    );
+}
+
+#[test]
+fn test_ms_eq_mono_passes_through() {
+    let mut eq = side_high_boost();
+    let mut in_buf = Buffer::SILENT;
+    in_buf
+        .iter_mut()
+        .enumerate()
+        .for_each(|(i, s)| *s = i as f32);
+    let mut out_bufs = [Buffer::SILENT, Buffer::SILENT];
+    eq.process(&[Input::new(&[in_buf.clone()], ())], &mut out_bufs);
+    assert_eq!(out_bufs[0], in_buf);
+    assert_eq!(out_bufs[1], Buffer::SILENT);
+}
@@ -0,0 +1,40 @@
+//! A minimal graph exercising the items available without the `std` feature.
+//!
+//! Run with `--no-default-features --features "node-boxed node-pass node-sum"` to check that
+//! `dasp_graph` builds and processes within a `no_std` context.
+
+#![cfg(all(feature = "node-boxed", feature = "node-pass", feature = "node-sum"))]
+
+use dasp_graph::{node, Buffer, Input, Node, NodeData};
+
+type BoxedNode = dasp_graph::BoxedNode<()>;
+
+// A source node that writes a constant value to each of its buffers.
+struct Constant(f32);
+
+impl Node for Constant {
+    fn process(&mut self, _inputs: &[Input], output: &mut [Buffer]) {
+        for buffer in output.iter_mut() {
+            buffer.iter_mut().for_each(|s| *s = self.0);
+        }
+    }
+}
+
+#[test]
+fn test_source_pass_sum() {
+    type Graph = petgraph::Graph<NodeData<BoxedNode>, (), petgraph::Directed, u32>;
+    type Processor = dasp_graph::Processor<Graph>;
+
+    let mut g = Graph::new();
+    let mut p = Processor::with_capacity(4);
+    let a = g.add_node(NodeData::boxed1(Constant(0.25)));
+    let b = g.add_node(NodeData::boxed1(Constant(0.5)));
+    let pass = g.add_node(NodeData::boxed1(node::Pass));
+    let sum = g.add_node(NodeData::boxed1(node::Sum));
+    g.add_edge(a, pass, ());
+    g.add_edge(pass, sum, ());
+    g.add_edge(b, sum, ());
+
+    p.process(&mut g, sum);
+    assert_eq!(g[sum].buffers[0], Buffer::from([0.75; Buffer::LEN]));
+}
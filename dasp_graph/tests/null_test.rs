@@ -0,0 +1,100 @@
+#![cfg(feature = "node-null-test")]
+
+use dasp_graph::node::NullTest;
+use dasp_graph::{Buffer, Input, Node};
+
+// A block of a sine of the given frequency in cycles per block, beginning at the given frame.
+fn sine(start: usize, cycles: f32, amp: f32) -> Buffer {
+    let mut buf = Buffer::SILENT;
+    for (i, s) in buf.iter_mut().enumerate() {
+        let phase = (start + i) as f32 / Buffer::LEN as f32;
+        *s = amp * (2.0 * std::f32::consts::PI * cycles * phase).sin();
+    }
+    buf
+}
+
+fn process(null: &mut NullTest, reference: &[Buffer], processed: &[Buffer]) -> Vec<Buffer> {
+    let mut output = vec![Buffer::SILENT; 2];
+    let inputs = [Input::new(reference, ()), Input::new(processed, ())];
+    null.process(&inputs, &mut output);
+    output
+}
+
+#[test]
+fn test_null_test_identical() {
+    let mut null = NullTest::new(2, 0);
+    let handle = null.handle();
+    let signal = [sine(0, 2.0, 0.5), sine(0, 3.0, 0.25)];
+    let output = process(&mut null, &signal, &signal);
+    assert_eq!(output, vec![Buffer::SILENT; 2]);
+    assert_eq!(null.gain(), 1.0);
+    assert_eq!(null.residual_rms(), 0.0);
+    assert_eq!(handle.residual_rms(), 0.0);
+}
+
+#[test]
+fn test_null_test_difference() {
+    let mut null = NullTest::new(1, 0);
+    let reference = [sine(0, 2.0, 0.5)];
+
+    // An added tone remains in the residual.
+    let added = sine(0, 5.0, 0.1);
+    let mut altered = reference.clone();
+    altered[0]
+        .iter_mut()
+        .zip(added.iter())
+        .for_each(|(s, a)| *s += a);
+    let output = process(&mut null, &reference, &altered);
+    let rms = null.residual_rms();
+    assert!((rms - 0.1 / 2f32.sqrt()).abs() < 0.01, "{}", rms);
+    assert!(output[0].iter().any(|&s| s.abs() > 0.05));
+    assert!(null.residual_db() > -25.0);
+}
+
+#[test]
+fn test_null_test_gain_match() {
+    let mut null = NullTest::new(1, 0);
+    let reference = [sine(0, 2.0, 0.5)];
+
+    // A change in gain or polarity is matched.
+    for &gain in &[0.5, -1.0] {
+        let mut scaled = reference.clone();
+        scaled[0].iter_mut().for_each(|s| *s *= gain);
+        process(&mut null, &reference, &scaled);
+        assert!((null.gain() - 1.0 / gain).abs() < 1e-4);
+        assert!(null.residual_rms() < 1e-6);
+    }
+
+    // Unless gain matching is disabled.
+    null.gain_match = false;
+    let mut scaled = reference.clone();
+    scaled[0].iter_mut().for_each(|s| *s *= 0.5);
+    process(&mut null, &reference, &scaled);
+    assert_eq!(null.gain(), 1.0);
+    assert!(null.residual_rms() > 0.1);
+}
+
+#[test]
+fn test_null_test_latency() {
+    // The processed signal lags the reference by 10 frames.
+    let latency = 10;
+    let mut null = NullTest::new(1, latency);
+    assert_eq!(null.latency_frames(), latency);
+    for block in 0..4 {
+        let start = block * Buffer::LEN;
+        let reference = [sine(start, 1.0, 0.5)];
+        let processed = [if block == 0 {
+            let mut buf = sine(0, 1.0, 0.5);
+            buf.rotate_right(latency);
+            buf[..latency].iter_mut().for_each(|s| *s = 0.0);
+            buf
+        } else {
+            sine(start - latency, 1.0, 0.5)
+        }];
+        process(&mut null, &reference, &processed);
+        assert!(null.residual_rms() < 1e-5, "{}", null.residual_rms());
+    }
+
+    null.reset();
+    assert_eq!(null.residual_rms(), 0.0);
+}
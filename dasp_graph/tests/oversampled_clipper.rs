@@ -0,0 +1,108 @@
+#![cfg(feature = "node-oversampled-clipper")]
+
+mod common;
+
+use dasp_graph::node::{ClipShape, Oversample, OversampledClipper};
+use dasp_graph::{Buffer, Input, Node};
+use std::f64::consts::PI;
+
+const SAMPLE_RATE: f64 = 44_100.0;
+// A whole number of cycles of `HZ` fit within `ANALYSIS_LEN` frames, as do the aliases.
+const HZ: f64 = 5_010.0;
+const ANALYSIS_LEN: usize = 4_410;
+
+// Clip a loud sine, returning a window of the output once the filters have settled.
+fn render(clipper: &mut OversampledClipper) -> Vec<f64> {
+    let blocks = (2 * ANALYSIS_LEN).div_ceil(Buffer::LEN);
+    let (_, output) = common::render(clipper, 1, blocks, 0, |block| {
+        let mut buf = Buffer::SILENT;
+        for (ix, s) in buf.iter_mut().enumerate() {
+            let t = (block * Buffer::LEN + ix) as f64 / SAMPLE_RATE;
+            *s = (2.0 * PI * HZ * t).sin() as f32;
+        }
+        vec![buf]
+    });
+    let out = &output[0][output[0].len() - ANALYSIS_LEN..];
+    out.iter().map(|&s| s as f64).collect()
+}
+
+// The power of the given DFT bin.
+fn bin_power(signal: &[f64], bin: usize) -> f64 {
+    let (mut re, mut im) = (0.0, 0.0);
+    for (i, &s) in signal.iter().enumerate() {
+        let phase = 2.0 * PI * (bin * i) as f64 / signal.len() as f64;
+        re += s * phase.cos();
+        im -= s * phase.sin();
+    }
+    2.0 * (re * re + im * im) / (signal.len() * signal.len()) as f64
+}
+
+// The fraction of the power of the signal that does not lie on a harmonic of `HZ`.
+fn alias_ratio(signal: &[f64]) -> f64 {
+    let total = signal.iter().map(|s| s * s).sum::<f64>() / signal.len() as f64;
+    let bin_hz = SAMPLE_RATE / ANALYSIS_LEN as f64;
+    let harmonics: f64 = (1..)
+        .map(|k| k as f64 * HZ)
+        .take_while(|&hz| hz < SAMPLE_RATE / 2.0)
+        .map(|hz| bin_power(signal, (hz / bin_hz).round() as usize))
+        .sum();
+    (total - harmonics).max(0.0) / total
+}
+
+#[test]
+fn test_oversampling_reduces_aliasing() {
+    let mut naive = OversampledClipper::new(1, Oversample::X1);
+    naive.threshold = 0.3;
+    let naive_ratio = alias_ratio(&render(&mut naive));
+
+    let mut x2 = OversampledClipper::new(1, Oversample::X2);
+    x2.threshold = 0.3;
+    let x2_ratio = alias_ratio(&render(&mut x2));
+
+    let mut x4 = OversampledClipper::new(1, Oversample::X4);
+    x4.threshold = 0.3;
+    let x4_output = render(&mut x4);
+    let x4_ratio = alias_ratio(&x4_output);
+
+    assert!(naive_ratio > 0.001, "{}", naive_ratio);
+    assert!(
+        x2_ratio < naive_ratio / 20.0,
+        "{} {}",
+        x2_ratio,
+        naive_ratio
+    );
+    assert!(
+        x4_ratio < naive_ratio / 50.0,
+        "{} {}",
+        x4_ratio,
+        naive_ratio
+    );
+
+    // The clipped fundamental is preserved.
+    let peak = x4_output.iter().fold(0.0f64, |max, s| max.max(s.abs()));
+    assert!(peak > 0.3 && peak < 0.45, "{}", peak);
+}
+
+#[test]
+fn test_clip_shapes() {
+    let mut clipper = OversampledClipper::new(2, Oversample::X2);
+    clipper.threshold = 0.5;
+    assert_eq!(clipper.clip(0.25), 0.25);
+    assert_eq!(clipper.clip(-2.0), -0.5);
+    clipper.shape = ClipShape::Soft;
+    assert!(clipper.clip(0.1) < 0.1 && clipper.clip(0.1) > 0.09);
+    assert!(clipper.clip(100.0) <= 0.5);
+
+    // Quiet material passes through the oversampling filters unchanged, besides latency.
+    clipper.set_oversample(Oversample::X4);
+    assert_eq!(clipper.oversample().factor(), 4);
+    let mut output = vec![Buffer::SILENT; 3];
+    let input = Buffer::from([0.01; Buffer::LEN]);
+    for _ in 0..16 {
+        let bufs = [input.clone(), input.clone()];
+        clipper.process(&[Input::new(&bufs, ())], &mut output);
+    }
+    assert!(output[0].iter().all(|&s| (s - 0.01).abs() < 1e-4));
+    assert_eq!(output[0], output[1]);
+    assert_eq!(output[2], Buffer::SILENT);
+}
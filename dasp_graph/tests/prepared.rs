@@ -0,0 +1,123 @@
+#![cfg(all(feature = "node-boxed", feature = "node-sum"))]
+
+use dasp_graph::{node, Buffer, Input, Node, NodeData};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+type BoxedNode = dasp_graph::BoxedNode<()>;
+type Graph = petgraph::Graph<NodeData<BoxedNode>, (), petgraph::Directed, u32>;
+type Processor = dasp_graph::Processor<Graph>;
+
+// Counts the allocations made by the current thread.
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|n| n.set(n.get() + 1));
+        System.alloc(layout)
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(|n| n.get())
+}
+
+// A source node that writes a ramp, continuing across blocks.
+struct Ramp(f32);
+
+impl Node for Ramp {
+    fn process(&mut self, _inputs: &[Input], output: &mut [Buffer]) {
+        for s in output[0].iter_mut() {
+            *s = self.0;
+            self.0 += 1.0;
+        }
+    }
+}
+
+// Two ramps summed onto a single output node.
+fn graph() -> (Graph, petgraph::graph::NodeIndex) {
+    let mut g = Graph::new();
+    let a = g.add_node(NodeData::boxed1(Ramp(0.0)));
+    let b = g.add_node(NodeData::boxed1(Ramp(1_000.0)));
+    let sum = g.add_node(NodeData::boxed1(node::Sum));
+    let out = g.add_node(NodeData::boxed1(node::Pass));
+    g.add_edge(a, sum, ());
+    g.add_edge(b, sum, ());
+    g.add_edge(sum, out, ());
+    (g, out)
+}
+
+#[test]
+fn test_process_prepared() {
+    let (mut g, out) = graph();
+    let (mut expected_g, expected_out) = graph();
+    let mut p = Processor::with_capacity(8);
+    let mut expected_p = Processor::with_capacity(8);
+
+    assert!(!p.is_prepared());
+    p.prepare(&g, out);
+    assert!(p.is_prepared());
+
+    for _ in 0..16 {
+        // Processing the prepared order performs no heap allocation.
+        let before = allocations();
+        p.process_prepared(&mut g);
+        assert_eq!(allocations(), before);
+
+        // And produces the same output as `process`.
+        expected_p.process(&mut expected_g, expected_out);
+        assert_eq!(g[out].buffers, expected_g[expected_out].buffers);
+    }
+
+    // After a change in topology, the order must be prepared again.
+    let c = g.add_node(NodeData::boxed1(Ramp(0.0)));
+    g.add_edge(c, out, ());
+    p.invalidate();
+    assert!(!p.is_prepared());
+    p.prepare(&g, out);
+    p.process_prepared(&mut g);
+}
+
+#[test]
+fn test_process_prepared_self_feedback() {
+    // An accumulator reading its own output from the previous block.
+    let mut g = Graph::new();
+    let ramp = g.add_node(NodeData::boxed1(Ramp(0.0)));
+    let acc = g.add_node(NodeData::boxed1(node::Sum).with_feedback());
+    let out = g.add_node(NodeData::boxed1(node::Pass));
+    g.add_edge(ramp, acc, ());
+    g.add_edge(acc, acc, ());
+    g.add_edge(acc, out, ());
+    let mut p = Processor::with_capacity(8);
+    p.prepare(&g, out);
+
+    let mut expected = vec![0.0; Buffer::LEN];
+    for block in 0..4 {
+        let before = allocations();
+        p.process_prepared(&mut g);
+        assert_eq!(allocations(), before);
+
+        for (ix, s) in expected.iter_mut().enumerate() {
+            *s += (block * Buffer::LEN + ix) as f32;
+        }
+        assert_eq!(&g[acc].buffers[0][..], &expected[..]);
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_process_unprepared() {
+    let (mut g, _) = graph();
+    let mut p = Processor::with_capacity(8);
+    p.process_prepared(&mut g);
+}
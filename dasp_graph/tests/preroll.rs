@@ -0,0 +1,45 @@
+#![cfg(all(feature = "node-boxed", feature = "node-delay"))]
+
+use dasp_graph::{node, Buffer, Input, Node, NodeData};
+use dasp_ring_buffer as ring_buffer;
+
+type BoxedNode = dasp_graph::BoxedNode<()>;
+
+// A source node that writes a single impulse at the very first frame it renders.
+struct Impulse(bool);
+
+impl Node for Impulse {
+    fn process(&mut self, _inputs: &[Input], output: &mut [Buffer]) {
+        output[0].silence();
+        if !self.0 {
+            output[0][0] = 1.0;
+            self.0 = true;
+        }
+    }
+}
+
+#[test]
+fn test_preroll() {
+    type Graph = petgraph::Graph<NodeData<BoxedNode>, (), petgraph::Directed, u32>;
+    type Processor = dasp_graph::Processor<Graph>;
+
+    let mut g = Graph::new();
+    let mut p = Processor::with_capacity(2);
+
+    // Delay the impulse by one and a half blocks.
+    let delay_frames = Buffer::LEN + Buffer::LEN / 2;
+    let ring_buf = ring_buffer::Fixed::from(vec![0.0; delay_frames]);
+    let src = g.add_node(NodeData::boxed1(Impulse(false)));
+    let delay = g.add_node(NodeData::boxed1(node::Delay(vec![ring_buf])));
+    g.add_edge(src, delay, ());
+
+    // The impulse enters the delay during the preroll, and the output is discarded.
+    p.preroll(&mut g, delay, 1);
+    assert_eq!(g[delay].buffers[0], Buffer::SILENT);
+
+    // The first real block already contains the tail.
+    p.process(&mut g, delay);
+    let out = &g[delay].buffers[0];
+    assert_eq!(out[Buffer::LEN / 2], 1.0);
+    assert_eq!(out.iter().filter(|&&s| s != 0.0).count(), 1);
+}
@@ -0,0 +1,43 @@
+#![cfg(feature = "node-boxed")]
+
+use dasp_graph::{Buffer, Input, Node, NodeData, ProcessorAdapter};
+
+type BoxedNode = dasp_graph::BoxedNode<()>;
+
+// A stereo source yielding a continuously increasing ramp, offset per channel.
+struct Ramp(f32);
+
+impl Node for Ramp {
+    fn process(&mut self, _inputs: &[Input], output: &mut [Buffer]) {
+        for ix in 0..Buffer::LEN {
+            for (ch, out_buf) in output.iter_mut().enumerate() {
+                out_buf[ix] = self.0 + ch as f32 * 0.5;
+            }
+            self.0 += 1.0;
+        }
+    }
+}
+
+#[test]
+fn test_processor_adapter_continuity() {
+    type Graph = petgraph::Graph<NodeData<BoxedNode>, (), petgraph::Directed, u32>;
+    type Processor = dasp_graph::Processor<Graph>;
+
+    let mut g = Graph::new();
+    let ramp = g.add_node(NodeData::boxed2(Ramp(0.0)));
+    let mut adapter = ProcessorAdapter::new(Processor::with_capacity(1), 2);
+
+    // Request 100 frames then 28 frames, spanning exactly two blocks.
+    let mut a = vec![0.0; 100 * 2];
+    let mut b = vec![0.0; 28 * 2];
+    adapter.process_interleaved(&mut g, ramp, &mut a);
+    assert_eq!(adapter.buffered_frames(), 2 * Buffer::LEN - 100);
+    adapter.process_interleaved(&mut g, ramp, &mut b);
+    assert_eq!(adapter.buffered_frames(), 0);
+
+    let frames: Vec<_> = a.chunks(2).chain(b.chunks(2)).collect();
+    assert_eq!(frames.len(), 128);
+    for (i, frame) in frames.iter().enumerate() {
+        assert_eq!(frame, &[i as f32, i as f32 + 0.5]);
+    }
+}
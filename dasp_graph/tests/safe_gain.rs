@@ -0,0 +1,66 @@
+#![cfg(feature = "node-safe-gain")]
+
+use dasp_graph::node::SafeGain;
+use dasp_graph::{Buffer, Input, Node};
+
+// Process the given number of blocks of a constant input, returning the first channel.
+fn process(gain: &mut SafeGain, level: f32, blocks: usize) -> Vec<f32> {
+    let bufs = vec![Buffer::from([level; Buffer::LEN]); 2];
+    let mut output = vec![Buffer::SILENT; 2];
+    let mut out = vec![];
+    for _ in 0..blocks {
+        gain.process(&[Input::new(&bufs, ())], &mut output);
+        assert_eq!(output[0], output[1]);
+        out.extend(output[0].iter().cloned());
+    }
+    out
+}
+
+#[test]
+fn test_safe_gain_ramp() {
+    let mut gain = SafeGain::new(0.0);
+    gain.smoothing_frames = 128.0;
+    gain.soft_limit = false;
+
+    // Snapping the gain ramps linearly over the smoothing time.
+    gain.set_gain(1.0);
+    let out = process(&mut gain, 0.5, 3);
+    for (i, &s) in out.iter().enumerate().take(128) {
+        assert!(
+            (s - 0.5 * (i + 1) as f32 / 128.0).abs() < 1e-5,
+            "{}: {}",
+            i,
+            s
+        );
+    }
+    assert!(out[128..].iter().all(|&s| s == 0.5));
+    assert_eq!(gain.current_gain(), 1.0);
+
+    // Ramps down likewise.
+    gain.set_gain(0.5);
+    let out = process(&mut gain, 1.0, 2);
+    assert!((out[63] - 0.75).abs() < 1e-5);
+    assert_eq!(out[127], 0.5);
+}
+
+#[test]
+fn test_safe_gain_soft_limit() {
+    let mut gain = SafeGain::new(1.0);
+    gain.smoothing_frames = 64.0;
+
+    // Below the knee, samples are unaffected.
+    assert_eq!(process(&mut gain, 0.5, 1)[0], 0.5);
+
+    // Boosting a loud signal never exceeds the limit, even throughout the ramp.
+    gain.set_gain(4.0);
+    let out = process(&mut gain, 0.8, 4);
+    let mut prev = 0.0;
+    for &s in &out {
+        assert!(s <= 1.0);
+        assert!(s >= prev);
+        prev = s;
+    }
+    assert!(out[out.len() - 1] > 0.99);
+    assert_eq!(SafeGain::limit(-10.0), -1.0);
+    assert!(SafeGain::limit(1.0) < 1.0 && SafeGain::limit(1.0) > SafeGain::KNEE);
+}
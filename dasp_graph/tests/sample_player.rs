@@ -0,0 +1,46 @@
+#![cfg(feature = "node-sample-player")]
+
+use dasp_graph::{node, Buffer, Input, Node};
+
+// Produce a trigger buffer with a single-frame pulse at each of the given frames.
+fn triggers(frames: &[usize]) -> Vec<Buffer> {
+    let mut buf = Buffer::SILENT;
+    for &ix in frames {
+        buf[ix] = 1.0;
+    }
+    vec![buf]
+}
+
+#[test]
+fn test_overlapping_voices_sum() {
+    let mut player = node::SamplePlayer::new(vec![vec![1.0; 4]], 4);
+    let trigger = triggers(&[0, 2]);
+    let mut output = vec![Buffer::SILENT; 2];
+    player.process(&[Input::new(&trigger, ())], &mut output);
+    // The mono sample is written to both channels, with the second hit overlapping the first.
+    for out_buf in &output {
+        assert_eq!(&out_buf[..7], &[1.0, 1.0, 2.0, 2.0, 1.0, 1.0, 0.0]);
+    }
+    assert_eq!(player.active_voices(), 0);
+}
+
+#[test]
+fn test_voice_limit_steals_oldest() {
+    let mut player = node::SamplePlayer::new(vec![vec![1.0; 4]], 1);
+    let trigger = triggers(&[0, 2]);
+    let mut output = vec![Buffer::SILENT];
+    player.process(&[Input::new(&trigger, ())], &mut output);
+    assert_eq!(&output[0][..7], &[1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 0.0]);
+}
+
+#[test]
+fn test_pitch_and_manual_trigger() {
+    let mut player = node::SamplePlayer::new(vec![vec![0.0, 1.0]], 2);
+    player.pitch = 0.5;
+    player.gain = 0.5;
+    player.trigger();
+    let mut output = vec![Buffer::SILENT];
+    let no_inputs: &[Input] = &[];
+    player.process(no_inputs, &mut output);
+    assert_eq!(&output[0][..5], &[0.0, 0.25, 0.5, 0.25, 0.0]);
+}
@@ -0,0 +1,55 @@
+#![cfg(feature = "node-signal")]
+
+use dasp_graph::node::SignalNode;
+use dasp_graph::{Buffer, Node};
+use dasp_signal::{self as signal, Signal};
+
+#[test]
+fn test_signal_node_sine() {
+    let sine = || {
+        let mut phase = 0.0f64;
+        signal::gen_mut(move || {
+            let frame = [(phase * 2.0 * std::f64::consts::PI).sin() as f32];
+            phase = (phase + 440.0 / 44_100.0) % 1.0;
+            frame
+        })
+    };
+    let mut node = SignalNode::new(sine());
+    let mut output = [Buffer::SILENT, Buffer::SILENT];
+    node.process(&[], &mut output);
+    let expected: Vec<f32> = sine().take(Buffer::LEN).map(|[s]| s).collect();
+    assert_eq!(&output[0][..], &expected[..]);
+    assert_eq!(output[1], Buffer::SILENT);
+    assert!(!node.is_exhausted());
+}
+
+#[test]
+fn test_signal_node_stereo() {
+    let frames = (0..Buffer::LEN).map(|i| [i as f32, -(i as f32)]);
+    let mut node = SignalNode::new(signal::from_iter(frames));
+    let mut output = [Buffer::SILENT, Buffer::SILENT];
+    node.process(&[], &mut output);
+    for (i, (&l, &r)) in output[0].iter().zip(output[1].iter()).enumerate() {
+        assert_eq!(l, i as f32);
+        assert_eq!(r, -(i as f32));
+    }
+}
+
+#[test]
+fn test_signal_node_exhausted() {
+    let frames = vec![[1.0f32]; Buffer::LEN + 10];
+    let mut node = SignalNode::new(signal::from_iter(frames));
+    let mut output = [Buffer::SILENT];
+    node.process(&[], &mut output);
+    assert_eq!(output[0], Buffer::from([1.0; Buffer::LEN]));
+    assert!(!node.is_exhausted());
+
+    // The remainder of the block following the last frame is silent.
+    node.process(&[], &mut output);
+    assert!(output[0][..10].iter().all(|&s| s == 1.0));
+    assert!(output[0][10..].iter().all(|&s| s == 0.0));
+    assert!(node.is_exhausted());
+
+    node.process(&[], &mut output);
+    assert_eq!(output[0], Buffer::SILENT);
+}
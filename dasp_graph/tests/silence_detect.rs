@@ -0,0 +1,89 @@
+#![cfg(feature = "node-silence-detect")]
+
+use dasp_graph::node::{SilenceDetect, SilenceDetectEvent};
+use dasp_graph::{Buffer, Input, Node};
+
+#[test]
+fn test_silence_detect() {
+    // A low frequency tone with 100 frames of silence inserted at frame 200.
+    let (silence_start, silence_len) = (200, 100);
+    let tone = |frame: usize| {
+        if frame >= silence_start && frame < silence_start + silence_len {
+            0.0
+        } else {
+            0.5 * (frame as f32 * 0.01 * std::f32::consts::PI * 2.0 + 1.0).sin()
+        }
+    };
+
+    let mut detect = SilenceDetect::new(2, 64);
+    let mut output = vec![Buffer::SILENT; 2];
+    for block in 0..8 {
+        let mut in_bufs = vec![Buffer::SILENT; 2];
+        for in_buf in in_bufs.iter_mut() {
+            for (ix, s) in in_buf.iter_mut().enumerate() {
+                *s = tone(block * Buffer::LEN + ix);
+            }
+        }
+        let inputs = [Input::new(&in_bufs, ())];
+        detect.process(&inputs, &mut output);
+
+        // Audio is passed through untouched.
+        assert_eq!(output, in_bufs);
+    }
+
+    let silences: Vec<_> = detect
+        .events()
+        .iter()
+        .filter(|e| matches!(e, SilenceDetectEvent::Silence { .. }))
+        .collect();
+    assert_eq!(
+        silences,
+        vec![&SilenceDetectEvent::Silence {
+            start: silence_start as u64,
+            len: silence_len as u64,
+        }]
+    );
+}
+
+#[test]
+fn test_dropout_detect() {
+    let mut detect = SilenceDetect::new(1, 64);
+    detect.slope_threshold = 0.25;
+
+    // A sudden jump on the second block.
+    let in_bufs = vec![Buffer::from([0.1; Buffer::LEN])];
+    let inputs = [Input::new(&in_bufs, ())];
+    let mut output = vec![Buffer::SILENT];
+    detect.process(&inputs, &mut output);
+    assert!(detect.events().is_empty());
+
+    let mut in_bufs = vec![Buffer::from([0.1; Buffer::LEN])];
+    in_bufs[0][10] = 0.9;
+    let inputs = [Input::new(&in_bufs, ())];
+    detect.process(&inputs, &mut output);
+    let position = Buffer::LEN as u64 + 10;
+    assert_eq!(
+        detect.events(),
+        &[
+            SilenceDetectEvent::Dropout {
+                position,
+                channel: 0
+            },
+            SilenceDetectEvent::Dropout {
+                position: position + 1,
+                channel: 0
+            },
+        ]
+    );
+
+    // Trailing silence is only reported once flushed.
+    detect.process(&[Input::new(&[Buffer::SILENT], ())], &mut output);
+    detect.flush();
+    assert_eq!(
+        detect.events().last(),
+        Some(&SilenceDetectEvent::Silence {
+            start: 2 * Buffer::LEN as u64,
+            len: Buffer::LEN as u64,
+        })
+    );
+}
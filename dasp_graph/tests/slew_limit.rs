@@ -0,0 +1,57 @@
+#![cfg(feature = "node-slew-limit")]
+
+use dasp_graph::node::SlewLimit;
+use dasp_graph::{Buffer, Input, Node};
+
+const SAMPLE_RATE: f64 = 1_000.0;
+
+#[test]
+fn test_slew_limit_step() {
+    // Rise by at most `0.01` and fall by at most `0.02` per sample.
+    let mut slew = SlewLimit::new(1, SAMPLE_RATE, 10.0, 20.0);
+    let mut output = [Buffer::SILENT];
+
+    // The output ramps toward the step rather than jumping, continuing across blocks.
+    let step = [Buffer::from([1.0; Buffer::LEN])];
+    let mut rendered = vec![];
+    for _ in 0..2 {
+        slew.process(&[Input::new(&step, ())], &mut output);
+        rendered.extend(output[0].iter().cloned());
+    }
+    for (i, &sample) in rendered.iter().enumerate() {
+        let expected = ((i + 1) as f32 * 0.01).min(1.0);
+        assert!((sample - expected).abs() < 1e-4);
+    }
+
+    // Once the input is reached, the output follows it.
+    slew.process(&[Input::new(&step, ())], &mut output);
+    assert_eq!(output[0], step[0]);
+    assert_eq!(slew.values(), &[1.0]);
+
+    // Falling uses the fall rate.
+    let zero = [Buffer::SILENT];
+    slew.process(&[Input::new(&zero, ())], &mut output);
+    for (i, &sample) in output[0].iter().enumerate() {
+        let expected = (1.0 - (i + 1) as f32 * 0.02).max(0.0);
+        assert!((sample - expected).abs() < 1e-4);
+    }
+}
+
+#[test]
+fn test_slew_limit_channels() {
+    let mut slew = SlewLimit::new(1, SAMPLE_RATE, 10.0, 10.0);
+    let in_bufs = [
+        Buffer::from([1.0; Buffer::LEN]),
+        Buffer::from([1.0; Buffer::LEN]),
+    ];
+    let mut output = [Buffer::SILENT; 3];
+    slew.process(&[Input::new(&in_bufs, ())], &mut output);
+    assert!((output[0][0] - 0.01).abs() < 1e-6);
+
+    // Channels beyond those allocated are passed through.
+    assert_eq!(output[1], in_bufs[1]);
+    assert_eq!(output[2], Buffer::SILENT);
+
+    slew.reset();
+    assert_eq!(slew.values(), &[0.0]);
+}
@@ -0,0 +1,163 @@
+#![cfg(all(
+    feature = "node-biquad",
+    feature = "node-boxed",
+    feature = "node-delay",
+    feature = "node-sum"
+))]
+
+use dasp_graph::{node, Buffer, Input, Node, NodeData};
+use dasp_ring_buffer as ring_buffer;
+use std::any::Any;
+
+type BoxedNode = dasp_graph::BoxedNode<()>;
+type Graph = petgraph::Graph<NodeData<BoxedNode>, (), petgraph::Directed, u32>;
+type Processor = dasp_graph::Processor<Graph>;
+
+// A source that writes a descending sawtooth burst for its first two blocks, then silence.
+#[derive(Clone)]
+struct Burst {
+    frame: usize,
+}
+
+impl Node for Burst {
+    fn process(&mut self, _inputs: &[Input], output: &mut [Buffer]) {
+        for s in output[0].iter_mut() {
+            *s = if self.frame < Buffer::LEN * 2 {
+                1.0 - (self.frame % 16) as f32 / 8.0
+            } else {
+                0.0
+            };
+            self.frame += 1;
+        }
+    }
+
+    fn save_state(&self) -> Option<Box<dyn Any + Send>> {
+        Some(Box::new(self.clone()))
+    }
+
+    fn load_state(&mut self, state: &dyn Any) {
+        if let Some(state) = state.downcast_ref::<Self>() {
+            self.frame = state.frame;
+        }
+    }
+}
+
+// A source within a feedback loop through a delay, followed by a filter.
+fn graph() -> (Graph, petgraph::graph::NodeIndex) {
+    let mut g = Graph::new();
+    let src = g.add_node(NodeData::boxed1(Burst { frame: 0 }));
+    let sum = g.add_node(NodeData::boxed1(node::Sum));
+    let ring_buf = ring_buffer::Fixed::from(vec![0.0; 100]);
+    let delay = g.add_node(NodeData::boxed1(node::Delay(vec![ring_buf])).with_feedback());
+    let filter = g.add_node(NodeData::boxed1(node::Biquad::new(
+        0.2, 0.4, 0.2, -0.5, 0.3,
+    )));
+    g.add_edge(src, sum, ());
+    g.add_edge(sum, delay, ());
+    g.add_edge(delay, sum, ());
+    g.add_edge(sum, filter, ());
+    (g, filter)
+}
+
+fn render(p: &mut Processor, g: &mut Graph, out: petgraph::graph::NodeIndex) -> Vec<f32> {
+    let mut output = vec![];
+    for _ in 0..8 {
+        p.process(g, out);
+        output.extend(g[out].buffers[0].iter().cloned());
+    }
+    output
+}
+
+#[test]
+fn test_snapshot_restore() {
+    let (mut g, out) = graph();
+    let mut p = Processor::with_capacity(4);
+    for _ in 0..3 {
+        p.process(&mut g, out);
+    }
+
+    let snapshot = p.snapshot(&g);
+    let expected = render(&mut p, &mut g, out);
+    assert!(expected.iter().any(|&s| s.abs() > 0.1));
+
+    // Restoring reproduces identical output, any number of times.
+    for _ in 0..2 {
+        p.restore(&mut g, &snapshot);
+        assert_eq!(render(&mut p, &mut g, out), expected);
+    }
+}
+
+#[test]
+fn test_snapshot_restore_source() {
+    // The state of the source alone determines whether the burst is replayed.
+    let (mut g, out) = graph();
+    let mut p = Processor::with_capacity(4);
+    let fresh = render(&mut p, &mut g, out);
+
+    let (mut g2, out2) = graph();
+    let snapshot = p.snapshot(&g2);
+    render(&mut p, &mut g2, out2);
+    p.restore(&mut g2, &snapshot);
+    assert_eq!(render(&mut p, &mut g2, out2), fresh);
+}
+
+#[test]
+fn test_snapshot_stateless() {
+    // Nodes without state are skipped, while their buffers are still restored.
+    let mut g = Graph::new();
+    let pass = g.add_node(NodeData::boxed1(node::Sum));
+    assert!(g[pass].node.save_state().is_none());
+    let p = Processor::with_capacity(1);
+    let snapshot = p.snapshot(&g);
+    g[pass].buffers[0][0] = 1.0;
+
+    let mut p = p;
+    p.restore(&mut g, &snapshot);
+    assert_eq!(g[pass].buffers[0], Buffer::SILENT);
+}
+
+#[test]
+#[cfg(feature = "node-gate")]
+fn test_snapshot_restore_gate() {
+    // A slow attack leaves the gate partially open at the time of the snapshot.
+    let mut g = Graph::new();
+    let src = g.add_node(NodeData::boxed1(Burst { frame: 0 }));
+    let mut gate = node::Gate::new(-12.0);
+    gate.attack_frames = 200.0;
+    let gate = g.add_node(NodeData::boxed1(gate));
+    g.add_edge(src, gate, ());
+    let mut p = Processor::with_capacity(2);
+    p.process(&mut g, gate);
+
+    let snapshot = p.snapshot(&g);
+    let expected = render(&mut p, &mut g, gate);
+    assert!(expected.iter().any(|&s| s.abs() > 0.1));
+    p.restore(&mut g, &snapshot);
+    assert_eq!(render(&mut p, &mut g, gate), expected);
+}
+
+#[test]
+#[cfg(feature = "node-graph")]
+fn test_snapshot_restore_nested_graph() {
+    // The state of the nested graph is captured via the state of the graph node.
+    let (graph, output_node) = graph();
+    let nested = node::GraphNode {
+        processor: Processor::with_capacity(4),
+        graph,
+        input_nodes: vec![],
+        output_node,
+        node_type: std::marker::PhantomData,
+    };
+    let mut g = Graph::new();
+    let out = g.add_node(NodeData::boxed1(nested));
+    let mut p = Processor::with_capacity(1);
+    for _ in 0..3 {
+        p.process(&mut g, out);
+    }
+
+    let snapshot = p.snapshot(&g);
+    let expected = render(&mut p, &mut g, out);
+    assert!(expected.iter().any(|&s| s.abs() > 0.1));
+    p.restore(&mut g, &snapshot);
+    assert_eq!(render(&mut p, &mut g, out), expected);
+}
@@ -130,3 +130,61 @@ fn test_sum_unboxed() {
     let expected = Buffer::from([0.2; Buffer::LEN]);
     assert_eq!(&g[sum].buffers[..], &[expected][..]);
 }
+
+fn constant(channels: &[f32]) -> Vec<Buffer> {
+    channels
+        .iter()
+        .map(|&value| Buffer::from([value; Buffer::LEN]))
+        .collect()
+}
+
+#[test]
+fn test_sum_indexed_mismatched_channels() {
+    let mono = constant(&[0.5]);
+    let stereo = constant(&[0.1, 0.2]);
+    let inputs = [Input::new(&mono, ()), Input::new(&stereo, ())];
+
+    // A mono input only lands on the first channel.
+    let mut output = vec![Buffer::SILENT; 2];
+    node::Sum.process(&inputs, &mut output);
+    assert_eq!(output, constant(&[0.6, 0.2]));
+
+    // Input channels beyond the output are dropped.
+    let mut output = vec![Buffer::SILENT; 1];
+    node::Sum.process(&inputs, &mut output);
+    assert_eq!(output, constant(&[0.6]));
+}
+
+#[test]
+fn test_sum_mono_broadcast() {
+    let mono = constant(&[0.5]);
+    let stereo = constant(&[0.125, 0.25]);
+    let inputs = [Input::new(&mono, ()), Input::new(&stereo, ())];
+    let mut sum = node::Sum::with_mono_broadcast();
+    assert_eq!(sum, node::SumBroadcast);
+
+    // The mono input is broadcast across the stereo output.
+    let mut output = vec![Buffer::SILENT; 2];
+    sum.process(&inputs, &mut output);
+    assert_eq!(output, constant(&[0.625, 0.75]));
+}
+
+#[test]
+fn test_sum_mono_broadcast_downmix() {
+    let stereo = constant(&[0.25, 0.5]);
+    let mut sum = node::Sum::with_mono_broadcast();
+
+    // The stereo input is averaged onto the mono output.
+    let mut output = vec![Buffer::SILENT; 1];
+    sum.process(&[Input::new(&stereo, ())], &mut output);
+    assert_eq!(output, constant(&[0.375]));
+
+    // Channels beyond the output fold onto the channel of the same index modulo the output count.
+    let surround = constant(&[0.25, 0.5, 0.75]);
+    let mut output = vec![Buffer::SILENT; 2];
+    sum.process(&[Input::new(&surround, ())], &mut output);
+    assert_eq!(output, constant(&[0.5, 0.5]));
+
+    // Without output buffers, nothing is written.
+    sum.process(&[Input::new(&surround, ())], &mut []);
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "node-table-shaper")]
+
+use dasp_graph::{node, Buffer, Input, Node};
+
+// A ramp spanning beyond the range of the table.
+fn ramp() -> Buffer {
+    let mut buf = Buffer::SILENT;
+    for (i, s) in buf.iter_mut().enumerate() {
+        *s = -1.5 + 3.0 * i as f32 / (Buffer::LEN - 1) as f32;
+    }
+    buf
+}
+
+fn process(shaper: &mut node::TableShaper, in_buf: &Buffer) -> Buffer {
+    let mut output = vec![Buffer::SILENT; 2];
+    shaper.process(&[Input::new(std::slice::from_ref(in_buf), ())], &mut output);
+    assert_eq!(output[1], Buffer::SILENT);
+    output[0].clone()
+}
+
+#[test]
+fn test_identity_table() {
+    let mut shaper = node::TableShaper::new(vec![-1.0, 1.0]);
+    let mut in_buf = ramp();
+    in_buf.iter_mut().for_each(|s| *s /= 1.5);
+    let out = process(&mut shaper, &in_buf);
+    for (x, y) in in_buf.iter().zip(out.iter()) {
+        assert!((x - y).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn test_hard_clip_table() {
+    // Unity gain up to +-0.5 and flat beyond, sampled at 5 points.
+    let mut shaper = node::TableShaper::new(vec![-0.5, -0.5, 0.0, 0.5, 0.5]);
+    let in_buf = ramp();
+    let out = process(&mut shaper, &in_buf);
+    for (&x, &y) in in_buf.iter().zip(out.iter()) {
+        assert!((y - x.clamp(-0.5, 0.5)).abs() < 1e-6, "{} -> {}", x, y);
+    }
+}
+
+#[test]
+fn test_interpolation_drive_and_mix() {
+    let mut shaper = node::TableShaper::new(vec![0.0, 1.0, 0.0]);
+    assert_eq!(shaper.shape(-1.0), 0.0);
+    assert_eq!(shaper.shape(-0.5), 0.5);
+    assert_eq!(shaper.shape(0.0), 1.0);
+    assert_eq!(shaper.shape(0.25), 0.75);
+    assert_eq!(shaper.shape(2.0), 0.0);
+
+    let in_buf = Buffer::from([0.25; Buffer::LEN]);
+    shaper.drive = 2.0;
+    assert_eq!(process(&mut shaper, &in_buf)[0], 0.5);
+    shaper.mix = 0.5;
+    assert_eq!(process(&mut shaper, &in_buf)[0], 0.375);
+    shaper.mix = 0.0;
+    assert_eq!(process(&mut shaper, &in_buf)[0], 0.25);
+}
@@ -0,0 +1,80 @@
+#![cfg(all(
+    feature = "node-boxed",
+    feature = "node-channel-delay",
+    feature = "node-delay",
+    feature = "node-pass"
+))]
+
+use dasp_graph::{node, Buffer, Input, Node, NodeData};
+use dasp_ring_buffer as ring_buffer;
+
+type BoxedNode = dasp_graph::BoxedNode<()>;
+type Graph = petgraph::Graph<NodeData<BoxedNode>, (), petgraph::Directed, u32>;
+type Processor = dasp_graph::Processor<Graph>;
+
+// A source node that writes a single impulse at the very first frame it renders.
+struct Impulse(bool);
+
+impl Node for Impulse {
+    fn process(&mut self, _inputs: &[Input], output: &mut [Buffer]) {
+        output[0].silence();
+        if !self.0 {
+            output[0][0] = 1.0;
+            self.0 = true;
+        }
+    }
+}
+
+fn delay(frames: usize) -> NodeData<BoxedNode> {
+    let ring_buf = ring_buffer::Fixed::from(vec![0.0; frames]);
+    NodeData::boxed1(node::Delay(vec![ring_buf]))
+}
+
+#[test]
+fn test_tail_frames() {
+    let mut g = Graph::new();
+    let p = Processor::with_capacity(8);
+
+    // A chain of two delays alongside a shorter parallel branch.
+    let src = g.add_node(NodeData::boxed1(Impulse(false)));
+    let a = g.add_node(delay(100));
+    let b = g.add_node(delay(50));
+    let c = g.add_node(NodeData::boxed1(node::ChannelDelay::new(vec![30.5])));
+    let out = g.add_node(NodeData::boxed1(node::Pass));
+    g.add_edge(src, a, ());
+    g.add_edge(a, b, ());
+    g.add_edge(b, out, ());
+    g.add_edge(src, c, ());
+    g.add_edge(c, out, ());
+
+    assert_eq!(g[src].node.tail_frames(), None);
+    assert_eq!(g[a].node.tail_frames(), Some(100));
+    assert_eq!(g[c].node.tail_frames(), Some(31));
+
+    // Tails are summed along each path, taking the longest.
+    assert_eq!(p.tail_frames(&g, src), 0);
+    assert_eq!(p.tail_frames(&g, a), 100);
+    assert_eq!(p.tail_frames(&g, c), 31);
+    assert_eq!(p.tail_frames(&g, out), 150);
+}
+
+#[test]
+fn test_render_tail() {
+    let mut g = Graph::new();
+    let mut p = Processor::with_capacity(4);
+    let src = g.add_node(NodeData::boxed1(Impulse(false)));
+    let a = g.add_node(delay(100));
+    let b = g.add_node(delay(50));
+    g.add_edge(src, a, ());
+    g.add_edge(a, b, ());
+
+    // Rendering one block of input plus the tail captures the delayed impulse.
+    let tail_blocks = p.tail_frames(&g, b).div_ceil(Buffer::LEN);
+    let mut output = vec![];
+    for _ in 0..1 + tail_blocks {
+        p.process(&mut g, b);
+        output.extend(g[b].buffers[0].iter().cloned());
+    }
+    assert_eq!(output[150], 1.0);
+    assert_eq!(output.iter().filter(|&&s| s != 0.0).count(), 1);
+}
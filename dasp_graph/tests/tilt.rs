@@ -0,0 +1,66 @@
+#![cfg(feature = "node-tilt")]
+
+mod common;
+
+use common::{magnitude, SAMPLE_RATE};
+use dasp_graph::node::Tilt;
+use dasp_graph::Buffer;
+
+// Frequencies are chosen to fall exactly on the bins of the measured length to avoid leakage.
+const LOW_HZ: f64 = 187.5;
+const HIGH_HZ: f64 = 7_500.0;
+
+// Render a tone of the given frequency through the tilt, returning the input and output of the
+// first channel once the filters have settled.
+fn render(tilt: &mut Tilt, hz: f64) -> (Vec<f32>, Vec<f32>) {
+    let (mut input, mut output) = common::render(tilt, 2, 96, 32, |block| {
+        vec![common::sine(block, hz, 0.5), Buffer::SILENT]
+    });
+    assert!(output[1].iter().all(|&s| s == 0.0));
+    (input.remove(0), output.remove(0))
+}
+
+// The gain in dB applied by the tilt to a tone of the given frequency.
+fn gain_db(tilt: &mut Tilt, hz: f64) -> f64 {
+    tilt.reset();
+    let (input, output) = render(tilt, hz);
+    20.0 * (magnitude(&output, hz) / magnitude(&input, hz)).log10()
+}
+
+#[test]
+fn test_tilt_flat_is_transparent() {
+    let mut tilt = Tilt::new(2, SAMPLE_RATE);
+    let (input, output) = render(&mut tilt, HIGH_HZ);
+    for (a, b) in input.iter().zip(&output) {
+        assert!((a - b).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn test_tilt_positive_brightens() {
+    let mut tilt = Tilt::new(2, SAMPLE_RATE);
+    tilt.set_tilt_db(6.0);
+    let low = gain_db(&mut tilt, LOW_HZ);
+    let high = gain_db(&mut tilt, HIGH_HZ);
+    assert!(low < -2.5 && low > -3.1, "{}", low);
+    assert!(high > 2.5 && high < 3.1, "{}", high);
+}
+
+#[test]
+fn test_tilt_negative_darkens() {
+    let mut tilt = Tilt::new(2, SAMPLE_RATE);
+    tilt.set_tilt_db(-6.0);
+    let low = gain_db(&mut tilt, LOW_HZ);
+    let high = gain_db(&mut tilt, HIGH_HZ);
+    assert!(low > 2.5 && low < 3.1, "{}", low);
+    assert!(high < -2.5 && high > -3.1, "{}", high);
+}
+
+#[test]
+fn test_tilt_pivot_is_unchanged() {
+    let mut tilt = Tilt::new(2, SAMPLE_RATE);
+    tilt.set_pivot(1_500.0);
+    tilt.set_tilt_db(12.0);
+    let pivot = gain_db(&mut tilt, 1_500.0);
+    assert!(pivot.abs() < 0.1, "{}", pivot);
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "node-tremolo")]
+
+use dasp_graph::node::{Tremolo, TremoloRate, TremoloWaveform};
+use dasp_graph::{Buffer, Input, Node};
+
+const SAMPLE_RATE: f64 = 48_000.0;
+
+// Process a constant stereo signal of `1.0`, returning the gain of each channel.
+fn render(tremolo: &mut Tremolo, blocks: usize) -> [Vec<f32>; 2] {
+    let bufs = vec![Buffer::from([1.0; Buffer::LEN]); 2];
+    let mut output = vec![Buffer::SILENT; 2];
+    let mut gains = [vec![], vec![]];
+    for _ in 0..blocks {
+        tremolo.process(&[Input::new(&bufs, ())], &mut output);
+        for (ch, gain) in gains.iter_mut().enumerate() {
+            gain.extend(output[ch].iter().cloned());
+        }
+    }
+    gains
+}
+
+#[test]
+fn test_tremolo_auto_pan() {
+    // One LFO cycle every 1500 frames.
+    let mut tremolo = Tremolo::new(SAMPLE_RATE, TremoloRate::Hz(32.0));
+    tremolo.depth = 1.0;
+    tremolo.stereo_phase = 180.0;
+    let [l, r] = render(&mut tremolo, 47);
+
+    // The channels modulate in anti-phase across the full depth.
+    for (l, r) in l.iter().zip(&r) {
+        assert!((l + r - 1.0).abs() < 1e-5);
+    }
+    let max = l.iter().cloned().fold(0.0, f32::max);
+    let min = l.iter().cloned().fold(1.0, f32::min);
+    assert!(max > 0.999 && min < 0.001);
+    assert_eq!(l[0], 1.0);
+    assert_eq!(l[750], 0.0);
+
+    // The phase persists across blocks, so the modulation is continuous.
+    let max_step = 2.0 * std::f32::consts::PI / 1_500.0 * 0.5;
+    for w in l.windows(2) {
+        assert!((w[1] - w[0]).abs() <= max_step + 1e-5);
+    }
+}
+
+#[test]
+fn test_tremolo_tempo_sync_and_depth() {
+    // Quarter notes at 120 BPM, i.e. one cycle every 24000 frames.
+    let rate = TremoloRate::Tempo {
+        bpm: 120.0,
+        beats: 1.0,
+    };
+    let mut tremolo = Tremolo::new(SAMPLE_RATE, rate);
+    assert_eq!(tremolo.hz(), 2.0);
+    tremolo.waveform = TremoloWaveform::Triangle;
+    tremolo.depth = 0.5;
+    let [l, r] = render(&mut tremolo, 375);
+    assert_eq!(l, r);
+    assert_eq!(l[0], 1.0);
+    assert!((l[6_000] - 0.75).abs() < 1e-4);
+    assert!((l[12_000] - 0.5).abs() < 1e-4);
+    assert!((l[18_000] - 0.75).abs() < 1e-4);
+    assert!((tremolo.phase() - 0.0).abs() < 1e-6 || (tremolo.phase() - 1.0).abs() < 1e-6);
+}
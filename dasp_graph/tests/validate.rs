@@ -0,0 +1,109 @@
+use dasp_graph::{validate, Buffer, GraphError, Input, Node, NodeData};
+
+type Graph = petgraph::Graph<NodeData<TestNode>, (), petgraph::Directed, u32>;
+
+// A node that declares the given expectations of its inputs.
+#[derive(Default)]
+struct TestNode {
+    inputs: Option<usize>,
+    channels: Option<usize>,
+}
+
+impl Node for TestNode {
+    fn process(&mut self, _inputs: &[Input], output: &mut [Buffer]) {
+        output.iter_mut().for_each(|buf| buf.silence());
+    }
+
+    fn expected_inputs(&self) -> Option<usize> {
+        self.inputs
+    }
+
+    fn expected_input_channels(&self) -> Option<usize> {
+        self.channels
+    }
+}
+
+fn source() -> NodeData<TestNode> {
+    NodeData::new1(TestNode::default())
+}
+
+#[test]
+fn test_validate_input_count() {
+    let mut g = Graph::new();
+    let effect = g.add_node(NodeData::new1(TestNode {
+        inputs: Some(1),
+        ..Default::default()
+    }));
+    let a = g.add_node(source());
+    g.add_edge(a, effect, ());
+    assert_eq!(validate(&g), Ok(()));
+
+    // Over-connect the effect.
+    let b = g.add_node(source());
+    let c = g.add_node(source());
+    g.add_edge(b, effect, ());
+    g.add_edge(c, effect, ());
+    let errors = validate(&g).unwrap_err();
+    assert_eq!(
+        errors,
+        vec![GraphError::InputCount {
+            node: effect,
+            expected: 1,
+            found: 3,
+        }]
+    );
+    assert_eq!(errors[0].node(), effect);
+    assert_eq!(
+        errors[0].to_string(),
+        "node NodeIndex(0) expects 1 inputs but has 3"
+    );
+}
+
+#[test]
+fn test_validate_input_channels() {
+    let mut g = Graph::new();
+    let effect = g.add_node(NodeData::new1(TestNode {
+        channels: Some(2),
+        ..Default::default()
+    }));
+    let mono = g.add_node(source());
+    let stereo = g.add_node(NodeData::new2(TestNode::default()));
+    g.add_edge(mono, effect, ());
+    g.add_edge(stereo, effect, ());
+    assert_eq!(
+        validate(&g),
+        Err(vec![GraphError::InputChannels {
+            node: effect,
+            input: mono,
+            expected: 2,
+            found: 1,
+        }])
+    );
+}
+
+#[test]
+fn test_validate_opt_in() {
+    // Nodes without expectations are never flagged, regardless of their inputs.
+    let mut g = Graph::new();
+    let sum = g.add_node(source());
+    for _ in 0..3 {
+        let src = g.add_node(NodeData::new2(TestNode::default()));
+        g.add_edge(src, sum, ());
+    }
+    assert_eq!(validate(&g), Ok(()));
+}
+
+#[test]
+fn test_validate_self_loop() {
+    // Self-loops only count as an input for feedback nodes, as during processing.
+    let mut g = Graph::new();
+    let n = g.add_node(NodeData::new1(TestNode {
+        inputs: Some(0),
+        ..Default::default()
+    }));
+    g.add_edge(n, n, ());
+    assert_eq!(validate(&g), Ok(()));
+
+    g[n].feedback = true;
+    assert_eq!(validate(&g).unwrap_err()[0].node(), n);
+}
@@ -0,0 +1,86 @@
+#![cfg(feature = "node-vibrato")]
+
+use dasp_graph::node::{Vibrato, VibratoWaveform};
+use dasp_graph::{Buffer, Input, Node};
+use std::f64::consts::PI;
+
+const SAMPLE_RATE: f64 = 44_100.0;
+
+// Process the given number of blocks of a steady sine at `hz`, returning the first channel.
+fn render(vibrato: &mut Vibrato, hz: f64, blocks: usize) -> Vec<f32> {
+    let mut output = vec![Buffer::SILENT];
+    let mut out = vec![];
+    for block in 0..blocks {
+        let mut buf = Buffer::SILENT;
+        for (ix, s) in buf.iter_mut().enumerate() {
+            let t = (block * Buffer::LEN + ix) as f64 / SAMPLE_RATE;
+            *s = (2.0 * PI * hz * t).sin() as f32;
+        }
+        vibrato.process(&[Input::new(&[buf], ())], &mut output);
+        out.extend(output[0].iter().cloned());
+    }
+    out
+}
+
+// The frequency of each cycle of the signal, measured between upward zero crossings.
+fn cycle_frequencies(signal: &[f32]) -> Vec<f64> {
+    let mut crossings = vec![];
+    for (i, w) in signal.windows(2).enumerate() {
+        if w[0] < 0.0 && w[1] >= 0.0 {
+            let frac = w[0] as f64 / (w[0] - w[1]) as f64;
+            crossings.push(i as f64 + frac);
+        }
+    }
+    crossings
+        .windows(2)
+        .map(|w| SAMPLE_RATE / (w[1] - w[0]))
+        .collect()
+}
+
+#[test]
+fn test_vibrato_wobbles_at_lfo_rate() {
+    let mut vibrato = Vibrato::new(1, SAMPLE_RATE, 5.0);
+    vibrato.rate = 5.0;
+    vibrato.depth_ms = 2.0;
+    let out = render(&mut vibrato, 1_000.0, 2_000);
+    // Skip the first second, then measure the frequency of each cycle of the 1kHz tone.
+    let freqs = cycle_frequencies(&out[SAMPLE_RATE as usize..]);
+
+    // The pitch deviates by roughly `depth * PI * rate` in either direction, i.e. ~3%.
+    let max = freqs.iter().cloned().fold(0.0, f64::max);
+    let min = freqs.iter().cloned().fold(f64::MAX, f64::min);
+    assert!(max > 1_025.0 && max < 1_040.0, "{}", max);
+    assert!(min < 975.0 && min > 960.0, "{}", min);
+
+    // Each LFO cycle spans 200 cycles of the tone. The deviation repeats every full LFO cycle and
+    // inverts every half cycle.
+    let diff = |lag: usize| {
+        let n = freqs.len() - lag;
+        (0..n)
+            .map(|i| (freqs[i] - freqs[i + lag]).abs())
+            .sum::<f64>()
+            / n as f64
+    };
+    assert!(diff(200) < 1.0, "{}", diff(200));
+    assert!(diff(100) > 20.0, "{}", diff(100));
+}
+
+#[test]
+fn test_vibrato_no_depth_is_delay() {
+    let mut vibrato = Vibrato::new(2, SAMPLE_RATE, 5.0);
+    vibrato.depth_ms = 0.0;
+    vibrato.waveform = VibratoWaveform::Triangle;
+    let mut input = Buffer::SILENT;
+    input[0] = 1.0;
+    let mut output = vec![Buffer::SILENT; 3];
+    vibrato.process(&[Input::new(&[input.clone(), input], ())], &mut output);
+
+    // Only the wet signal is output, delayed by a single frame.
+    let mut expected = Buffer::SILENT;
+    expected[1] = 1.0;
+    assert_eq!(output[0], expected);
+    assert_eq!(output[1], expected);
+    assert_eq!(output[2], Buffer::SILENT);
+    assert!(vibrato.phase() > 0.0);
+    assert_eq!(vibrato.tail_frames(), Some(1));
+}
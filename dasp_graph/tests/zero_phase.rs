@@ -0,0 +1,100 @@
+use dasp_graph::{Buffer, Input, Node};
+
+// A one-pole low-pass filter applied to each channel.
+#[derive(Clone)]
+struct OnePole {
+    coeff: f32,
+    state: Vec<f32>,
+}
+
+impl OnePole {
+    fn new(coeff: f32) -> Self {
+        OnePole {
+            coeff,
+            state: vec![0.0; 2],
+        }
+    }
+}
+
+impl Node for OnePole {
+    fn process(&mut self, inputs: &[Input], output: &mut [Buffer]) {
+        let in_bufs = inputs[0].buffers();
+        for ((out_buf, in_buf), y) in output.iter_mut().zip(in_bufs).zip(&mut self.state) {
+            for (out, &x) in out_buf.iter_mut().zip(in_buf.iter()) {
+                *y += self.coeff * (x - *y);
+                *out = *y;
+            }
+        }
+    }
+}
+
+#[test]
+fn test_zero_phase_impulse_response_is_symmetric() {
+    let (len, centre) = (1_000, 500);
+    let mut channels = vec![vec![0.0; len], vec![0.0; len]];
+    channels[0][centre] = 1.0;
+    channels[1][centre] = -1.0;
+    dasp_graph::process_zero_phase(&OnePole::new(0.1), &mut channels, Buffer::LEN);
+    for k in 1..200 {
+        let (before, after) = (channels[0][centre - k], channels[0][centre + k]);
+        assert!(
+            (before - after).abs() < 1e-6,
+            "{}: {} != {}",
+            k,
+            before,
+            after
+        );
+        assert_eq!(channels[1][centre - k], -before);
+    }
+    // The peak remains at the position of the impulse.
+    assert!(channels[0][centre] > channels[0][centre + 1]);
+    assert!(channels[0][centre] > channels[0][centre - 1]);
+}
+
+#[test]
+fn test_zero_phase_has_no_phase_shift() {
+    // A slow sine is passed through without any lag, where a single pass would delay it.
+    let len = 4_000;
+    let sine: Vec<f32> = (0..len)
+        .map(|i| (2.0 * std::f32::consts::PI * i as f32 / 400.0).sin())
+        .collect();
+    let mut channels = vec![sine.clone()];
+    dasp_graph::process_zero_phase(&OnePole::new(0.5), &mut channels, Buffer::LEN);
+    for (a, b) in sine[1_000..3_000].iter().zip(&channels[0][1_000..3_000]) {
+        assert!((a - b).abs() < 1e-3, "{} != {}", a, b);
+    }
+
+    // For comparison, a single forward pass lags behind the input.
+    let mut node = OnePole::new(0.5);
+    let mut forward = vec![];
+    for chunk in sine.chunks(Buffer::LEN) {
+        let mut in_buf = Buffer::SILENT;
+        in_buf[..chunk.len()].copy_from_slice(chunk);
+        let mut out = [Buffer::SILENT];
+        node.process(&[Input::new(&[in_buf], ())], &mut out);
+        forward.extend(out[0][..chunk.len()].iter().cloned());
+    }
+    let max_err = sine[1_000..3_000]
+        .iter()
+        .zip(&forward[1_000..3_000])
+        .map(|(a, b)| (a - b).abs())
+        .fold(0.0, f32::max);
+    assert!(max_err > 1e-2);
+}
+
+#[test]
+fn test_zero_phase_preserves_length() {
+    let mut channels = vec![vec![1.0; Buffer::LEN * 3 + 5]];
+    dasp_graph::process_zero_phase(&OnePole::new(1.0), &mut channels, Buffer::LEN);
+    assert_eq!(channels[0], vec![1.0; Buffer::LEN * 3 + 5]);
+}
+
+#[test]
+fn test_zero_phase_block_frames() {
+    let signal: Vec<f32> = (0..1_000).map(|i| ((i * 7) % 13) as f32 - 6.0).collect();
+    let mut a = vec![signal.clone()];
+    let mut b = vec![signal];
+    dasp_graph::process_zero_phase(&OnePole::new(0.3), &mut a, Buffer::LEN);
+    dasp_graph::process_zero_phase(&OnePole::new(0.3), &mut b, 300);
+    assert_eq!(a, b);
+}
@@ -15,6 +15,7 @@ use dasp_sample::Duplex;
 ///
 /// - When using `dasp_interpolate`, this item requires the **floor** feature to be enabled.
 /// - When using `dasp`, this item requires the **interpolate-floor** feature to be enabled.
+#[derive(Clone, Debug)]
 pub struct Floor<F> {
     left: F,
 }
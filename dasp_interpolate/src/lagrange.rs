@@ -0,0 +1,108 @@
+//! A Lagrange interpolator implementation.
+//!
+//! ### Required Features
+//!
+//! - When using `dasp_interpolate`, this module requires the **lagrange** feature to be enabled.
+//! - When using `dasp`, this module requires the **interpolate-lagrange** feature to be enabled.
+
+use crate::Interpolator;
+use dasp_frame::Frame;
+use dasp_ring_buffer as ring_buffer;
+use dasp_sample::{Duplex, Sample};
+
+/// Interpolator that fits a polynomial of a configurable order through the surrounding frames.
+///
+/// The order is one less than the number of frames in the ring buffer given to `new`. An order of
+/// `1` is equivalent to linear interpolation. Odd orders such as `3` or `5` are recommended, as the
+/// interpolated point is then centred between the frames used for the fit.
+///
+/// Lagrange interpolation is a middle ground between `Linear` and `Sinc` interpolation. It
+/// reproduces any polynomial of degree less than or equal to its order exactly and has a flat
+/// response at low frequencies, making it well suited to fractional delay line reads. However,
+/// its response rolls off towards the Nyquist frequency and it provides little attenuation of
+/// aliasing, so `Sinc` remains the better choice for sample rate conversion where quality matters
+/// most. The cost per interpolated frame grows with the square of the order.
+///
+/// ### Required Features
+///
+/// - When using `dasp_interpolate`, this item requires the **lagrange** feature to be enabled.
+/// - When using `dasp`, this item requires the **interpolate-lagrange** feature to be enabled.
+pub struct Lagrange<S> {
+    frames: ring_buffer::Fixed<S>,
+}
+
+impl<S> Lagrange<S> {
+    /// Create a new **Lagrange** interpolator with the given ring buffer.
+    ///
+    /// The given ring buffer should have a length one greater than the desired interpolation
+    /// order. Interpolation occurs between the frame at index `(order - 1) / 2` and the frame
+    /// that follows it, so the interpolated signal lags the most recent source frame by
+    /// `(order + 1) / 2` frames.
+    ///
+    /// The initial contents of the ring_buffer will act as padding for the interpolated signal.
+    ///
+    /// **panic!**s if the given ring buffer's length is less than `2`.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_interpolate`, this item requires the **lagrange** feature to be enabled.
+    /// - When using `dasp`, this item requires the **interpolate-lagrange** feature to be enabled.
+    pub fn new(frames: ring_buffer::Fixed<S>) -> Self
+    where
+        S: ring_buffer::SliceMut,
+        S::Element: Frame,
+    {
+        assert!(frames.len() >= 2);
+        Lagrange { frames }
+    }
+
+    /// The order of the interpolating polynomial.
+    pub fn order(&self) -> usize
+    where
+        S: ring_buffer::Slice,
+    {
+        self.frames.len() - 1
+    }
+}
+
+impl<S> Interpolator for Lagrange<S>
+where
+    S: ring_buffer::SliceMut,
+    S::Element: Frame,
+    <S::Element as Frame>::Sample: Duplex<f64>,
+{
+    type Frame = S::Element;
+
+    /// Lagrange interpolation
+    fn interpolate(&self, x: f64) -> Self::Frame {
+        let n = self.frames.len();
+        // The position of the frame that precedes the interpolated point.
+        let left = (n - 2) / 2;
+        Self::Frame::from_fn(|ch| {
+            let mut sum = 0.0;
+            for j in 0..n {
+                let weight = (0..n).filter(|&m| m != j).fold(1.0, |w, m| {
+                    let (pos_j, pos_m) = (j as f64 - left as f64, m as f64 - left as f64);
+                    w * (x - pos_m) / (pos_j - pos_m)
+                });
+                let sample = match self.frames[j].channel(ch) {
+                    Some(sample) => sample.to_sample::<f64>(),
+                    None => 0.0,
+                };
+                sum += weight * sample;
+            }
+            sum.to_sample()
+        })
+    }
+
+    fn next_source_frame(&mut self, source_frame: Self::Frame) {
+        let _old_frame = self.frames.push(source_frame);
+    }
+
+    fn reset(&mut self) {
+        self.frames.set_first(0);
+        for frame in self.frames.iter_mut() {
+            *frame = Self::Frame::EQUILIBRIUM;
+        }
+    }
+}
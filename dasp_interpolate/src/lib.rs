@@ -10,6 +10,8 @@
 //!
 //! - The **floor** feature (or **interpolate-floor** feature if using `dasp`) provides a floor
 //!   interpolator implementation.
+//! - The **lagrange** feature (or **interpolate-lagrange** feature if using `dasp`) provides a
+//!   Lagrange interpolator implementation of configurable order.
 //! - The **linear** feature (or **interpolate-linear** feature if using `dasp`) provides a linear
 //!   interpolator implementation.
 //! - The **sinc** feature (or **interpolate-sinc** feature if using `dasp`) provides a sinc
@@ -29,6 +31,8 @@ use dasp_frame::Frame;
 
 #[cfg(feature = "floor")]
 pub mod floor;
+#[cfg(feature = "lagrange")]
+pub mod lagrange;
 #[cfg(feature = "linear")]
 pub mod linear;
 #[cfg(feature = "sinc")]
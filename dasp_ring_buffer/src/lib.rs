@@ -6,6 +6,8 @@
 //! for types that may be used as the underlying buffer in `Fixed` and `Bounded` ring buffers.
 //! - The [Fixed](./struct.Fixed.html) ring buffer type.
 //! - The [Bounded](./struct.Bounded.html) ring buffer type.
+//! - The [DelayLine](./struct.DelayLine.html) type for reading fractional delays from a `Fixed`
+//!   ring buffer.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -14,7 +16,7 @@ extern crate alloc;
 
 use core::iter::{Chain, Cycle, FromIterator, Skip, Take};
 use core::mem;
-use core::ops::{Index, IndexMut};
+use core::ops::{Add, Index, IndexMut, Mul, Sub};
 use core::ptr;
 use core::slice;
 
@@ -903,3 +905,177 @@ where
         self.bounded.len()
     }
 }
+
+/////////////////////////////
+///// FRACTIONAL DELAY //////
+/////////////////////////////
+
+/// The method used by a `DelayLine` to read between the samples of its buffer.
+///
+/// These correspond to the `Floor`, `Linear` and 3rd-order `Lagrange` interpolators of
+/// `dasp_interpolate`, which can't be used here as `dasp_interpolate` itself depends on
+/// `dasp_ring_buffer`. Those interpolators also stream source frames at a conversion ratio, while
+/// a `DelayLine` reads at arbitrary delays from a buffer that it shares with every read.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Interpolation {
+    /// Rounds the delay down to the nearest whole frame. Cheapest, but fractional delays are not
+    /// supported.
+    Floor,
+    /// Linearly interpolates between the two nearest samples. Attenuates high frequencies for
+    /// fractional delays, particularly near half a frame.
+    Linear,
+    /// Interpolates through the four nearest samples with a 3rd-order Lagrange polynomial,
+    /// preserving more high-frequency content than `Linear` at a slightly greater cost.
+    Cubic,
+}
+
+/// The sample types that a `DelayLine` may hold and interpolate between.
+///
+/// Implemented for `f32` and `f64`, the types in which interpolation is performed.
+pub trait DelaySample: Copy + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> {
+    /// The value of silence.
+    const EQUILIBRIUM: Self;
+
+    /// Convert the given `f32` (a delay or an interpolation coefficient) to this type.
+    fn from_f32(f: f32) -> Self;
+}
+
+impl DelaySample for f32 {
+    const EQUILIBRIUM: Self = 0.0;
+
+    #[inline]
+    fn from_f32(f: f32) -> Self {
+        f
+    }
+}
+
+impl DelaySample for f64 {
+    const EQUILIBRIUM: Self = 0.0;
+
+    #[inline]
+    fn from_f32(f: f32) -> Self {
+        f as f64
+    }
+}
+
+/// A delay line supporting reads at fractional delays, e.g. for modulated delay, chorus, flanger
+/// and comb filter effects.
+///
+/// `f32` or `f64` samples are pushed into an underlying `Fixed` ring buffer and may then be read
+/// back from any delay in frames, interpolating between samples using the selected
+/// `Interpolation`. A delay of `0.0` reads the most recently pushed sample.
+///
+/// The longest delay that may be read is `max_delay`, one less than the length of the buffer.
+/// Longer delays are clamped to this maximum. Interpolation near the maximum reuses the oldest
+/// sample, so allocate a buffer at least one frame longer (or two, for `Cubic`) than the longest
+/// fractional delay that will be read.
+///
+/// ```
+/// use dasp_ring_buffer::{DelayLine, Interpolation};
+///
+/// fn main() {
+///     let mut delay = DelayLine::new(vec![0.0; 8], Interpolation::Linear);
+///     for i in 0..8 {
+///         delay.push(i as f32);
+///     }
+///     assert_eq!(delay.read(0.0), 7.0);
+///     assert_eq!(delay.read(2.5), 4.5);
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct DelayLine<S> {
+    buffer: Fixed<S>,
+    interpolation: Interpolation,
+}
+
+impl<S> DelayLine<S>
+where
+    S: SliceMut,
+    S::Element: DelaySample,
+{
+    /// Create a new **DelayLine** around the given data slice, whose length determines the
+    /// maximum delay.
+    ///
+    /// **Panic!**s if the given `data` buffer is empty.
+    pub fn new(data: S, interpolation: Interpolation) -> Self {
+        DelayLine {
+            buffer: Fixed::from(data),
+            interpolation,
+        }
+    }
+
+    /// The longest delay in frames that may be read.
+    pub fn max_delay(&self) -> f32 {
+        (self.buffer.len() - 1) as f32
+    }
+
+    /// The method used to read between samples.
+    pub fn interpolation(&self) -> Interpolation {
+        self.interpolation
+    }
+
+    /// Set the method used to read between samples.
+    pub fn set_interpolation(&mut self, interpolation: Interpolation) {
+        self.interpolation = interpolation;
+    }
+
+    /// Push a new sample into the delay line, returning the oldest sample which is removed.
+    #[inline]
+    pub fn push(&mut self, sample: S::Element) -> S::Element {
+        self.buffer.push(sample)
+    }
+
+    /// Read the sample at the given delay in frames, where `0.0` is the most recently pushed
+    /// sample.
+    ///
+    /// The delay is clamped to the range `0.0..=self.max_delay()`.
+    #[inline]
+    pub fn read(&self, delay: f32) -> S::Element {
+        let delay = delay.max(0.0).min(self.max_delay());
+        // Truncation is equivalent to `floor` as the delay is positive.
+        let whole = delay as usize;
+        let frac = delay - whole as f32;
+        let sample = |d: usize| {
+            *self
+                .buffer
+                .get(self.buffer.len() - 1 - d.min(self.buffer.len() - 1))
+        };
+        let c = S::Element::from_f32;
+        match self.interpolation {
+            Interpolation::Floor => sample(whole),
+            _ if frac == 0.0 => sample(whole),
+            Interpolation::Linear => {
+                let (a, b) = (sample(whole), sample(whole + 1));
+                a + (b - a) * c(frac)
+            }
+            Interpolation::Cubic => {
+                // Interpolate through the samples at `base..base + 4`, centred around the delay
+                // where possible. At delays below one frame, no newer sample is available and so
+                // the four newest samples are used.
+                let base = whole.max(1) - 1;
+                let x = c(delay - base as f32);
+                let (one, two, three) = (c(1.0), c(2.0), c(3.0));
+                let y = [
+                    sample(base),
+                    sample(base + 1),
+                    sample(base + 2),
+                    sample(base + 3),
+                ];
+                let w = [
+                    (one - x) * (x - two) * (x - three) * c(1.0 / 6.0),
+                    x * (x - two) * (x - three) * c(0.5),
+                    x * (one - x) * (x - three) * c(0.5),
+                    x * (x - one) * (x - two) * c(1.0 / 6.0),
+                ];
+                y[0] * w[0] + y[1] * w[1] + y[2] * w[2] + y[3] * w[3]
+            }
+        }
+    }
+
+    /// Reset every sample within the delay line to equilibrium.
+    pub fn clear(&mut self) {
+        for s in self.buffer.iter_mut() {
+            *s = S::Element::EQUILIBRIUM;
+        }
+    }
+}
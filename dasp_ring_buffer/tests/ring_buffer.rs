@@ -39,3 +39,56 @@ fn test_bounded_get_out_of_range() {
     let rb = ring_buffer::Bounded::from([0i32; 3]);
     let _ = rb[0];
 }
+
+#[test]
+fn test_delay_line_fractional_read() {
+    use ring_buffer::{DelayLine, Interpolation};
+
+    // A ramp, such that the sample at each delay is known exactly.
+    let mut delay = DelayLine::new(vec![0.0; 16], Interpolation::Linear);
+    for i in 0..32 {
+        delay.push(i as f32);
+    }
+    assert_eq!(delay.max_delay(), 15.0);
+    assert_eq!(delay.read(0.0), 31.0);
+    assert_eq!(delay.read(3.0), 28.0);
+    assert_eq!(delay.read(3.25), 27.75);
+    assert_eq!(delay.read(100.0), 16.0);
+    assert_eq!(delay.read(-1.0), 31.0);
+
+    // Cubic interpolation is exact for a ramp, while floor rounds towards the newer sample.
+    delay.set_interpolation(Interpolation::Cubic);
+    assert!((delay.read(3.25) - 27.75).abs() < 1e-5);
+    assert!((delay.read(0.5) - 30.5).abs() < 1e-5);
+    delay.set_interpolation(Interpolation::Floor);
+    assert_eq!(delay.read(3.75), 28.0);
+
+    delay.clear();
+    assert_eq!(delay.read(5.5), 0.0);
+}
+
+#[test]
+fn test_delay_line_cubic_quadratic() {
+    use ring_buffer::{DelayLine, Interpolation};
+
+    // A 3rd-order Lagrange polynomial reproduces a quadratic exactly.
+    let f = |x: f32| 0.5 * x * x - 2.0 * x + 1.0;
+    let mut delay = DelayLine::new([0.0; 8], Interpolation::Cubic);
+    for i in 0..8 {
+        delay.push(f(i as f32));
+    }
+    for &d in &[1.25, 2.5, 4.75] {
+        assert!((delay.read(d) - f(7.0 - d)).abs() < 1e-4);
+    }
+}
+
+#[test]
+fn test_delay_line_sample_types() {
+    use ring_buffer::{DelayLine, Interpolation};
+
+    let mut delay = DelayLine::new([0.0f64; 8], Interpolation::Linear);
+    for i in 0..8 {
+        delay.push(i as f64 * 0.1);
+    }
+    assert!((delay.read(1.5) - 0.55).abs() < 1e-12);
+}
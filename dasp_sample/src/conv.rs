@@ -12,8 +12,11 @@
 //!
 //! Note that floating point conversions use the range -1.0 <= v < 1.0:
 //! `(1.0 as f64).to_sample::<i16>()` will overflow!
+//!
+//! The companded `ALaw` and `MuLaw` types may be converted to and from `i16`, `f32` and `f64`.
+//! These conversions are performed via the 16-bit linear representation.
 
-use crate::types::{I24, I48, U24, U48};
+use crate::types::{ALaw, MuLaw, I24, I48, U24, U48};
 
 macro_rules! conversion_fn {
     ($Rep:ty, $s:ident to_i8 { $body:expr }) => {
@@ -676,6 +679,58 @@ impl_from_sample! {f64, to_f64 from
     {f32:f32}
 }
 
+/// Implement the `FromSample` trait in both directions between the given companded type and the
+/// linear `i16`, `f32` and `f64` types.
+macro_rules! impl_from_sample_companded {
+    ($($T:ident)*) => {
+        $(
+            impl FromSample<i16> for $T {
+                #[inline]
+                fn from_sample_(s: i16) -> Self {
+                    $T::from_linear(s)
+                }
+            }
+
+            impl FromSample<$T> for i16 {
+                #[inline]
+                fn from_sample_(s: $T) -> Self {
+                    s.to_linear()
+                }
+            }
+
+            impl FromSample<f32> for $T {
+                #[inline]
+                fn from_sample_(s: f32) -> Self {
+                    $T::from_linear(self::f32::to_i16(s))
+                }
+            }
+
+            impl FromSample<$T> for f32 {
+                #[inline]
+                fn from_sample_(s: $T) -> Self {
+                    self::i16::to_f32(s.to_linear())
+                }
+            }
+
+            impl FromSample<f64> for $T {
+                #[inline]
+                fn from_sample_(s: f64) -> Self {
+                    $T::from_linear(self::f64::to_i16(s))
+                }
+            }
+
+            impl FromSample<$T> for f64 {
+                #[inline]
+                fn from_sample_(s: $T) -> Self {
+                    self::i16::to_f64(s.to_linear())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_sample_companded!(ALaw MuLaw);
+
 /// Similar to the std `Into` trait, but specifically for converting between sample types.
 ///
 /// This trait has a blanket implementation for all types that implement
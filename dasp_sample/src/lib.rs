@@ -11,9 +11,11 @@
 extern crate alloc;
 
 pub use conv::{Duplex, FromSample, ToSample};
-pub use types::{I24, I48, U24, U48};
+pub use mix::Mix;
+pub use types::{ALaw, MuLaw, I24, I48, U24, U48};
 
 pub mod conv;
+pub mod mix;
 mod ops;
 pub mod types;
 
@@ -238,6 +240,93 @@ pub trait Sample: Copy + Clone + PartialOrd + PartialEq {
         let self_f = self.to_float_sample();
         (self_f * amp).to_sample()
     }
+
+    /// Converts the given floating point sample to `Self`, saturating rather than overflowing.
+    ///
+    /// The value is first clamped to the range `-1.0 <= v < 1.0` expected by the conversion
+    /// functions in the `conv` module, where the upper bound is the greatest `f32` less than
+    /// `1.0`. This applies to floating point formats too, so that the result is well-defined for
+    /// every `Sample` type. Conversions to integral formats then truncate towards zero, as
+    /// described in the `conv` module.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dasp_sample::Sample;
+    ///
+    /// fn main() {
+    ///     assert_eq!(i16::saturating_from_float(0.5), 16_384);
+    ///     assert_eq!(i16::saturating_from_float(1.5), i16::MAX);
+    ///     assert_eq!(u8::saturating_from_float(-4.0), 0);
+    /// }
+    /// ```
+    #[inline]
+    fn saturating_from_float<F>(f: F) -> Self
+    where
+        F: FloatSample,
+    {
+        // The greatest `f32` less than `1.0`, which is also exactly representable as an `f64`.
+        const MAX: f64 = 1.0 - 1.0 / 16_777_216.0;
+        let f = f.to_sample::<f64>().clamp(-1.0, MAX);
+        Self::Float::from_sample(f).to_sample()
+    }
+
+    /// Adds the given sample of any `Sample` type to `self` in a common floating point domain.
+    ///
+    /// Both samples are converted to `f64`, summed and the result is converted back to `Self` via
+    /// `saturating_from_float`. See `saturating_from_float` for details on saturation and
+    /// rounding.
+    ///
+    /// This allows for generic mixing across heterogeneous sample types.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dasp_sample::Sample;
+    ///
+    /// fn main() {
+    ///     assert_eq!(8_192_i16.add_sample(0.25f32), 16_384);
+    ///     assert_eq!(0.75f32.add_sample(192u8), 1.0 - 1.0 / 16_777_216.0);
+    /// }
+    /// ```
+    #[inline]
+    fn add_sample<S>(self, other: S) -> Self
+    where
+        S: Sample,
+    {
+        let a = self.to_float_sample().to_sample::<f64>();
+        let b = other.to_float_sample().to_sample::<f64>();
+        Self::saturating_from_float(a + b)
+    }
+
+    /// Multiplies `self` by the given sample of any `Sample` type in a common floating point
+    /// domain.
+    ///
+    /// Both samples are converted to `f64`, multiplied and the result is converted back to `Self`
+    /// via `saturating_from_float`. See `saturating_from_float` for details on saturation and
+    /// rounding.
+    ///
+    /// This is useful for generic ring modulation across heterogeneous sample types.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dasp_sample::Sample;
+    ///
+    /// fn main() {
+    ///     assert_eq!(16_384_i16.mul_sample(0.5f32), 8_192);
+    ///     assert_eq!((-0.5f64).mul_sample(0_u8), 0.5);
+    /// }
+    /// ```
+    #[inline]
+    fn mul_sample<S>(self, other: S) -> Self
+    where
+        S: Sample,
+    {
+        let a = self.to_float_sample().to_sample::<f64>();
+        let b = other.to_float_sample().to_sample::<f64>();
+        Self::saturating_from_float(a * b)
+    }
 }
 
 /// A macro used to simplify the implementation of `Sample`.
@@ -272,7 +361,9 @@ impl_sample! {
     U48: Signed: i64, Float: f64, EQUILIBRIUM: types::u48::EQUILIBRIUM,
     u64: Signed: i64, Float: f64, EQUILIBRIUM: 9_223_372_036_854_775_808,
     f32: Signed: f32, Float: f32, EQUILIBRIUM: 0.0,
-    f64: Signed: f64, Float: f64, EQUILIBRIUM: 0.0
+    f64: Signed: f64, Float: f64, EQUILIBRIUM: 0.0,
+    ALaw: Signed: i16, Float: f32, EQUILIBRIUM: types::alaw::EQUILIBRIUM,
+    MuLaw: Signed: i16, Float: f32, EQUILIBRIUM: types::mulaw::EQUILIBRIUM
 }
 
 /// Integral and floating-point **Sample** format types whose equilibrium is at 0.
@@ -0,0 +1,118 @@
+//! An accumulator for mixing many samples of any **Sample** type without intermediate clipping.
+
+use crate::{FloatSample, Sample};
+
+/// Accumulates samples of any **Sample** type into a floating point sum, for mixing busses with
+/// a large fan-in.
+///
+/// Summing many samples via `Sample::add_sample` saturates after every addition, such that the
+/// result depends on the order of the inputs and may clip even when the final sum is in range.
+/// The **Mix** instead converts each sample to the floating point type `F` and accumulates the
+/// unclamped sum. The sum is converted to the target type with a single final saturation and
+/// rounding step via `Sample::saturating_from_float`.
+///
+/// # Example
+///
+/// ```rust
+/// use dasp_sample::Mix;
+///
+/// fn main() {
+///     let mut mix = Mix::<f64>::new();
+///     mix.add(i16::MAX);
+///     mix.add(0.75f32);
+///     mix.add(-1.0f64);
+///     assert_eq!(mix.to_sample::<f32>(), 0.75 + 32_767.0 / 32_768.0 - 1.0);
+/// }
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Mix<F = f64> {
+    sum: F,
+}
+
+impl<F> Mix<F>
+where
+    F: FloatSample,
+{
+    /// Create a new, silent **Mix**.
+    #[inline]
+    pub fn new() -> Self {
+        Mix {
+            sum: F::EQUILIBRIUM,
+        }
+    }
+
+    /// Add the given sample of any `Sample` type to the mix.
+    #[inline]
+    pub fn add<S>(&mut self, sample: S)
+    where
+        S: Sample,
+    {
+        self.sum = self.sum + to_float(sample);
+    }
+
+    /// Add the given sample of any `Sample` type to the mix, scaled by the given amplitude.
+    #[inline]
+    pub fn add_amp<S>(&mut self, sample: S, amp: F)
+    where
+        S: Sample,
+    {
+        self.sum = self.sum + to_float::<S, F>(sample) * amp;
+    }
+
+    /// The unclamped sum of all samples added so far.
+    #[inline]
+    pub fn sum(&self) -> F {
+        self.sum
+    }
+
+    /// Convert the sum to the target `Sample` type, saturating rather than overflowing.
+    ///
+    /// See `Sample::saturating_from_float` for details on saturation and rounding.
+    #[inline]
+    pub fn to_sample<T>(&self) -> T
+    where
+        T: Sample,
+    {
+        T::saturating_from_float(self.sum)
+    }
+
+    /// Reset the sum to silence, e.g. to re-use the **Mix** for the next frame.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.sum = F::EQUILIBRIUM;
+    }
+}
+
+impl<F> Default for Mix<F>
+where
+    F: FloatSample,
+{
+    fn default() -> Self {
+        Mix::new()
+    }
+}
+
+impl<F, S> Extend<S> for Mix<F>
+where
+    F: FloatSample,
+    S: Sample,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = S>,
+    {
+        for sample in iter {
+            self.add(sample);
+        }
+    }
+}
+
+// Convert any sample to the accumulator type via `f64`, as in `Sample::add_sample`.
+#[inline]
+fn to_float<S, F>(sample: S) -> F
+where
+    S: Sample,
+    F: FloatSample,
+{
+    sample.to_float_sample().to_sample::<f64>().to_sample()
+}
@@ -1,9 +1,11 @@
 //! A collection of custom, non-std **Sample** types.
 
+pub use self::alaw::ALaw;
 pub use self::i11::I11;
 pub use self::i20::I20;
 pub use self::i24::I24;
 pub use self::i48::I48;
+pub use self::mulaw::MuLaw;
 pub use self::u11::U11;
 pub use self::u20::U20;
 pub use self::u24::U24;
@@ -282,3 +284,145 @@ pub mod u48 {
     new_sample_type!(U48: i64, eq: 140_737_488_355_328, min: 0, max: 281_474_976_710_655, total: 281_474_976_710_656,
                      from: u8, u16, {U20:i32}, {U24:i32}, u32);
 }
+
+macro_rules! companded_sample_type {
+    ($T:ident, eq: $EQ:expr) => {
+        pub const EQUILIBRIUM: $T = $T($EQ);
+
+        /// A companded sample, stored as its 8-bit encoding.
+        ///
+        /// Every 8-bit value is a valid encoding. Comparisons are performed on the decoded linear
+        /// values.
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+        pub struct $T(u8);
+
+        impl $T {
+            /// Construct a sample from its 8-bit encoding.
+            #[inline]
+            pub fn new(encoded: u8) -> Self {
+                $T(encoded)
+            }
+
+            /// Return the 8-bit encoding of the sample.
+            #[inline]
+            pub fn inner(self) -> u8 {
+                self.0
+            }
+
+            /// Compand the given 16-bit linear sample.
+            #[inline]
+            pub fn from_linear(s: i16) -> Self {
+                $T(encode(s))
+            }
+
+            /// Expand the sample to a 16-bit linear sample.
+            #[inline]
+            pub fn to_linear(self) -> i16 {
+                decode(self.0)
+            }
+        }
+
+        impl Default for $T {
+            fn default() -> Self {
+                EQUILIBRIUM
+            }
+        }
+
+        impl PartialOrd for $T {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $T {
+            #[inline]
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                self.to_linear().cmp(&other.to_linear())
+            }
+        }
+    };
+}
+
+/// The ITU-T G.711 A-law companded sample type.
+pub mod alaw {
+    companded_sample_type!(ALaw, eq: 0xD5);
+
+    // The upper bound of each segment of the 13-bit magnitude.
+    const SEG_END: [i16; 8] = [0x1F, 0x3F, 0x7F, 0xFF, 0x1FF, 0x3FF, 0x7FF, 0xFFF];
+
+    /// Encode a 16-bit linear sample as an 8-bit A-law sample.
+    pub fn encode(s: i16) -> u8 {
+        // A-law operates on 13-bit samples.
+        let mut s = s >> 3;
+        let mask = if s >= 0 {
+            0xD5
+        } else {
+            s = -s - 1;
+            0x55
+        };
+        let seg = match SEG_END.iter().position(|&end| s <= end) {
+            Some(seg) => seg as u8,
+            None => return 0x7F ^ mask,
+        };
+        let quant = (if seg < 2 { s >> 1 } else { s >> seg } & 0x0F) as u8;
+        ((seg << 4) | quant) ^ mask
+    }
+
+    /// Decode an 8-bit A-law sample to a 16-bit linear sample.
+    pub fn decode(a: u8) -> i16 {
+        let a = a ^ 0x55;
+        let mut t = ((a & 0x0F) as i16) << 4;
+        let seg = (a & 0x70) >> 4;
+        match seg {
+            0 => t += 8,
+            1 => t += 0x108,
+            _ => t = (t + 0x108) << (seg - 1),
+        }
+        if a & 0x80 != 0 {
+            t
+        } else {
+            -t
+        }
+    }
+}
+
+/// The ITU-T G.711 µ-law companded sample type.
+pub mod mulaw {
+    companded_sample_type!(MuLaw, eq: 0xFF);
+
+    const BIAS: i32 = 0x84;
+    const CLIP: i32 = 32_635;
+
+    /// Encode a 16-bit linear sample as an 8-bit µ-law sample.
+    pub fn encode(s: i16) -> u8 {
+        let mut s = s as i32;
+        let sign = if s < 0 {
+            s = -s;
+            0x80
+        } else {
+            0
+        };
+        let s = s.min(CLIP) + BIAS;
+        // The exponent is the position of the highest set bit above the 8th.
+        let mut exponent = 7;
+        while exponent > 0 && s & (0x80 << exponent) == 0 {
+            exponent -= 1;
+        }
+        let mantissa = (s >> (exponent + 3)) & 0x0F;
+        !(sign | (exponent << 4) | mantissa) as u8
+    }
+
+    /// Decode an 8-bit µ-law sample to a 16-bit linear sample.
+    pub fn decode(u: u8) -> i16 {
+        let u = !u;
+        let exponent = (u >> 4) & 0x07;
+        let mantissa = (u & 0x0F) as i32;
+        let s = (((mantissa << 3) + BIAS) << exponent) - BIAS;
+        if u & 0x80 != 0 {
+            -s as i16
+        } else {
+            s as i16
+        }
+    }
+}
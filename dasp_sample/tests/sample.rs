@@ -0,0 +1,50 @@
+use dasp_sample::types::{i24, u48};
+use dasp_sample::{Mix, Sample, I24, U48};
+
+#[test]
+fn test_add_sample_mixed_types() {
+    // -0.25 as an `i16` plus 0.5 as an `f32`.
+    assert_eq!((-8_192_i16).add_sample(0.5f32), 8_192);
+    // And the other way around.
+    assert_eq!(0.5f32.add_sample(-8_192_i16), 0.25);
+}
+
+#[test]
+fn test_add_sample_saturates() {
+    assert_eq!(i16::MAX.add_sample(i16::MAX), i16::MAX);
+    assert_eq!(i16::MIN.add_sample(-1.0f64), i16::MIN);
+    assert_eq!(I24::new(8_000_000).unwrap().add_sample(0.5f32), i24::MAX);
+    assert_eq!(U48::EQUILIBRIUM.add_sample(-2.0f64), u48::MIN);
+    assert!(0.75f32.add_sample(0.75f64) < 1.0);
+}
+
+#[test]
+fn test_mul_sample_mixed_types() {
+    assert_eq!(16_384_i16.mul_sample(-0.5f64), -8_192);
+    assert_eq!(0.5f32.mul_sample(192_u8), 0.25);
+}
+
+#[test]
+fn test_mix_saturates_once() {
+    // One hundred half-scale samples sum far beyond full scale.
+    let mut mix = Mix::<f64>::new();
+    mix.extend(std::iter::repeat_n(16_384_i16, 100));
+    assert_eq!(mix.sum(), 50.0);
+    assert_eq!(mix.to_sample::<i16>(), i16::MAX);
+
+    // The intermediate sum is not clipped, so cancelling samples restore the exact result.
+    mix.extend(std::iter::repeat_n(-16_384_i16, 99));
+    assert_eq!(mix.to_sample::<i16>(), 16_384);
+
+    // Whereas saturating after every addition clips along the way.
+    let clipped = (0..100)
+        .map(|_| 16_384_i16)
+        .chain((0..99).map(|_| -16_384_i16))
+        .fold(0i16, |acc, s| acc.add_sample(s));
+    assert_ne!(clipped, 16_384);
+
+    mix.clear();
+    mix.add_amp(1.0f32, 0.25);
+    mix.add(64u8);
+    assert_eq!(mix.to_sample::<f32>(), -0.25);
+}
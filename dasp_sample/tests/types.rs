@@ -80,3 +80,60 @@ test_type!(I24, i24);
 test_type!(U24, u24);
 test_type!(I48, i48);
 test_type!(U48, u48);
+
+/// Expands to a unique module with round-trip tests for the given companded sample type.
+///
+/// `max_err` maps the magnitude of a 16-bit linear sample to the maximum expected quantization
+/// error for that sample.
+macro_rules! test_companded_type {
+    ($T:ident, $mod_name:ident, max_err: $max_err:expr) => {
+        mod $mod_name {
+            use dasp_sample::types::$mod_name::{self, $T};
+            use dasp_sample::Sample;
+
+            #[test]
+            fn round_trip_i16() {
+                let max_err: fn(i32) -> i32 = $max_err;
+                for s in (i16::MIN..=i16::MAX).step_by(7) {
+                    let companded: $T = s.to_sample();
+                    let linear: i16 = companded.to_sample();
+                    let err = (linear as i32 - s as i32).abs();
+                    assert!(err <= max_err((s as i32).abs()), "{} -> {}", s, linear);
+                }
+            }
+
+            #[test]
+            fn round_trip_encoded() {
+                // Every encoding should survive being expanded and companded again.
+                for b in 0..=255u8 {
+                    let companded = $T::new(b);
+                    let linear: i16 = companded.to_sample();
+                    assert_eq!(linear, $T::from_linear(linear).to_linear());
+                }
+            }
+
+            #[test]
+            fn f32() {
+                assert_eq!($T::EQUILIBRIUM, $mod_name::EQUILIBRIUM);
+                assert_eq!($T::from_sample(0.0f32), $mod_name::EQUILIBRIUM);
+                let companded: $T = 0.5f32.to_sample();
+                let linear: f32 = companded.to_sample();
+                assert!((linear - 0.5).abs() < 0.02);
+                let companded: $T = (-0.25f32).to_sample();
+                let linear: f32 = companded.to_sample();
+                assert!((linear + 0.25).abs() < 0.01);
+            }
+
+            #[test]
+            fn ordering() {
+                let a: $T = (-0.5f32).to_sample();
+                let b: $T = 0.0f32.to_sample();
+                let c: $T = 0.5f32.to_sample();
+                assert!(a < b && b < c);
+            }
+        }
+    };
+}
+
+test_companded_type!(ALaw, alaw, max_err: |s| s / 32 + 16);
+test_companded_type!(MuLaw, mulaw, max_err: |s| (s + 0x84) / 16 + 1);
@@ -14,6 +14,8 @@
 //! - [gen](./fn.gen.html) for generating frames of type F from some `Fn() -> F`.
 //! - [gen_mut](./fn.gen_mut.html) for generating frames of type F from some `FnMut() -> F`.
 //! - [from_iter](./fn.from_iter.html) for converting an iterator yielding frames to a signal.
+//! - [from_fn](./fn.from_fn.html) for generating a possibly finite signal from some state and an
+//!   `FnMut(&mut State) -> Option<F>`.
 //! - [from_interleaved_samples_iter](./fn.from_interleaved_samples_iter.html) for converting an
 //! iterator yielding interleaved samples to a signal.
 //!
@@ -45,13 +47,15 @@
 
 #[cfg(not(feature = "std"))]
 extern crate alloc;
+#[cfg(not(feature = "std"))]
+use alloc::vec;
 
 use core;
 use core::cell::RefCell;
 use dasp_frame::Frame;
 use dasp_interpolate::Interpolator;
 use dasp_ring_buffer as ring_buffer;
-use dasp_sample::{Duplex, Sample};
+use dasp_sample::{Duplex, Sample, ToSample};
 use interpolate::Converter;
 
 pub mod interpolate;
@@ -73,6 +77,11 @@ type Rc<T> = alloc::rc::Rc<T>;
 #[cfg(feature = "std")]
 type Rc<T> = std::rc::Rc<T>;
 
+#[cfg(not(feature = "std"))]
+type Vec<T> = alloc::vec::Vec<T>;
+#[cfg(feature = "std")]
+type Vec<T> = std::vec::Vec<T>;
+
 /// Types that yield `Frame`s of a one-or-more-channel PCM signal.
 ///
 /// For example, `Signal` allows us to add two signals, modulate a signal's amplitude by another
@@ -247,6 +256,44 @@ pub trait Signal {
         }
     }
 
+    /// A signal adaptor similar to `map` that holds some internal state.
+    ///
+    /// Analogous to `Iterator::scan`, `scan` takes an initial `state` and a function `f`. Each
+    /// frame yielded by `self` is passed to `f` along with a mutable reference to the state. The
+    /// frame returned by `f` is yielded by the resulting signal.
+    ///
+    /// This is useful for writing arbitrary stateful per-frame transformations, e.g. integrators or
+    /// simple filters, without having to implement a whole new signal type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dasp_signal::{self as signal, Signal};
+    ///
+    /// fn main() {
+    ///     let frames = [1.0, 2.0, 3.0, 4.0];
+    ///     let signal = signal::from_iter(frames.iter().cloned());
+    ///     let running_sum = signal.scan(0.0, |sum, f| {
+    ///         *sum += f;
+    ///         *sum
+    ///     });
+    ///     assert_eq!(running_sum.take(4).collect::<Vec<_>>(), vec![1.0, 3.0, 6.0, 10.0]);
+    /// }
+    /// ```
+    fn scan<St, M, F>(self, state: St, f: M) -> Scan<Self, St, M, F>
+    where
+        Self: Sized,
+        M: FnMut(&mut St, Self::Frame) -> F,
+        F: Frame,
+    {
+        Scan {
+            signal: self,
+            state,
+            f,
+            frame: core::marker::PhantomData,
+        }
+    }
+
     /// Provides an iterator that yields the sum of the frames yielded by both `other` and `self`
     /// in lock-step.
     ///
@@ -307,6 +354,42 @@ pub trait Signal {
         MulAmp { a: self, b: other }
     }
 
+    /// Provides a signal that yields the frames of `self` while the `control` signal is above
+    /// the given `threshold`, and equilibrium otherwise.
+    ///
+    /// Both signals are advanced in lock-step, such that frames of `self` that occur while the
+    /// gate is closed are discarded. The returned signal is exhausted when either signal is
+    /// exhausted.
+    ///
+    /// This is useful for rhythmic gating, or for keying a signal by the envelope of another.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dasp_signal::{self as signal, Signal};
+    ///
+    /// fn main() {
+    ///     let frames = [0.1, 0.2, 0.3, 0.4];
+    ///     let control = [1.0, 0.0, 0.6, 0.5];
+    ///     let signal = signal::from_iter(frames.iter().cloned());
+    ///     let control = signal::from_iter(control.iter().cloned());
+    ///     let gated: Vec<_> = signal.while_gate(control, 0.5).until_exhausted().collect();
+    ///     assert_eq!(gated, vec![0.1, 0.0, 0.3, 0.0]);
+    /// }
+    /// ```
+    #[inline]
+    fn while_gate<C>(self, control: C, threshold: f64) -> WhileGate<Self, C>
+    where
+        Self: Sized,
+        C: Signal<Frame = f64>,
+    {
+        WhileGate {
+            signal: self,
+            control,
+            threshold,
+        }
+    }
+
     /// Provides an iterator that offsets the amplitude of every channel in each frame of the
     /// signal by some sample value and yields the resulting frames.
     ///
@@ -422,6 +505,87 @@ pub trait Signal {
         }
     }
 
+    /// Produces a new `Signal` that applies the given amplitude transfer function to the sample
+    /// of each channel in every `Frame` yielded by `self`.
+    ///
+    /// This is useful for arbitrary per-sample amplitude mappings such as waveshaping.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dasp_signal::{self as signal, Signal};
+    ///
+    /// fn main() {
+    ///     let frames = [[0.25f32, -1.0], [-0.5, 2.0]];
+    ///     let signal = signal::from_iter(frames.iter().cloned());
+    ///     let clipped: Vec<_> = signal.map_amp(|s| s.max(-0.5).min(0.5)).take(2).collect();
+    ///     assert_eq!(clipped, vec![[0.25, -0.5], [-0.5, 0.5]]);
+    /// }
+    /// ```
+    #[inline]
+    fn map_amp<M>(self, map: M) -> MapAmp<Self, M>
+    where
+        Self: Sized,
+        M: FnMut(<Self::Frame as Frame>::Sample) -> <Self::Frame as Frame>::Sample,
+    {
+        MapAmp { signal: self, map }
+    }
+
+    /// Encodes a stereo signal from left/right to mid/side.
+    ///
+    /// The first channel of each yielded frame is the mid component `(l + r) / 2` and the second
+    /// is the side component `(l - r) / 2`. This allows for processing the mid and side components
+    /// independently, e.g. for widening, before decoding back to left/right via `from_mid_side`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dasp_signal::{self as signal, Signal};
+    ///
+    /// fn main() {
+    ///     let frames = [[1.0, 0.5], [0.25, -0.25]];
+    ///     let signal = signal::from_iter(frames.iter().cloned());
+    ///     let ms: Vec<_> = signal.to_mid_side().take(2).collect();
+    ///     assert_eq!(ms, vec![[0.75, 0.25], [0.0, 0.25]]);
+    /// }
+    /// ```
+    #[inline]
+    fn to_mid_side<T>(self) -> ToMidSide<Self>
+    where
+        Self: Sized + Signal<Frame = [T; 2]>,
+        T: Sample,
+    {
+        ToMidSide { signal: self }
+    }
+
+    /// Decodes a stereo signal from mid/side to left/right.
+    ///
+    /// The first channel of each frame yielded by `self` is treated as the mid component and the
+    /// second as the side component. The left channel is `mid + side` and the right channel is
+    /// `mid - side`, such that this is the inverse of `to_mid_side`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dasp_signal::{self as signal, Signal};
+    ///
+    /// fn main() {
+    ///     let frames = [[0.75, 0.25], [0.0, 0.25]];
+    ///     let signal = signal::from_iter(frames.iter().cloned());
+    ///     let lr: Vec<_> = signal.from_mid_side().take(2).collect();
+    ///     assert_eq!(lr, vec![[1.0, 0.5], [0.25, -0.25]]);
+    /// }
+    /// ```
+    #[inline]
+    #[allow(clippy::wrong_self_convention)]
+    fn from_mid_side<T>(self) -> FromMidSide<Self>
+    where
+        Self: Sized + Signal<Frame = [T; 2]>,
+        T: Sample,
+    {
+        FromMidSide { signal: self }
+    }
+
     /// Multiplies the rate at which frames of `self` are yielded by the given `signal`.
     ///
     /// This happens by wrapping `self` in a `rate::Converter` and calling `set_playback_hz_scale`
@@ -536,6 +700,106 @@ pub trait Signal {
         }
     }
 
+    /// Prepends the given number of silent frames to the `Signal`.
+    ///
+    /// This is equivalent to `delay` and is provided for symmetry with `pad_end`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dasp_signal::{self as signal, Signal};
+    ///
+    /// fn main() {
+    ///     let frames = [0.2, 0.4];
+    ///     let signal = signal::from_iter(frames.iter().cloned());
+    ///     let padded: Vec<_> = signal.pad_start(2).until_exhausted().collect();
+    ///     assert_eq!(padded, vec![0.0, 0.0, 0.2, 0.4]);
+    /// }
+    /// ```
+    fn pad_start(self, n_frames: usize) -> Delay<Self>
+    where
+        Self: Sized,
+    {
+        self.delay(n_frames)
+    }
+
+    /// Appends the given number of silent frames once the `Signal` is exhausted.
+    ///
+    /// The padding begins once `is_exhausted` returns `true` for the source signal. The returned
+    /// signal is exhausted once all of the padding frames have been yielded. For infinite signals,
+    /// no padding is ever yielded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dasp_signal::{self as signal, Signal};
+    ///
+    /// fn main() {
+    ///     let frames = [0.2, 0.4];
+    ///     let signal = signal::from_iter(frames.iter().cloned());
+    ///     let padded: Vec<_> = signal.pad_end(2).until_exhausted().collect();
+    ///     assert_eq!(padded, vec![0.2, 0.4, 0.0, 0.0]);
+    /// }
+    /// ```
+    fn pad_end(self, n_frames: usize) -> PadEnd<Self>
+    where
+        Self: Sized,
+    {
+        PadEnd {
+            signal: self,
+            n_frames,
+        }
+    }
+
+    /// Reduces the rate at which frames are yielded by the given integer `factor`, applying an
+    /// anti-aliasing low-pass filter before keeping every `factor`th frame.
+    ///
+    /// Naively discarding frames causes content above the new Nyquist frequency to fold back into
+    /// the audible band as aliasing. To avoid this, each channel is first passed through an
+    /// 8th-order Butterworth low-pass filter with a cutoff at 90% of the new Nyquist frequency.
+    ///
+    /// The filter order is a trade-off. A higher order gives a steeper transition band and so
+    /// allows a cutoff closer to the new Nyquist frequency, at the cost of more computation per
+    /// frame and more phase distortion and ringing near the cutoff. The 8th-order filter rolls
+    /// off at roughly 48dB per octave, attenuating content at 1.5x the new Nyquist frequency by
+    /// more than 30dB. The filter is causal, so the output is delayed by its group delay.
+    ///
+    /// A `factor` of `1` yields the frames of `self` unchanged.
+    ///
+    /// **Panics** if `factor` is `0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dasp_signal::{self as signal, Signal};
+    ///
+    /// fn main() {
+    ///     let signal = signal::rate(48_000.0).const_hz(100.0).sine();
+    ///     let downsampled: Vec<f64> = signal.downsample(4).take(12_000).collect();
+    ///     let peak = downsampled.iter().fold(0.0f64, |peak, s| peak.max(s.abs()));
+    ///     assert!(peak > 0.99 && peak < 1.01);
+    /// }
+    /// ```
+    fn downsample(self, factor: usize) -> Downsample<Self>
+    where
+        Self: Sized,
+    {
+        assert!(factor > 0, "the downsampling factor must be greater than 0");
+        // The angular cutoff frequency in radians per input frame.
+        let w0 = core::f64::consts::PI * DOWNSAMPLE_CUTOFF / factor as f64;
+        let filters = core::array::from_fn(|section| {
+            // The Q of each section of the cascade, given by the Butterworth pole angles.
+            let n = DOWNSAMPLE_SECTIONS as f64 * 2.0;
+            let theta = core::f64::consts::PI * (2 * section + 1) as f64 / (2.0 * n);
+            Biquad::low_pass(w0, 1.0 / (2.0 * ops::f64::cos(theta)))
+        });
+        Downsample {
+            signal: self,
+            factor,
+            filters,
+        }
+    }
+
     /// Converts a `Signal` into a type that yields the interleaved `Sample`s.
     ///
     /// # Example
@@ -562,6 +826,40 @@ pub trait Signal {
         }
     }
 
+    /// Converts a finite `Signal` into an `Iterator` yielding each channel sample of each frame in
+    /// interleaved order, converted to the sample type `T`.
+    ///
+    /// This is useful for writing a signal to a file or an audio API that expects interleaved
+    /// samples of a specific format such as `i16`.
+    ///
+    /// The iterator returns `None` once the signal is exhausted and all channels of the last frame
+    /// have been yielded. For infinite signals, use `Iterator::take` to limit the output.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dasp_signal::{self as signal, Signal};
+    ///
+    /// fn main() {
+    ///     let frames = [[0.5f32, -0.5], [0.0, 1.0]];
+    ///     let signal = signal::from_iter(frames.iter().cloned());
+    ///     let samples: Vec<i16> = signal.to_samples().collect();
+    ///     assert_eq!(samples, vec![16384, -16384, 0, 32767]);
+    /// }
+    /// ```
+    fn to_samples<T>(self) -> ToSamples<Self, T>
+    where
+        Self: Sized,
+        T: Sample,
+        <Self::Frame as Frame>::Sample: ToSample<T>,
+    {
+        ToSamples {
+            signal: self,
+            current_frame: None,
+            sample: core::marker::PhantomData,
+        }
+    }
+
     /// Clips the amplitude of each channel in each `Frame` yielded by `self` to the given
     /// threshold amplitude.
     ///
@@ -587,6 +885,45 @@ pub trait Signal {
         }
     }
 
+    /// Quantizes each sample in each `Frame` yielded by `self` to the given bit depth, e.g. for
+    /// lo-fi bit-crushing effects.
+    ///
+    /// Samples are mapped to the range `-1.0..1.0` and rounded to the nearest of `2^bits` evenly
+    /// spaced levels, from `-1.0` up to one step below `1.0`. Samples outside of this range are
+    /// clamped to the nearest level. The result is converted back to the original sample format.
+    ///
+    /// If `dither` is `Some(seed)`, triangular (TPDF) dither with a peak amplitude of one
+    /// quantization step is added to each sample before rounding. This decorrelates the
+    /// quantization error from the signal at the cost of a slightly raised noise floor. The dither
+    /// is generated by a `signal::noise` generator initialised with the given `seed`, so the same
+    /// seed always produces the same output for the same input.
+    ///
+    /// `bits` is clamped to the range `1..=32`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dasp_signal::{self as signal, Signal};
+    ///
+    /// fn main() {
+    ///     let frames = [0.3, -0.3, 0.9];
+    ///     let signal = signal::from_iter(frames.iter().cloned());
+    ///     let crushed: Vec<f64> = signal.clip_to_n_bits(2, None).take(3).collect();
+    ///     assert_eq!(crushed, vec![0.5, -0.5, 0.5]);
+    /// }
+    /// ```
+    fn clip_to_n_bits(self, bits: u32, dither: Option<u64>) -> ClipToNBits<Self>
+    where
+        Self: Sized,
+    {
+        let levels = (1u64 << bits.clamp(1, 32)) as f64;
+        ClipToNBits {
+            signal: self,
+            step: 2.0 / levels,
+            dither: dither.map(noise),
+        }
+    }
+
     /// Create a new `Signal` that calls the enclosing function on each iteration.
     ///
     /// # Example
@@ -619,6 +956,42 @@ pub trait Signal {
         }
     }
 
+    /// Checks that every sample yielded by `self` is finite, panicking with the index of the frame
+    /// at which a `NaN` or infinite sample is first encountered.
+    ///
+    /// This is useful during development for catching unstable filters or feedback loops at the
+    /// point they blow up, rather than once the non-finite values have propagated through the rest
+    /// of the signal chain. Frames are yielded unchanged.
+    ///
+    /// Use `AssertFinite::panic_on_non_finite` to instead record the index of the first
+    /// non-finite frame, which may then be retrieved via `AssertFinite::first_non_finite`. With
+    /// the **std** feature enabled, a message is also printed to stderr, once.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dasp_signal::{self as signal, Signal};
+    ///
+    /// fn main() {
+    ///     let frames = [0.5, 0.25, std::f64::NAN, 0.0];
+    ///     let signal = signal::from_iter(frames.iter().cloned());
+    ///     let mut checked = signal.assert_finite().panic_on_non_finite(false);
+    ///     assert_eq!(checked.by_ref().until_exhausted().count(), 4);
+    ///     assert_eq!(checked.first_non_finite(), Some(2));
+    /// }
+    /// ```
+    fn assert_finite(self) -> AssertFinite<Self>
+    where
+        Self: Sized,
+    {
+        AssertFinite {
+            signal: self,
+            index: 0,
+            panic_on_non_finite: true,
+            first_non_finite: None,
+        }
+    }
+
     /// Forks `Self` into two signals that produce the same frames.
     ///
     /// The given `ring_buffer` must be empty to ensure correct behaviour.
@@ -729,6 +1102,71 @@ pub trait Signal {
         UntilExhausted { signal: self }
     }
 
+    /// Converts the `Signal` into an `Iterator` yielding fixed-size blocks of `N` frames until
+    /// `signal.is_exhausted()` returns `true`.
+    ///
+    /// This allows for applying block-based processing, e.g. an FFT, in the middle of a chain of
+    /// per-frame signals. Use `signal::from_blocks` to convert the blocks back into a `Signal`.
+    ///
+    /// If the signal becomes exhausted part way through a block, the remainder of the final block
+    /// is filled with `Frame::EQUILIBRIUM`. If the signal becomes exhausted exactly at the end of
+    /// a block, no further block is yielded. As a result, flattening the blocks yields the frames
+    /// of the signal followed by less than `N` frames of silence, which may be removed via `take`
+    /// if the original length is known.
+    ///
+    /// Infinite signals yield blocks indefinitely. If `N` is `0`, no blocks are yielded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dasp_signal::{self as signal, Signal};
+    ///
+    /// fn main() {
+    ///     let frames = [1, 2, 3, 4, 5];
+    ///     let signal = signal::from_iter(frames.iter().cloned());
+    ///     let blocks: Vec<_> = signal.into_blocks::<2>().collect();
+    ///     assert_eq!(blocks, vec![[1, 2], [3, 4], [5, 0]]);
+    ///
+    ///     let flattened = signal::from_blocks(blocks);
+    ///     assert_eq!(flattened.until_exhausted().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5, 0]);
+    /// }
+    /// ```
+    fn into_blocks<const N: usize>(self) -> IntoBlocks<Self, N>
+    where
+        Self: Sized,
+    {
+        IntoBlocks { signal: self }
+    }
+
+    /// Converts the `Signal` into an `Iterator` yielding each frame along with its absolute
+    /// position, until the `signal.is_exhausted()` returns `true`.
+    ///
+    /// The position is the number of frames yielded before the frame, beginning at `0`. This is
+    /// useful for analysis and instrumentation where per-position logic would otherwise require a
+    /// manual counter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dasp_signal::{self as signal, Signal};
+    ///
+    /// fn main() {
+    ///     let frames = [0.1, 0.2, 0.3];
+    ///     let signal = signal::from_iter(frames.iter().cloned());
+    ///     let pairs: Vec<_> = signal.enumerate().collect();
+    ///     assert_eq!(pairs, vec![(0, 0.1), (1, 0.2), (2, 0.3)]);
+    /// }
+    /// ```
+    fn enumerate(self) -> Enumerate<Self>
+    where
+        Self: Sized,
+    {
+        Enumerate {
+            signal: self,
+            position: 0,
+        }
+    }
+
     /// Buffers the signal using the given ring buffer.
     ///
     /// When `next` is called on the returned signal, it will first check if the ring buffer is
@@ -784,10 +1222,14 @@ pub trait Signal {
         }
     }
 
-    /// Borrows a Signal rather than consuming it.
+    /// Collects all frames of a finite signal and yields them in reverse order.
     ///
-    /// This is useful to allow applying signal adaptors while still retaining ownership of the
-    /// original signal.
+    /// The whole signal is consumed up-front and stored in a `Vec`, so memory use is proportional
+    /// to the length of the signal. This requires that the signal eventually becomes exhausted.
+    /// The returned signal becomes exhausted once all collected frames have been yielded.
+    ///
+    /// **Panics** if `self` is not exhausted after yielding `max_frames` frames. This guards
+    /// against attempting to reverse an infinite signal.
     ///
     /// # Example
     ///
@@ -795,11 +1237,172 @@ pub trait Signal {
     /// use dasp_signal::{self as signal, Signal};
     ///
     /// fn main() {
-    ///     let frames = [0, 1, 2, 3, 4];
-    ///     let mut signal = signal::from_iter(frames.iter().cloned());
-    ///     assert_eq!(signal.next(), 0);
-    ///     assert_eq!(signal.by_ref().take(2).collect::<Vec<_>>(), vec![1, 2]);
-    ///     assert_eq!(signal.next(), 3);
+    ///     let frames = [0.1, 0.2, 0.3, 0.4];
+    ///     let signal = signal::from_iter(frames.iter().cloned());
+    ///     let reversed: Vec<_> = signal.reverse(1024).until_exhausted().collect();
+    ///     assert_eq!(reversed, vec![0.4, 0.3, 0.2, 0.1]);
+    /// }
+    /// ```
+    fn reverse(self, max_frames: usize) -> Reverse<Self::Frame>
+    where
+        Self: Sized,
+    {
+        let mut frames = Vec::new();
+        for frame in self.until_exhausted() {
+            assert!(
+                frames.len() < max_frames,
+                "`Signal::reverse` requires a finite signal of at most `max_frames` frames"
+            );
+            frames.push(frame);
+        }
+        Reverse { frames }
+    }
+
+    /// Yields the frames of a finite signal `n` times in total, then becomes exhausted.
+    ///
+    /// Frames are yielded from `self` as they are produced during the first pass and are stored
+    /// in a `Vec` to be replayed for each of the remaining passes. Memory use is therefore
+    /// proportional to the length of the signal and the signal must eventually become exhausted.
+    /// No seeking of the source is required.
+    ///
+    /// If `n` is `0` or `self` is already exhausted, the returned signal is exhausted immediately.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dasp_signal::{self as signal, Signal};
+    ///
+    /// fn main() {
+    ///     let frames = [0.1, 0.2];
+    ///     let signal = signal::from_iter(frames.iter().cloned());
+    ///     let repeated: Vec<_> = signal.repeat_n(3).until_exhausted().collect();
+    ///     assert_eq!(repeated, vec![0.1, 0.2, 0.1, 0.2, 0.1, 0.2]);
+    /// }
+    /// ```
+    fn repeat_n(self, n: usize) -> RepeatN<Self>
+    where
+        Self: Sized,
+    {
+        RepeatN {
+            signal: self,
+            frames: Vec::new(),
+            recording: n > 0,
+            repeats: n,
+            index: 0,
+        }
+    }
+
+    /// Changes the duration of the signal by the given `ratio` without changing its pitch.
+    ///
+    /// A `ratio` of `2.0` yields a signal twice as long as `self`, while a `ratio` of `0.5` yields
+    /// a signal half as long.
+    ///
+    /// Uses waveform similarity overlap-add (WSOLA). The output is built from Hann-windowed
+    /// segments of 1024 frames overlapping by half. Each segment is read from around the input
+    /// position corresponding to its output position, shifted by up to 256 frames to the position
+    /// most similar to the natural continuation of the previous segment, so that overlapping
+    /// segments add in phase. Only a few segments of `self` are buffered at a time, so infinite
+    /// signals may also be stretched.
+    ///
+    /// The segment and search lengths suit periodic content with fundamentals above roughly 100Hz
+    /// at typical sample rates. Transients are smeared across or repeated within neighbouring
+    /// segments, so that when stretching, sharp attacks such as drum hits may be heard as softened
+    /// or doubled, and when compressing, very short events may be skipped.
+    ///
+    /// Once `self` is exhausted, the returned signal becomes exhausted after yielding the number
+    /// of frames of `self` multiplied by `ratio`, rounded to the nearest frame.
+    ///
+    /// **Panics** if `ratio` is not finite and greater than `0.0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dasp_signal::{self as signal, Signal};
+    ///
+    /// fn main() {
+    ///     let sine = signal::rate(48_000.0).const_hz(440.0).sine().take(4_800);
+    ///     let stretched = signal::from_iter(sine).time_stretch(2.0);
+    ///     assert_eq!(stretched.until_exhausted().count(), 9_600);
+    /// }
+    /// ```
+    fn time_stretch(self, ratio: f64) -> TimeStretch<Self>
+    where
+        Self: Sized,
+    {
+        assert!(
+            ratio.is_finite() && ratio > 0.0,
+            "the time stretch ratio must be finite and greater than 0.0"
+        );
+        TimeStretch {
+            signal: self,
+            ratio,
+            input: Vec::new(),
+            input_start: 0,
+            segment: 0,
+            last_position: 0,
+            overlap: Vec::new(),
+            ready: Vec::new(),
+            ready_index: 0,
+            yielded: 0,
+        }
+    }
+
+    /// Repairs short dropouts by linearly interpolating across runs of NaN or silent frames.
+    ///
+    /// A frame is considered part of a gap if any of its channels is NaN, or if all of its
+    /// channels are exactly equilibrium. Each gap of at most `max_gap` frames that lies between
+    /// two valid frames is replaced by a linear interpolation between those frames. Gaps longer
+    /// than `max_gap` frames, along with gaps at the start or end of the signal, are yielded as
+    /// silence.
+    ///
+    /// Note that intentional digital silence of at most `max_gap` frames is also interpolated
+    /// across.
+    ///
+    /// Up to `max_gap + 1` frames of `self` are buffered in order to find the end of each gap.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dasp_signal::{self as signal, Signal};
+    ///
+    /// fn main() {
+    ///     let frames = [0.0, 0.25, f64::NAN, f64::NAN, 1.0];
+    ///     let signal = signal::from_iter(frames.iter().cloned());
+    ///     let repaired: Vec<_> = signal.interpolate_gaps(2).until_exhausted().collect();
+    ///     assert_eq!(repaired, vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+    /// }
+    /// ```
+    fn interpolate_gaps(self, max_gap: usize) -> InterpolateGaps<Self>
+    where
+        Self: Sized,
+        <Self::Frame as Frame>::Sample: Duplex<f64>,
+    {
+        InterpolateGaps {
+            signal: self,
+            max_gap,
+            previous: None,
+            long_gap: false,
+            ready: Vec::new(),
+            ready_index: 0,
+        }
+    }
+
+    /// Borrows a Signal rather than consuming it.
+    ///
+    /// This is useful to allow applying signal adaptors while still retaining ownership of the
+    /// original signal.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dasp_signal::{self as signal, Signal};
+    ///
+    /// fn main() {
+    ///     let frames = [0, 1, 2, 3, 4];
+    ///     let mut signal = signal::from_iter(frames.iter().cloned());
+    ///     assert_eq!(signal.next(), 0);
+    ///     assert_eq!(signal.by_ref().take(2).collect::<Vec<_>>(), vec![1, 2]);
+    ///     assert_eq!(signal.next(), 3);
     ///     assert_eq!(signal.next(), 4);
     /// }
     /// ```
@@ -883,6 +1486,16 @@ pub struct ZipMap<S, O, M, F> {
     frame: core::marker::PhantomData<F>,
 }
 
+/// A signal that maps each frame of another signal to a new frame via a function with access to
+/// some mutable state.
+#[derive(Clone)]
+pub struct Scan<S, St, M, F> {
+    signal: S,
+    state: St,
+    f: M,
+    frame: core::marker::PhantomData<F>,
+}
+
 /// A type that wraps an Iterator and provides a `Signal` implementation for it.
 #[derive(Clone)]
 pub struct FromIterator<I>
@@ -893,6 +1506,26 @@ where
     next: Option<I::Item>,
 }
 
+/// A signal that yields each frame of an iterator of fixed-size blocks of frames.
+///
+/// See the `from_blocks` function.
+#[derive(Clone)]
+pub struct FromBlocks<I, F, const N: usize> {
+    blocks: I,
+    block: Option<[F; N]>,
+    index: usize,
+}
+
+/// A signal that generates frames from some state until the given function returns `None`.
+///
+/// See the `from_fn` function.
+#[derive(Clone)]
+pub struct FromFn<St, G, F> {
+    state: St,
+    gen: G,
+    next: Option<F>,
+}
+
 /// An iterator that converts an iterator of `Sample`s to an iterator of `Frame`s.
 #[derive(Clone)]
 pub struct FromInterleavedSamplesIterator<I, F>
@@ -976,6 +1609,14 @@ pub struct MulAmp<A, B> {
     b: B,
 }
 
+/// Yields the frames of `signal` while `control` is above `threshold`, and equilibrium otherwise.
+#[derive(Clone)]
+pub struct WhileGate<S, C> {
+    signal: S,
+    control: C,
+    threshold: f64,
+}
+
 /// Provides an iterator that offsets the amplitude of every channel in each frame of the
 /// signal by some sample value and yields the resulting frames.
 #[derive(Clone)]
@@ -1014,6 +1655,26 @@ pub struct ScaleAmpPerChannel<S, F> {
     amp_frame: F,
 }
 
+/// Applies an amplitude transfer function to the sample of each channel in every `Frame` yielded
+/// by `signal`.
+#[derive(Clone)]
+pub struct MapAmp<S, M> {
+    signal: S,
+    map: M,
+}
+
+/// Encodes the stereo frames yielded by `signal` from left/right to mid/side.
+#[derive(Clone)]
+pub struct ToMidSide<S> {
+    signal: S,
+}
+
+/// Decodes the stereo frames yielded by `signal` from mid/side to left/right.
+#[derive(Clone)]
+pub struct FromMidSide<S> {
+    signal: S,
+}
+
 /// Multiplies the rate at which frames of `self` are yielded by the given `signal`.
 ///
 /// This happens by wrapping `self` in a `rate::Converter` and calling `set_playback_hz_scale`
@@ -1038,6 +1699,45 @@ pub struct Delay<S> {
     n_frames: usize,
 }
 
+/// Yields silent frames once the source signal is exhausted.
+#[derive(Clone)]
+pub struct PadEnd<S> {
+    signal: S,
+    n_frames: usize,
+}
+
+/// Reduces the rate of the `signal` by an integer factor, applying an anti-aliasing low-pass
+/// filter before keeping every `factor`th frame.
+#[derive(Clone)]
+pub struct Downsample<S>
+where
+    S: Signal,
+{
+    signal: S,
+    factor: usize,
+    filters: [Biquad<<S::Frame as Frame>::Float>; DOWNSAMPLE_SECTIONS],
+}
+
+// The number of 2nd-order sections in the `Downsample` anti-aliasing filter.
+const DOWNSAMPLE_SECTIONS: usize = 4;
+
+// The `Downsample` filter cutoff as a fraction of the new Nyquist frequency.
+const DOWNSAMPLE_CUTOFF: f64 = 0.9;
+
+// A 2nd-order IIR filter section operating on each channel of a floating point frame.
+#[derive(Clone, Copy)]
+struct Biquad<F> {
+    b0: f64,
+    b1: f64,
+    b2: f64,
+    a1: f64,
+    a2: f64,
+    x1: F,
+    x2: F,
+    y1: F,
+    y2: F,
+}
+
 /// A signal that calls its enclosing function and returns the original value. The signal may
 /// mutate state.
 #[derive(Clone)]
@@ -1046,6 +1746,17 @@ pub struct Inspect<S, F> {
     inspect: F,
 }
 
+/// Checks that every sample yielded by a signal is finite.
+///
+/// See the `Signal::assert_finite` method.
+#[derive(Clone)]
+pub struct AssertFinite<S> {
+    signal: S,
+    index: usize,
+    panic_on_non_finite: bool,
+    first_non_finite: Option<usize>,
+}
+
 /// Converts a `Signal` to a type that yields the individual interleaved samples.
 pub struct IntoInterleavedSamples<S>
 where
@@ -1055,6 +1766,19 @@ where
     current_frame: <S::Frame as Frame>::Channels,
 }
 
+/// An `Iterator` yielding the interleaved samples of a finite signal, converted to the sample
+/// type `T`.
+///
+/// See the `Signal::to_samples` method.
+pub struct ToSamples<S, T>
+where
+    S: Signal,
+{
+    signal: S,
+    current_frame: Option<<S::Frame as Frame>::Channels>,
+    sample: core::marker::PhantomData<T>,
+}
+
 /// Converts the `IntoInterleavedSamples` into an `Iterator` that always returns `Some`.
 pub struct IntoInterleavedSamplesIterator<S>
 where
@@ -1072,6 +1796,29 @@ where
     signal: S,
 }
 
+/// Yields fixed-size blocks of frames from the signal until the `signal.is_exhausted()` returns
+/// `true`.
+///
+/// See the `Signal::into_blocks` method.
+#[derive(Clone)]
+pub struct IntoBlocks<S, const N: usize>
+where
+    S: Signal,
+{
+    signal: S,
+}
+
+/// Yields each frame from the signal along with its absolute position until the
+/// `signal.is_exhausted()` returns `true`.
+#[derive(Clone)]
+pub struct Enumerate<S>
+where
+    S: Signal,
+{
+    signal: S,
+    position: usize,
+}
+
 /// Clips samples in each frame yielded by `signal` to the given threshhold amplitude.
 #[derive(Clone)]
 pub struct ClipAmp<S>
@@ -1082,6 +1829,91 @@ where
     thresh: <<S::Frame as Frame>::Sample as Sample>::Signed,
 }
 
+/// Quantizes samples in each frame yielded by `signal` to a given bit depth.
+///
+/// See the `Signal::clip_to_n_bits` method.
+#[derive(Clone)]
+pub struct ClipToNBits<S> {
+    signal: S,
+    step: f64,
+    dither: Option<Noise>,
+}
+
+/// Yields the frames of a finite signal in reverse order.
+///
+/// See the `Signal::reverse` method.
+#[derive(Clone)]
+pub struct Reverse<F> {
+    frames: Vec<F>,
+}
+
+/// Yields the frames of a finite signal a fixed number of times.
+#[derive(Clone)]
+pub struct RepeatN<S>
+where
+    S: Signal,
+{
+    signal: S,
+    frames: Vec<S::Frame>,
+    recording: bool,
+    repeats: usize,
+    index: usize,
+}
+
+/// Changes the duration of a signal without changing its pitch.
+///
+/// See the `Signal::time_stretch` method.
+#[derive(Clone)]
+pub struct TimeStretch<S>
+where
+    S: Signal,
+{
+    signal: S,
+    ratio: f64,
+    // The buffered frames of `signal`, where `input[0]` is the frame at index `input_start`.
+    input: Vec<S::Frame>,
+    input_start: usize,
+    // The index of the next segment to be added.
+    segment: usize,
+    // The input position at which the previous segment was read.
+    last_position: isize,
+    // The sum of the overlapping segments, starting at the position of the next segment.
+    overlap: Vec<<S::Frame as Frame>::Float>,
+    // Completed frames that are yet to be yielded.
+    ready: Vec<S::Frame>,
+    ready_index: usize,
+    yielded: usize,
+}
+
+/// Linearly interpolates across short runs of NaN or silent frames.
+///
+/// See the `Signal::interpolate_gaps` method.
+#[derive(Clone)]
+pub struct InterpolateGaps<S>
+where
+    S: Signal,
+{
+    signal: S,
+    max_gap: usize,
+    // The last valid frame yielded by `signal`.
+    previous: Option<S::Frame>,
+    // Whether `signal` is within a gap that is too long to be interpolated.
+    long_gap: bool,
+    // Repaired frames that are yet to be yielded.
+    ready: Vec<S::Frame>,
+    ready_index: usize,
+}
+
+// The length of each `TimeStretch` segment in frames.
+const TIME_STRETCH_SEGMENT: usize = 1024;
+
+// The distance between consecutive `TimeStretch` segments in the output.
+const TIME_STRETCH_HOP: usize = TIME_STRETCH_SEGMENT / 2;
+
+// The maximum distance in frames that a `TimeStretch` segment may be shifted from its nominal
+// input position.
+const TIME_STRETCH_TOLERANCE: isize = 256;
+
 /// Represents a forked `Signal` that has not yet been split into its two branches.
 ///
 /// A `Fork` can be split into its two branches via either of the following methods:
@@ -1383,6 +2215,73 @@ where
     }
 }
 
+/// Create a new `Signal` that yields each frame of the given blocks of `N` frames in turn.
+///
+/// This is the inverse of `Signal::into_blocks`. The signal becomes exhausted once the last frame
+/// of the last block has been yielded. If `N` is `0`, the signal is exhausted immediately.
+///
+/// # Example
+///
+/// ```rust
+/// use dasp_signal::{self as signal, Signal};
+///
+/// fn main() {
+///     let blocks = [[0.1, 0.2], [0.3, 0.4]];
+///     let mut signal = signal::from_blocks(blocks.iter().cloned());
+///     assert_eq!(signal.next(), 0.1);
+///     assert_eq!(signal.next(), 0.2);
+///     assert_eq!(signal.next(), 0.3);
+///     assert_eq!(signal.is_exhausted(), false);
+///     assert_eq!(signal.next(), 0.4);
+///     assert_eq!(signal.is_exhausted(), true);
+/// }
+/// ```
+pub fn from_blocks<I, F, const N: usize>(blocks: I) -> FromBlocks<I::IntoIter, F, N>
+where
+    I: IntoIterator<Item = [F; N]>,
+    F: Frame,
+{
+    let mut blocks = blocks.into_iter();
+    let block = if N == 0 { None } else { blocks.next() };
+    FromBlocks {
+        blocks,
+        block,
+        index: 0,
+    }
+}
+
+/// Create a new `Signal` that generates frames by calling `gen` with mutable access to `state`.
+///
+/// The signal becomes exhausted once `gen` returns `None`, after which it yields
+/// `F::EQUILIBRIUM` and `gen` is no longer called. This allows for writing finite generators with
+/// arbitrary state without first implementing an `Iterator`.
+///
+/// Note that `gen` will be called immediately so that `FromFn` can store the next pending frame
+/// and efficiently test for exhaustiveness.
+///
+/// # Example
+///
+/// ```rust
+/// use dasp_signal::{self as signal, Signal};
+///
+/// fn main() {
+///     let countdown = signal::from_fn(3, |n| {
+///         *n -= 1;
+///         if *n >= 0 { Some([*n]) } else { None }
+///     });
+///     let frames: Vec<_> = countdown.until_exhausted().collect();
+///     assert_eq!(frames, vec![[2], [1], [0]]);
+/// }
+/// ```
+pub fn from_fn<St, G, F>(mut state: St, mut gen: G) -> FromFn<St, G, F>
+where
+    G: FnMut(&mut St) -> Option<F>,
+    F: Frame,
+{
+    let next = gen(&mut state);
+    FromFn { state, gen, next }
+}
+
 /// Create a new `Signal` from the given `Frame`-yielding `Iterator`.
 ///
 /// When the `Iterator` is exhausted, the new `Signal` will yield `F::equilibrium`.
@@ -1601,44 +2500,96 @@ where
     }
 }
 
-impl<I, F> Signal for FromInterleavedSamplesIterator<I, F>
+impl<I, F, const N: usize> Signal for FromBlocks<I, F, N>
 where
-    I: Iterator,
-    I::Item: Sample,
-    F: Frame<Sample = I::Item>,
+    I: Iterator<Item = [F; N]>,
+    F: Frame,
 {
     type Frame = F;
 
     #[inline]
     fn next(&mut self) -> Self::Frame {
-        match self.next.take() {
-            Some(frame) => {
-                self.next = F::from_samples(&mut self.samples);
-                frame
-            }
-            None => F::EQUILIBRIUM,
+        let block = match self.block {
+            Some(ref block) => block,
+            None => return Frame::EQUILIBRIUM,
+        };
+        let frame = block[self.index];
+        self.index += 1;
+        if self.index == N {
+            self.block = self.blocks.next();
+            self.index = 0;
         }
+        frame
     }
 
     #[inline]
     fn is_exhausted(&self) -> bool {
-        self.next.is_none()
+        self.block.is_none()
     }
 }
 
-impl<F> Signal for Equilibrium<F>
+impl<St, G, F> Signal for FromFn<St, G, F>
 where
+    G: FnMut(&mut St) -> Option<F>,
     F: Frame,
 {
     type Frame = F;
 
     #[inline]
     fn next(&mut self) -> Self::Frame {
-        F::EQUILIBRIUM
+        match self.next.take() {
+            Some(frame) => {
+                self.next = (self.gen)(&mut self.state);
+                frame
+            }
+            None => Frame::EQUILIBRIUM,
+        }
+    }
+
+    #[inline]
+    fn is_exhausted(&self) -> bool {
+        self.next.is_none()
     }
 }
 
-impl<G, F> Signal for Gen<G, F>
+impl<I, F> Signal for FromInterleavedSamplesIterator<I, F>
+where
+    I: Iterator,
+    I::Item: Sample,
+    F: Frame<Sample = I::Item>,
+{
+    type Frame = F;
+
+    #[inline]
+    fn next(&mut self) -> Self::Frame {
+        match self.next.take() {
+            Some(frame) => {
+                self.next = F::from_samples(&mut self.samples);
+                frame
+            }
+            None => F::EQUILIBRIUM,
+        }
+    }
+
+    #[inline]
+    fn is_exhausted(&self) -> bool {
+        self.next.is_none()
+    }
+}
+
+impl<F> Signal for Equilibrium<F>
+where
+    F: Frame,
+{
+    type Frame = F;
+
+    #[inline]
+    fn next(&mut self) -> Self::Frame {
+        F::EQUILIBRIUM
+    }
+}
+
+impl<G, F> Signal for Gen<G, F>
 where
     G: Fn() -> F,
     F: Frame,
@@ -1701,6 +2652,24 @@ where
     }
 }
 
+impl<S, St, M, F> Signal for Scan<S, St, M, F>
+where
+    S: Signal,
+    M: FnMut(&mut St, S::Frame) -> F,
+    F: Frame,
+{
+    type Frame = F;
+
+    #[inline]
+    fn next(&mut self) -> Self::Frame {
+        (self.f)(&mut self.state, self.signal.next())
+    }
+
+    fn is_exhausted(&self) -> bool {
+        self.signal.is_exhausted()
+    }
+}
+
 impl<S> Signal for Hz<S>
 where
     S: Signal<Frame = f64>,
@@ -2141,6 +3110,29 @@ where
     }
 }
 
+impl<S, C> Signal for WhileGate<S, C>
+where
+    S: Signal,
+    C: Signal<Frame = f64>,
+{
+    type Frame = S::Frame;
+
+    #[inline]
+    fn next(&mut self) -> Self::Frame {
+        let frame = self.signal.next();
+        if self.control.next() > self.threshold {
+            frame
+        } else {
+            Self::Frame::EQUILIBRIUM
+        }
+    }
+
+    #[inline]
+    fn is_exhausted(&self) -> bool {
+        self.signal.is_exhausted() || self.control.is_exhausted()
+    }
+}
+
 impl<S> Signal for ScaleAmp<S>
 where
     S: Signal,
@@ -2179,6 +3171,65 @@ where
     }
 }
 
+impl<S, M> Signal for MapAmp<S, M>
+where
+    S: Signal,
+    M: FnMut(<S::Frame as Frame>::Sample) -> <S::Frame as Frame>::Sample,
+{
+    type Frame = S::Frame;
+
+    #[inline]
+    fn next(&mut self) -> Self::Frame {
+        self.signal.next().map(&mut self.map)
+    }
+
+    #[inline]
+    fn is_exhausted(&self) -> bool {
+        self.signal.is_exhausted()
+    }
+}
+
+impl<S, T> Signal for ToMidSide<S>
+where
+    S: Signal<Frame = [T; 2]>,
+    T: Sample,
+{
+    type Frame = [T; 2];
+
+    #[inline]
+    fn next(&mut self) -> Self::Frame {
+        let [l, r] = self.signal.next();
+        let (l, r) = (l.to_float_sample(), r.to_float_sample());
+        let half = 0.5.to_sample::<T::Float>();
+        [((l + r) * half).to_sample(), ((l - r) * half).to_sample()]
+    }
+
+    #[inline]
+    fn is_exhausted(&self) -> bool {
+        self.signal.is_exhausted()
+    }
+}
+
+impl<S, T> Signal for FromMidSide<S>
+where
+    S: Signal<Frame = [T; 2]>,
+    T: Sample,
+{
+    type Frame = [T; 2];
+
+    #[inline]
+    fn next(&mut self) -> Self::Frame {
+        let [mid, side] = self.signal.next();
+        let (mid, side) = (mid.to_float_sample(), side.to_float_sample());
+        [(mid + side).to_sample(), (mid - side).to_sample()]
+    }
+
+    #[inline]
+    fn is_exhausted(&self) -> bool {
+        self.signal.is_exhausted()
+    }
+}
+
 impl<S> Signal for OffsetAmp<S>
 where
     S: Signal,
@@ -2261,6 +3312,98 @@ where
     }
 }
 
+impl<S> Signal for PadEnd<S>
+where
+    S: Signal,
+{
+    type Frame = S::Frame;
+
+    #[inline]
+    fn next(&mut self) -> Self::Frame {
+        if !self.signal.is_exhausted() {
+            self.signal.next()
+        } else {
+            self.n_frames = self.n_frames.saturating_sub(1);
+            Self::Frame::EQUILIBRIUM
+        }
+    }
+
+    #[inline]
+    fn is_exhausted(&self) -> bool {
+        self.signal.is_exhausted() && self.n_frames == 0
+    }
+}
+
+impl<S> Signal for Downsample<S>
+where
+    S: Signal,
+{
+    type Frame = S::Frame;
+
+    #[inline]
+    fn next(&mut self) -> Self::Frame {
+        if self.factor == 1 {
+            return self.signal.next();
+        }
+        let filters = &mut self.filters;
+        let mut filter = |frame: S::Frame| {
+            let frame = frame.to_float_frame();
+            let filtered = filters.iter_mut().fold(frame, |f, bq| bq.process(f));
+            filtered.map(|s| s.to_sample())
+        };
+        let frame = filter(self.signal.next());
+        for _ in 1..self.factor {
+            filter(self.signal.next());
+        }
+        frame
+    }
+
+    #[inline]
+    fn is_exhausted(&self) -> bool {
+        self.signal.is_exhausted()
+    }
+}
+
+impl<F> Biquad<F>
+where
+    F: Frame,
+    F::Sample: dasp_sample::FloatSample,
+{
+    // A low-pass filter with the given angular cutoff frequency in radians per frame and Q.
+    fn low_pass(w0: f64, q: f64) -> Self {
+        let (sin, cos) = (ops::f64::sin(w0), ops::f64::cos(w0));
+        let alpha = sin / (2.0 * q);
+        let a0 = 1.0 + alpha;
+        let b1 = (1.0 - cos) / a0;
+        Biquad {
+            b0: b1 / 2.0,
+            b1,
+            b2: b1 / 2.0,
+            a1: -2.0 * cos / a0,
+            a2: (1.0 - alpha) / a0,
+            x1: F::EQUILIBRIUM,
+            x2: F::EQUILIBRIUM,
+            y1: F::EQUILIBRIUM,
+            y2: F::EQUILIBRIUM,
+        }
+    }
+
+    fn process(&mut self, x: F) -> F {
+        let ch = |f: &F, ix: usize| f.channel(ix).map_or(0.0, |s| s.to_sample::<f64>());
+        let y = F::from_fn(|ix| {
+            let y = self.b0 * ch(&x, ix) + self.b1 * ch(&self.x1, ix) + self.b2 * ch(&self.x2, ix)
+                - self.a1 * ch(&self.y1, ix)
+                - self.a2 * ch(&self.y2, ix);
+            F::Sample::from_sample(y)
+        });
+        self.x2 = self.x1;
+        self.x1 = x;
+        self.y2 = self.y1;
+        self.y1 = y;
+        y
+    }
+}
+
 impl<S, F> Signal for Inspect<S, F>
 where
     S: Signal,
@@ -2281,6 +3424,60 @@ where
     }
 }
 
+impl<S> AssertFinite<S> {
+    /// Whether or not to panic upon encountering a non-finite sample. Defaults to `true`.
+    ///
+    /// When `false`, the index of the first non-finite frame is recorded instead.
+    pub fn panic_on_non_finite(mut self, panic: bool) -> Self {
+        self.panic_on_non_finite = panic;
+        self
+    }
+
+    /// The index of the first frame containing a non-finite sample, if any have been encountered.
+    pub fn first_non_finite(&self) -> Option<usize> {
+        self.first_non_finite
+    }
+}
+
+impl<S> Signal for AssertFinite<S>
+where
+    S: Signal,
+{
+    type Frame = S::Frame;
+
+    #[inline]
+    fn next(&mut self) -> Self::Frame {
+        let frame = self.signal.next();
+        let index = self.index;
+        self.index += 1;
+        let non_finite = frame
+            .channels()
+            .position(|s| !s.to_float_sample().to_sample::<f64>().is_finite());
+        if let Some(channel) = non_finite {
+            if self.panic_on_non_finite {
+                panic!(
+                    "non-finite sample in channel {} of frame {}",
+                    channel, index
+                );
+            }
+            if self.first_non_finite.is_none() {
+                self.first_non_finite = Some(index);
+                #[cfg(feature = "std")]
+                eprintln!(
+                    "non-finite sample in channel {} of frame {}",
+                    channel, index
+                );
+            }
+        }
+        frame
+    }
+
+    #[inline]
+    fn is_exhausted(&self) -> bool {
+        self.signal.is_exhausted()
+    }
+}
+
 impl<S> IntoInterleavedSamples<S>
 where
     S: Signal,
@@ -2315,6 +3512,28 @@ where
     }
 }
 
+impl<S, T> Iterator for ToSamples<S, T>
+where
+    S: Signal,
+    T: Sample,
+    <S::Frame as Frame>::Sample: ToSample<T>,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(channel) = self.current_frame.as_mut().and_then(Iterator::next) {
+                return Some(channel.to_sample());
+            }
+            if self.signal.is_exhausted() {
+                return None;
+            }
+            self.current_frame = Some(self.signal.next().channels());
+        }
+    }
+}
+
 impl<S> Iterator for UntilExhausted<S>
 where
     S: Signal,
@@ -2329,6 +3548,54 @@ where
     }
 }
 
+impl<S, const N: usize> Iterator for IntoBlocks<S, N>
+where
+    S: Signal,
+{
+    type Item = [S::Frame; N];
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if N == 0 || self.signal.is_exhausted() {
+            return None;
+        }
+        let mut block = [S::Frame::EQUILIBRIUM; N];
+        for frame in block.iter_mut() {
+            if self.signal.is_exhausted() {
+                break;
+            }
+            *frame = self.signal.next();
+        }
+        Some(block)
+    }
+}
+
+impl<S> Enumerate<S>
+where
+    S: Signal,
+{
+    /// The position of the next frame to be yielded.
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl<S> Iterator for Enumerate<S>
+where
+    S: Signal,
+{
+    type Item = (usize, S::Frame);
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.signal.is_exhausted() {
+            return None;
+        }
+        let position = self.position;
+        self.position += 1;
+        Some((position, self.signal.next()))
+    }
+}
+
 impl<S> Clone for IntoInterleavedSamples<S>
 where
     S: Signal + Clone,
@@ -2384,6 +3651,309 @@ where
     }
 }
 
+impl<S> Signal for ClipToNBits<S>
+where
+    S: Signal,
+{
+    type Frame = S::Frame;
+
+    #[inline]
+    fn next(&mut self) -> Self::Frame {
+        let step = self.step;
+        let dither = &mut self.dither;
+        self.signal.next().map(|s| {
+            let mut x = s.to_float_sample().to_sample::<f64>() / step;
+            if let Some(noise) = dither {
+                x += (noise.next_sample() + noise.next_sample()) / 2.0;
+            }
+            let max = 1.0 / step - 1.0;
+            let q = ops::f64::floor(x + 0.5).clamp(-max - 1.0, max) * step;
+            <<S::Frame as Frame>::Sample as Sample>::Float::from_sample(q).to_sample()
+        })
+    }
+
+    #[inline]
+    fn is_exhausted(&self) -> bool {
+        self.signal.is_exhausted()
+    }
+}
+
+impl<F> Signal for Reverse<F>
+where
+    F: Frame,
+{
+    type Frame = F;
+
+    #[inline]
+    fn next(&mut self) -> Self::Frame {
+        self.frames.pop().unwrap_or(F::EQUILIBRIUM)
+    }
+
+    #[inline]
+    fn is_exhausted(&self) -> bool {
+        self.frames.is_empty()
+    }
+}
+
+impl<S> TimeStretch<S>
+where
+    S: Signal,
+{
+    // The frame at the given input position, or equilibrium beyond the ends of the input.
+    fn frame_at(&self, position: isize) -> S::Frame {
+        if position < self.input_start as isize {
+            return S::Frame::EQUILIBRIUM;
+        }
+        let ix = position as usize - self.input_start;
+        self.input.get(ix).cloned().unwrap_or(S::Frame::EQUILIBRIUM)
+    }
+
+    // Buffer frames from the signal until the given input position or its end is reached.
+    fn fill_to(&mut self, position: isize) {
+        while ((self.input_start + self.input.len()) as isize) < position
+            && !self.signal.is_exhausted()
+        {
+            self.input.push(self.signal.next());
+        }
+    }
+
+    // The similarity of the `TIME_STRETCH_HOP` frames starting at the given input positions,
+    // normalized by the energy of the candidate so that louder candidates are not favoured.
+    fn similarity(&self, natural: isize, candidate: isize) -> f64 {
+        let (mut dot, mut energy) = (0.0, 0.0);
+        for j in 0..TIME_STRETCH_HOP as isize {
+            let a = self.frame_at(natural + j).to_float_frame();
+            let b = self.frame_at(candidate + j).to_float_frame();
+            for ch in 0..S::Frame::CHANNELS {
+                let (a, b) = (channel_f64(&a, ch), channel_f64(&b, ch));
+                dot += a * b;
+                energy += b * b;
+            }
+        }
+        if energy > 0.0 {
+            dot / ops::f64::sqrt(energy)
+        } else {
+            0.0
+        }
+    }
+
+    // Add the next segment to the overlap, moving any completed frames into `ready`.
+    fn next_segment(&mut self) {
+        let (hop, tolerance) = (TIME_STRETCH_HOP as isize, TIME_STRETCH_TOLERANCE);
+        // The first segment is placed a hop before the start of the output, so that every output
+        // frame is covered by two segments.
+        let output_position = self.segment as isize * hop - hop;
+        let nominal = ops::f64::floor(output_position as f64 / self.ratio + 0.5) as isize;
+        let position = if self.segment == 0 {
+            nominal
+        } else {
+            let natural = self.last_position + hop;
+            self.fill_to((nominal + tolerance).max(natural) + hop);
+            let mut best = (nominal, self.similarity(natural, nominal));
+            for candidate in nominal - tolerance..=nominal + tolerance {
+                let score = self.similarity(natural, candidate);
+                if score > best.1 {
+                    best = (candidate, score);
+                }
+            }
+            best.0
+        };
+        self.fill_to(position + TIME_STRETCH_SEGMENT as isize);
+
+        if self.overlap.is_empty() {
+            self.overlap = vec![Frame::EQUILIBRIUM; TIME_STRETCH_SEGMENT];
+        }
+        for j in 0..TIME_STRETCH_SEGMENT {
+            let phase = j as f64 / TIME_STRETCH_SEGMENT as f64;
+            let w = 0.5 - 0.5 * ops::f64::cos(2.0 * core::f64::consts::PI * phase);
+            let x = self.frame_at(position + j as isize).to_float_frame();
+            let sum = self.overlap[j];
+            self.overlap[j] = Frame::from_fn(|ch| {
+                Sample::from_sample(channel_f64(&sum, ch) + w * channel_f64(&x, ch))
+            });
+        }
+
+        self.ready.clear();
+        self.ready_index = 0;
+        if output_position >= 0 {
+            for f in &self.overlap[..TIME_STRETCH_HOP] {
+                self.ready.push(f.map(|s| s.to_sample()));
+            }
+        }
+        self.overlap.rotate_left(TIME_STRETCH_HOP);
+        for f in &mut self.overlap[TIME_STRETCH_SEGMENT - TIME_STRETCH_HOP..] {
+            *f = Frame::EQUILIBRIUM;
+        }
+        self.last_position = position;
+        self.segment += 1;
+
+        // Discard frames that can no longer be read by subsequent segments.
+        let next_nominal = (output_position + hop) as f64 / self.ratio;
+        let earliest = (position + hop).min(next_nominal as isize - tolerance - 1);
+        let discard = (earliest - self.input_start as isize).clamp(0, self.input.len() as isize);
+        self.input.drain(..discard as usize);
+        self.input_start += discard as usize;
+    }
+}
+
+// The given channel of a floating point frame as an `f64`.
+fn channel_f64<F>(frame: &F, ch: usize) -> f64
+where
+    F: Frame,
+    F::Sample: dasp_sample::FloatSample,
+{
+    frame.channel(ch).map_or(0.0, |s| s.to_sample::<f64>())
+}
+
+impl<S> Signal for TimeStretch<S>
+where
+    S: Signal,
+{
+    type Frame = S::Frame;
+
+    #[inline]
+    fn next(&mut self) -> Self::Frame {
+        while self.ready_index == self.ready.len() {
+            self.next_segment();
+        }
+        let frame = self.ready[self.ready_index];
+        self.ready_index += 1;
+        self.yielded += 1;
+        frame
+    }
+
+    #[inline]
+    fn is_exhausted(&self) -> bool {
+        if !self.signal.is_exhausted() {
+            return false;
+        }
+        let len = (self.input_start + self.input.len()) as f64 * self.ratio;
+        self.yielded as f64 >= ops::f64::floor(len + 0.5)
+    }
+}
+
+impl<S> Signal for RepeatN<S>
+where
+    S: Signal,
+{
+    type Frame = S::Frame;
+
+    #[inline]
+    fn next(&mut self) -> Self::Frame {
+        if self.recording {
+            if !self.signal.is_exhausted() {
+                let frame = self.signal.next();
+                self.frames.push(frame);
+                if self.signal.is_exhausted() {
+                    self.recording = false;
+                    self.repeats -= 1;
+                }
+                return frame;
+            }
+            self.recording = false;
+            self.repeats -= 1;
+        }
+        if self.repeats == 0 || self.frames.is_empty() {
+            return Self::Frame::EQUILIBRIUM;
+        }
+        let frame = self.frames[self.index];
+        self.index += 1;
+        if self.index == self.frames.len() {
+            self.index = 0;
+            self.repeats -= 1;
+        }
+        frame
+    }
+
+    #[inline]
+    fn is_exhausted(&self) -> bool {
+        if self.recording {
+            self.signal.is_exhausted() && (self.repeats == 1 || self.frames.is_empty())
+        } else {
+            self.repeats == 0 || self.frames.is_empty()
+        }
+    }
+}
+
+impl<S> Signal for InterpolateGaps<S>
+where
+    S: Signal,
+    <S::Frame as Frame>::Sample: Duplex<f64>,
+{
+    type Frame = S::Frame;
+
+    fn next(&mut self) -> Self::Frame {
+        if self.ready_index < self.ready.len() {
+            let frame = self.ready[self.ready_index];
+            self.ready_index += 1;
+            return frame;
+        }
+
+        let frame = self.signal.next();
+        if !is_gap(&frame) {
+            self.previous = Some(frame);
+            self.long_gap = false;
+            return frame;
+        }
+        if self.long_gap {
+            return Self::Frame::EQUILIBRIUM;
+        }
+
+        // Read ahead to find the end of the gap.
+        let mut len = 1;
+        let end = loop {
+            if len > self.max_gap || self.signal.is_exhausted() {
+                break None;
+            }
+            let frame = self.signal.next();
+            if !is_gap(&frame) {
+                break Some(frame);
+            }
+            len += 1;
+        };
+
+        self.ready.clear();
+        match (self.previous, end) {
+            (Some(left), Some(right)) => {
+                for i in 1..=len {
+                    let t = i as f64 / (len + 1) as f64;
+                    self.ready.push(left.zip_map(right, |l, r| {
+                        let l = l.to_sample::<f64>();
+                        let r = r.to_sample::<f64>();
+                        (l + (r - l) * t).to_sample()
+                    }));
+                }
+            }
+            _ => self.ready.resize(len, Self::Frame::EQUILIBRIUM),
+        }
+        self.ready.extend(end);
+        self.previous = end.or(self.previous);
+        self.long_gap = end.is_none();
+        self.ready_index = 1;
+        self.ready[0]
+    }
+
+    fn is_exhausted(&self) -> bool {
+        self.ready_index >= self.ready.len() && self.signal.is_exhausted()
+    }
+}
+
+// Whether any channel of the frame is NaN or all channels are exactly equilibrium.
+fn is_gap<F>(frame: &F) -> bool
+where
+    F: Frame,
+{
+    let mut silent = true;
+    for &sample in frame.channels_ref() {
+        // Only NaN is unordered with respect to itself.
+        if sample.partial_cmp(&sample).is_none() {
+            return true;
+        }
+        silent &= sample == F::Sample::EQUILIBRIUM;
+    }
+    silent
+}
+
 impl<S> Iterator for Take<S>
 where
     S: Signal,
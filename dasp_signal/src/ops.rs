@@ -30,4 +30,22 @@ pub mod f64 {
     pub fn sin(x: f64) -> f64 {
         x.sin()
     }
+
+    #[cfg(not(feature = "std"))]
+    pub fn cos(x: f64) -> f64 {
+        unsafe { core::intrinsics::cosf64(x) }
+    }
+    #[cfg(feature = "std")]
+    pub fn cos(x: f64) -> f64 {
+        x.cos()
+    }
+
+    #[cfg(not(feature = "std"))]
+    pub fn sqrt(x: f64) -> f64 {
+        unsafe { core::intrinsics::sqrtf64(x) }
+    }
+    #[cfg(feature = "std")]
+    pub fn sqrt(x: f64) -> f64 {
+        x.sqrt()
+    }
 }
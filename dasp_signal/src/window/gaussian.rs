@@ -0,0 +1,36 @@
+use super::{Window, Windower};
+use dasp_frame::Frame;
+use dasp_window::Gaussian;
+
+impl<'a, F, const PERCENT: u8> Windower<'a, F, Gaussian<PERCENT>>
+where
+    F: 'a + Frame,
+{
+    /// Constructor for a `Windower` using the `Gaussian` window function.
+    ///
+    /// The standard deviation of the window relative to its length is given by the `PERCENT`
+    /// parameter of the `Gaussian` type.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_signal`, this item requires the **window-gaussian** feature to be
+    ///   enabled.
+    /// - When using `dasp`, this item requires the **signal-window-gaussian** feature to be
+    ///   enabled.
+    pub fn gaussian(frames: &'a [F], bin: usize, hop: usize) -> Self {
+        Windower::new(frames, bin, hop)
+    }
+}
+
+/// A helper function for constructing a `Window` that uses a `Gaussian` `Type` function.
+///
+/// ### Required Features
+///
+/// - When using `dasp_signal`, this item requires the **window-gaussian** feature to be enabled.
+/// - When using `dasp`, this item requires the **signal-window-gaussian** feature to be enabled.
+pub fn gaussian<F, const PERCENT: u8>(num_frames: usize) -> Window<F, Gaussian<PERCENT>>
+where
+    F: Frame,
+{
+    Window::new(num_frames)
+}
@@ -6,15 +6,23 @@ use dasp_frame::Frame;
 use dasp_sample::Sample;
 use dasp_window::Window as WindowType;
 
+#[cfg(feature = "window-gaussian")]
+pub use gaussian::gaussian;
 #[cfg(feature = "window-hann")]
 pub use hann::hann;
 #[cfg(feature = "window-rectangle")]
 pub use rectangle::rectangle;
+#[cfg(feature = "window-tukey")]
+pub use tukey::tukey;
 
+#[cfg(feature = "window-gaussian")]
+mod gaussian;
 #[cfg(feature = "window-hann")]
 mod hann;
 #[cfg(feature = "window-rectangle")]
 mod rectangle;
+#[cfg(feature = "window-tukey")]
+mod tukey;
 
 /// A `Signal` type that for every yielded `phase`, yields the amplitude across the `window::Type`
 /// for that phase.
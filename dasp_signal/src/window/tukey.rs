@@ -0,0 +1,33 @@
+use super::{Window, Windower};
+use dasp_frame::Frame;
+use dasp_window::Tukey;
+
+impl<'a, F, const PERCENT: u8> Windower<'a, F, Tukey<PERCENT>>
+where
+    F: 'a + Frame,
+{
+    /// Constructor for a `Windower` using the `Tukey` window function.
+    ///
+    /// The tapered fraction of the window is given by the `PERCENT` parameter of the `Tukey` type.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_signal`, this item requires the **window-tukey** feature to be enabled.
+    /// - When using `dasp`, this item requires the **signal-window-tukey** feature to be enabled.
+    pub fn tukey(frames: &'a [F], bin: usize, hop: usize) -> Self {
+        Windower::new(frames, bin, hop)
+    }
+}
+
+/// A helper function for constructing a `Window` that uses a `Tukey` `Type` function.
+///
+/// ### Required Features
+///
+/// - When using `dasp_signal`, this item requires the **window-tukey** feature to be enabled.
+/// - When using `dasp`, this item requires the **signal-window-tukey** feature to be enabled.
+pub fn tukey<F, const PERCENT: u8>(num_frames: usize) -> Window<F, Tukey<PERCENT>>
+where
+    F: Frame,
+{
+    Window::new(num_frames)
+}
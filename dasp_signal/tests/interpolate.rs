@@ -1,6 +1,8 @@
 //! Tests for the `Converter` and `Interpolator` traits
 
-use dasp_interpolate::{floor::Floor, linear::Linear, sinc::Sinc};
+use dasp_interpolate::{
+    floor::Floor, lagrange::Lagrange, linear::Linear, sinc::Sinc, Interpolator,
+};
 use dasp_ring_buffer as ring_buffer;
 use dasp_signal::{self as signal, interpolate::Converter, Signal};
 
@@ -71,3 +73,28 @@ fn test_sinc() {
         None
     );
 }
+
+#[test]
+fn test_lagrange_polynomial() {
+    // A cubic is reproduced exactly by a 3rd order interpolator.
+    let p = |t: f64| 0.5 - 0.25 * t + 0.125 * t * t - 0.03125 * t * t * t;
+    let mut interp = Lagrange::new(ring_buffer::Fixed::from([0.0; 4]));
+    assert_eq!(interp.order(), 3);
+    for t in 0..4 {
+        interp.next_source_frame(p(t as f64));
+    }
+    // The interpolated point lies between the second and third frames.
+    for &x in &[0.0, 0.25, 0.5, 0.75] {
+        assert!((interp.interpolate(x) - p(1.0 + x)).abs() < 1e-12);
+    }
+    interp.next_source_frame(p(4.0));
+    assert!((interp.interpolate(0.5) - p(2.5)).abs() < 1e-12);
+}
+
+#[test]
+fn test_lagrange_first_order() {
+    let mut interp = Lagrange::new(ring_buffer::Fixed::from([[0.0, 1.0], [1.0, -1.0]]));
+    assert_eq!(interp.interpolate(0.25), [0.25, 0.5]);
+    interp.reset();
+    assert_eq!(interp.interpolate(0.25), [0.0, 0.0]);
+}
@@ -29,3 +29,414 @@ fn test_offset_amp() {
         .collect();
     assert_eq!(amp_offset, vec![0.0, 0.4, -0.9, -0.7]);
 }
+
+#[test]
+fn test_scan_leaky_integrator() {
+    // A leaky integrator fed a constant input should accumulate towards `input / (1 - leak)`.
+    let leak = 0.5;
+    let integrated: Vec<f64> = signal::gen(|| 1.0)
+        .scan(0.0, |acc, x| {
+            *acc = *acc * leak + x;
+            *acc
+        })
+        .take(4)
+        .collect();
+    assert_eq!(integrated, vec![1.0, 1.5, 1.75, 1.875]);
+}
+
+#[test]
+fn test_reverse() {
+    let ramp = [0.0, 0.25, 0.5, 0.75, 1.0];
+    let mut reversed = signal::from_iter(ramp.iter().cloned()).reverse(ramp.len());
+    let frames: Vec<_> = reversed.by_ref().until_exhausted().collect();
+    assert_eq!(frames, vec![1.0, 0.75, 0.5, 0.25, 0.0]);
+    assert!(reversed.is_exhausted());
+    assert_eq!(reversed.next(), 0.0);
+}
+
+#[test]
+#[should_panic]
+fn test_reverse_infinite() {
+    signal::gen(|| 0.5).reverse(1024);
+}
+
+#[test]
+fn test_pad_start() {
+    let ramp = [0.25, 0.5, 0.75, 1.0];
+    let signal = signal::from_iter(ramp.iter().cloned()).pad_start(4);
+    let frames: Vec<_> = signal.until_exhausted().collect();
+    assert_eq!(frames, vec![0.0, 0.0, 0.0, 0.0, 0.25, 0.5, 0.75, 1.0]);
+}
+
+#[test]
+fn test_pad_end() {
+    let ramp = [0.25, 0.5];
+    let mut signal = signal::from_iter(ramp.iter().cloned()).pad_end(3);
+    let frames: Vec<_> = signal.by_ref().until_exhausted().collect();
+    assert_eq!(frames, vec![0.25, 0.5, 0.0, 0.0, 0.0]);
+    assert!(signal.is_exhausted());
+}
+
+#[test]
+fn test_map_amp_tanh() {
+    let loud = signal::from_iter([[4.0f64, -8.0], [0.0, 16.0], [-2.0, 0.5]].iter().cloned());
+    let frames: Vec<_> = loud.map_amp(f64::tanh).until_exhausted().collect();
+    assert_eq!(frames.len(), 3);
+    for frame in frames {
+        assert!(frame.iter().all(|s| s.abs() < 1.0));
+    }
+}
+
+#[test]
+fn test_downsample_anti_aliasing() {
+    fn rms(frames: &[f64]) -> f64 {
+        (frames.iter().map(|s| s * s).sum::<f64>() / frames.len() as f64).sqrt()
+    }
+    // Downsample from 48kHz to 12kHz, skipping the filter's settling time.
+    let downsample = |hz: f64| -> Vec<f64> {
+        let sine = signal::rate(48_000.0).const_hz(hz).sine();
+        let frames = sine.downsample(4).until_exhausted();
+        frames.skip(1_000).take(12_000).collect()
+    };
+    // A 1kHz tone lies within the new 6kHz Nyquist and is preserved.
+    let passband = rms(&downsample(1_000.0));
+    assert!((passband - 0.5f64.sqrt()).abs() < 0.01);
+    // A 10kHz tone would alias to 2kHz, so should be attenuated by more than 30dB.
+    let aliased = rms(&downsample(10_000.0));
+    assert!(20.0 * (aliased / passband).log10() < -30.0);
+}
+
+#[test]
+fn test_downsample_factor_one() {
+    let ramp = [0.0, 0.25, 0.5, 0.75];
+    let frames: Vec<_> = signal::from_iter(ramp.iter().cloned())
+        .downsample(1)
+        .until_exhausted()
+        .collect();
+    assert_eq!(frames, ramp.to_vec());
+}
+
+#[test]
+fn test_repeat_n() {
+    let frames = [[0.0, 1.0], [0.25, 0.75], [0.5, 0.5], [0.75, 0.25]];
+    let mut repeated = signal::from_iter(frames.iter().cloned()).repeat_n(3);
+    let output: Vec<_> = repeated.by_ref().until_exhausted().collect();
+    assert_eq!(output.len(), 12);
+    for pass in output.chunks(frames.len()) {
+        assert_eq!(pass, &frames[..]);
+    }
+    assert!(repeated.is_exhausted());
+    assert_eq!(repeated.next(), [0.0, 0.0]);
+}
+
+#[test]
+fn test_repeat_n_empty() {
+    let frames = [0.5, 0.25];
+    let zero = signal::from_iter(frames.iter().cloned()).repeat_n(0);
+    assert!(zero.is_exhausted());
+    let empty = signal::from_iter(frames[..0].iter().cloned()).repeat_n(3);
+    assert!(empty.is_exhausted());
+}
+
+#[test]
+fn test_clip_to_n_bits() {
+    // A smooth ramp spanning beyond the full range.
+    let ramp = signal::from_iter((0..24_000).map(|i| -1.2 + i as f64 * 0.0001));
+    for &dither in &[None, Some(0)] {
+        let crushed = ramp.clone().clip_to_n_bits(4, dither);
+        let mut levels: Vec<f64> = crushed.until_exhausted().collect();
+        levels.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        levels.dedup();
+        assert_eq!(levels.len(), 16);
+        assert_eq!(levels[0], -1.0);
+        assert_eq!(levels[15], 0.875);
+    }
+}
+
+#[test]
+fn test_clip_to_n_bits_dither_seed() {
+    let sine = signal::rate(44_100.0).const_hz(440.0).sine();
+    let a: Vec<f64> = sine.clone().clip_to_n_bits(8, Some(42)).take(512).collect();
+    let b: Vec<f64> = sine.clone().clip_to_n_bits(8, Some(42)).take(512).collect();
+    let c: Vec<f64> = sine.clip_to_n_bits(8, Some(7)).take(512).collect();
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+}
+
+#[test]
+fn test_to_samples_i16() {
+    let stereo = signal::gen(|| [0.25f32, -1.0]);
+    let samples: Vec<i16> = stereo.to_samples().take(6).collect();
+    assert_eq!(samples, vec![8192, -32768, 8192, -32768, 8192, -32768]);
+
+    let frames = [[0.5f32, -0.5], [1.0, 0.0]];
+    let finite = signal::from_iter(frames.iter().cloned());
+    let samples: Vec<i16> = finite.to_samples().collect();
+    assert_eq!(samples, vec![16384, -16384, 32767, 0]);
+}
+
+// Estimate the frequency of a signal in Hz from its rate of upward zero crossings.
+fn zero_crossing_hz(frames: &[f64], sample_rate: f64) -> f64 {
+    let crossings = frames
+        .windows(2)
+        .filter(|w| w[0] < 0.0 && w[1] >= 0.0)
+        .count();
+    crossings as f64 * sample_rate / frames.len() as f64
+}
+
+#[test]
+fn test_time_stretch() {
+    const SAMPLE_RATE: f64 = 48_000.0;
+    let sine = signal::rate(SAMPLE_RATE)
+        .const_hz(440.0)
+        .sine()
+        .take(24_000);
+    let frames: Vec<f64> = sine.collect();
+    for &ratio in &[2.0, 0.5] {
+        let source = signal::from_iter(frames.iter().cloned());
+        let stretched: Vec<f64> = source.time_stretch(ratio).until_exhausted().collect();
+        assert_eq!(stretched.len(), (24_000.0 * ratio) as usize);
+        // Measure away from the ends of the signal.
+        let middle = &stretched[2_048..stretched.len() - 2_048];
+        let hz = zero_crossing_hz(middle, SAMPLE_RATE);
+        assert!(
+            (hz - 440.0).abs() < 440.0 * 0.01,
+            "{} Hz at ratio {}",
+            hz,
+            ratio
+        );
+        // Overlapping segments add in phase, maintaining the amplitude.
+        let peak = middle.iter().fold(0.0f64, |peak, s| peak.max(s.abs()));
+        let trough = middle
+            .chunks(109)
+            .map(|c| c.iter().fold(0.0f64, |peak, s| peak.max(s.abs())))
+            .fold(1.0f64, f64::min);
+        assert!(
+            peak < 1.05 && trough > 0.9,
+            "peak {}, trough {}",
+            peak,
+            trough
+        );
+    }
+}
+
+#[test]
+fn test_time_stretch_unity() {
+    let sine = signal::rate(48_000.0).const_hz(440.0).sine().take(8_192);
+    let frames: Vec<f64> = sine.collect();
+    let source = signal::from_iter(frames.iter().cloned());
+    let stretched: Vec<f64> = source.time_stretch(1.0).until_exhausted().collect();
+    assert_eq!(stretched.len(), frames.len());
+    for (a, b) in frames.iter().zip(&stretched) {
+        assert!((a - b).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn test_from_fn() {
+    let mut counter = signal::from_fn(0, |count| {
+        if *count < 10 {
+            *count += 1;
+            Some([*count as f32])
+        } else {
+            None
+        }
+    });
+    let frames: Vec<_> = counter.by_ref().until_exhausted().collect();
+    assert_eq!(frames.len(), 10);
+    assert_eq!(frames[0], [1.0]);
+    assert_eq!(frames[9], [10.0]);
+    assert!(counter.is_exhausted());
+    assert_eq!(counter.next(), [0.0]);
+}
+
+// A runaway feedback loop, overflowing to infinity at frame 1.
+fn unstable() -> impl Signal<Frame = f64> {
+    let mut y = 1.0f64;
+    signal::gen_mut(move || {
+        y *= 1e200;
+        y
+    })
+}
+
+#[test]
+#[should_panic(expected = "non-finite sample in channel 0 of frame 1")]
+fn test_assert_finite_panics() {
+    unstable().assert_finite().take(4).for_each(drop);
+}
+
+#[test]
+fn test_assert_finite_records_first() {
+    let mut checked = unstable().assert_finite().panic_on_non_finite(false);
+    let frames: Vec<f64> = checked.by_ref().take(4).collect();
+    assert_eq!(frames[0], 1e200);
+    assert!(frames[1].is_infinite());
+    assert_eq!(checked.first_non_finite(), Some(1));
+
+    let finite = signal::from_iter([[0.5, -0.5]; 8].iter().cloned());
+    let mut checked = finite.assert_finite();
+    assert_eq!(checked.by_ref().until_exhausted().count(), 8);
+    assert_eq!(checked.first_non_finite(), None);
+}
+
+#[test]
+fn test_enumerate() {
+    // Positions are absolute frame indices from the start of the signal.
+    let mut pairs = signal::from_fn(0, |n| {
+        *n += 1;
+        Some(*n as f32)
+    })
+    .enumerate();
+    let first: Vec<_> = pairs.by_ref().take(3).collect();
+    assert_eq!(first, vec![(0, 1.0), (1, 2.0), (2, 3.0)]);
+    assert_eq!(pairs.position(), 3);
+    assert_eq!(pairs.next(), Some((3, 4.0)));
+
+    // Finite signals end when exhausted.
+    let frames = [[1, 2], [3, 4]];
+    let pairs: Vec<_> = signal::from_iter(frames.iter().cloned())
+        .enumerate()
+        .collect();
+    assert_eq!(pairs, vec![(0, [1, 2]), (1, [3, 4])]);
+}
+
+#[test]
+fn test_mid_side_round_trip() {
+    let frames: Vec<[f64; 2]> = (0..64)
+        .map(|i| {
+            let t = i as f64 / 64.0;
+            [(t * 7.0).sin(), 0.5 * (t * 3.0).cos()]
+        })
+        .collect();
+
+    // Mono content is entirely mid, anti-phase content is entirely side.
+    let ms: Vec<_> = signal::from_iter(vec![[0.5, 0.5], [0.5, -0.5]])
+        .to_mid_side()
+        .until_exhausted()
+        .collect();
+    assert_eq!(ms, vec![[0.5, 0.0], [0.0, 0.5]]);
+
+    let round_trip: Vec<_> = signal::from_iter(frames.iter().cloned())
+        .to_mid_side()
+        .from_mid_side()
+        .until_exhausted()
+        .collect();
+    assert_eq!(round_trip.len(), frames.len());
+    for (a, b) in frames.iter().zip(&round_trip) {
+        assert!((a[0] - b[0]).abs() < 1e-12 && (a[1] - b[1]).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn test_while_gate() {
+    // A 441Hz tone gated by a square wave that is open for the first half of every 100 frames.
+    let tone = signal::rate(44_100.0).const_hz(441.0).sine();
+    // The control signal ends after 400 frames, ending the gated signal.
+    let control = signal::from_fn(0, |i| {
+        *i += 1;
+        match *i - 1 {
+            400 => None,
+            i if i % 100 < 50 => Some(1.0),
+            _ => Some(-1.0),
+        }
+    });
+    let expected: Vec<_> = signal::rate(44_100.0)
+        .const_hz(441.0)
+        .sine()
+        .take(400)
+        .collect();
+    let gated: Vec<_> = tone.while_gate(control, 0.0).until_exhausted().collect();
+    assert_eq!(gated.len(), 400);
+    for (i, (&g, &e)) in gated.iter().zip(&expected).enumerate() {
+        if i % 100 < 50 {
+            assert_eq!(g, e);
+        } else {
+            assert_eq!(g, 0.0);
+        }
+    }
+}
+
+#[test]
+fn test_interpolate_gaps() {
+    let nan = f64::NAN;
+    let ramp = [0.125, 0.25, nan, nan, nan, 0.75, 0.875];
+    let mut repaired = signal::from_iter(ramp.iter().cloned()).interpolate_gaps(3);
+    let output: Vec<_> = repaired.by_ref().until_exhausted().collect();
+    assert_eq!(output, vec![0.125, 0.25, 0.375, 0.5, 0.625, 0.75, 0.875]);
+    assert!(repaired.is_exhausted());
+
+    // Silent frames and frames with any NaN channel are gaps.
+    let frames = [[0.5, 1.0], [0.0, 0.0], [nan, 0.0], [1.0, 0.0]];
+    let output: Vec<_> = signal::from_iter(frames.iter().cloned())
+        .interpolate_gaps(2)
+        .until_exhausted()
+        .collect();
+    let expected = [
+        [0.5, 1.0],
+        [2.0 / 3.0, 2.0 / 3.0],
+        [5.0 / 6.0, 1.0 / 3.0],
+        [1.0, 0.0],
+    ];
+    for (frame, expected) in output.iter().zip(&expected) {
+        assert!((frame[0] - expected[0]).abs() < 1e-12);
+        assert!((frame[1] - expected[1]).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn test_interpolate_gaps_long() {
+    let nan = f64::NAN;
+    // Gaps longer than `max_gap`, or without a valid frame on each side, become silence.
+    let frames = [nan, 0.5, nan, nan, nan, 1.0, nan, 0.5, 0.0];
+    let output: Vec<_> = signal::from_iter(frames.iter().cloned())
+        .interpolate_gaps(2)
+        .until_exhausted()
+        .collect();
+    assert_eq!(output, vec![0.0, 0.5, 0.0, 0.0, 0.0, 1.0, 0.75, 0.5, 0.0]);
+
+    // A `max_gap` of `0` only removes NaNs.
+    let output: Vec<_> = signal::from_iter(frames.iter().cloned())
+        .interpolate_gaps(0)
+        .until_exhausted()
+        .collect();
+    assert_eq!(output, vec![0.0, 0.5, 0.0, 0.0, 0.0, 1.0, 0.0, 0.5, 0.0]);
+}
+
+#[test]
+fn test_into_blocks_from_blocks() {
+    let frames: Vec<[f32; 2]> = (0..200).map(|i| [i as f32, -(i as f32)]).collect();
+    let blocks: Vec<[[f32; 2]; 64]> = signal::from_iter(frames.iter().cloned())
+        .into_blocks::<64>()
+        .collect();
+    assert_eq!(blocks.len(), 4);
+
+    // The final partial block is padded with silence.
+    assert_eq!(blocks[3][7], [199.0, -199.0]);
+    assert!(blocks[3][8..].iter().all(|&frame| frame == [0.0, 0.0]));
+
+    // Flattening reproduces the original frames, followed by the padding.
+    let mut flattened = signal::from_blocks(blocks);
+    let output: Vec<_> = flattened.by_ref().until_exhausted().collect();
+    assert_eq!(output.len(), 256);
+    assert_eq!(&output[..200], &frames[..]);
+    assert!(flattened.is_exhausted());
+    assert_eq!(flattened.next(), [0.0, 0.0]);
+}
+
+#[test]
+fn test_into_blocks_exact() {
+    // A signal ending on a block boundary yields no padding block.
+    let frames: Vec<f32> = (0..128).map(|i| i as f32).collect();
+    let output: Vec<_> =
+        signal::from_blocks(signal::from_iter(frames.iter().cloned()).into_blocks::<64>())
+            .until_exhausted()
+            .collect();
+    assert_eq!(output, frames);
+
+    // Infinite signals yield blocks indefinitely.
+    let blocks = signal::equilibrium::<f32>().into_blocks::<64>();
+    assert_eq!(blocks.take(10).count(), 10);
+
+    let empty = signal::from_blocks(std::iter::empty::<[f32; 0]>());
+    assert!(empty.is_exhausted());
+}
@@ -49,3 +49,67 @@ fn test_window_size() {
         .collect();
     assert_eq!(windows.len(), 3);
 }
+
+#[cfg(all(
+    feature = "window-hann",
+    feature = "window-rectangle",
+    feature = "window-tukey"
+))]
+#[test]
+fn test_tukey_alpha() {
+    // An alpha of 0 reduces to the rectangle window.
+    let tukey = window::tukey::<f64, 0>(9);
+    let rectangle = window::rectangle::<f64>(9);
+    for (t, r) in tukey.zip(rectangle).take(9) {
+        assert_eq!(t, r);
+    }
+
+    // An alpha of 1 matches the hann window.
+    let tukey = window::tukey::<f64, 100>(9);
+    let hann = window::hann::<f64>(9);
+    for (t, h) in tukey.zip(hann).take(9) {
+        assert!((t - h).abs() < 1e-9);
+    }
+
+    // An alpha of 0.5 tapers the outer quarters and leaves the middle flat.
+    let expected = [0.0, 0.5, 1.0, 1.0, 1.0, 1.0, 1.0, 0.5, 0.0];
+    let tukey = window::tukey::<f64, 50>(9);
+    for (t, e) in tukey.zip(&expected) {
+        assert!((t - e).abs() < 1e-9);
+    }
+}
+
+#[cfg(feature = "window-gaussian")]
+#[test]
+fn test_gaussian_sigma() {
+    // The centre of the window is 1.0 while the rest follows the gaussian falloff.
+    let sigma = 0.25;
+    let gaussian = window::gaussian::<f64, 25>(9);
+    for (i, g) in gaussian.take(9).enumerate() {
+        let x = (i as f64 / 8.0 - 0.5) / sigma;
+        let expected = (-0.5 * x * x).exp();
+        assert!((g - expected).abs() < 1e-9, "{}: {} != {}", i, g, expected);
+        if i == 4 {
+            assert_eq!(g, 1.0);
+        }
+    }
+
+    // The edges lie two standard deviations from the centre.
+    let edge = window::gaussian::<f64, 25>(9).next().unwrap();
+    assert!((edge - (-2.0f64).exp()).abs() < 1e-9);
+
+    // A narrower window falls off faster.
+    let narrow = window::gaussian::<f64, 10>(9).nth(2).unwrap();
+    let wide = window::gaussian::<f64, 40>(9).nth(2).unwrap();
+    assert!(narrow < wide);
+}
+
+#[cfg(feature = "window-gaussian")]
+#[test]
+fn test_gaussian_windower() {
+    let data = [1.0f64; 9];
+    let mut windower = Windower::<_, dasp_window::Gaussian<25>>::gaussian(&data[..], 9, 9);
+    let windowed: Vec<f64> = windower.next().unwrap().take(9).collect();
+    assert_eq!(windowed[4], 1.0);
+    assert!((windowed[0] - (-2.0f64).exp()).abs() < 1e-9);
+}
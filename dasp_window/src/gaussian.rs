@@ -0,0 +1,41 @@
+use crate::ops::f64::exp;
+use crate::Window;
+use dasp_sample::Sample;
+
+/// A Gaussian window, where the standard deviation relative to the window length is given by the
+/// `PERCENT` parameter.
+///
+/// The standard deviation `sigma` is `PERCENT / 100` of the window length. A `PERCENT` of `0` is
+/// treated as `1` to avoid dividing by zero. Smaller values yield a narrower window in time with
+/// a wider main lobe in frequency and vice versa, allowing for control over the time-frequency
+/// resolution of Gabor and other spectral analysis. The window peaks at `1.0` in the centre.
+///
+/// [Wiki entry](https://en.wikipedia.org/wiki/Window_function#Gaussian_window).
+///
+/// ### Required Features
+///
+/// - When using `dasp_window`, this item requires the **gaussian** feature to be enabled.
+/// - When using `dasp`, this item requires the **window-gaussian** feature to be enabled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Gaussian<const PERCENT: u8>;
+
+impl<const PERCENT: u8> Gaussian<PERCENT> {
+    /// The standard deviation of the window relative to its length.
+    pub const SIGMA: f64 = if PERCENT == 0 {
+        0.01
+    } else {
+        PERCENT as f64 / 100.0
+    };
+}
+
+impl<S, const PERCENT: u8> Window<S> for Gaussian<PERCENT>
+where
+    S: Sample,
+{
+    type Output = S;
+    fn window(phase: S) -> Self::Output {
+        let p = phase.to_float_sample().to_sample::<f64>();
+        let x = (p - 0.5) / Self::SIGMA;
+        exp(-0.5 * x * x).to_sample::<S::Float>().to_sample::<S>()
+    }
+}
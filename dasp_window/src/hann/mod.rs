@@ -1,8 +1,6 @@
+use crate::ops::f64::cos;
 use crate::Window;
 use dasp_sample::Sample;
-use ops::f64::cos;
-
-mod ops;
 
 /// A type of window function, also known as the "raised cosine window".
 ///
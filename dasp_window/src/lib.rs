@@ -1,12 +1,16 @@
-//! Module for windowing over a batch of Frames. Includes default Hann and Rectangle window
-//! types.
+//! Module for windowing over a batch of Frames. Includes default Gaussian, Hann, Rectangle and
+//! Tukey window types.
 //!
 //! ### Optional Features
 //!
+//! - The **gaussian** feature (or **window-gaussian** feature if using `dasp`) provides the
+//!   [**Gaussian**](./struct.Gaussian.html) window function implementation.
 //! - The **hann** feature (or **window-hann** feature if using `dasp`) provides the
 //!   [**Hann**](./struct.Hann.html) window function implementation.
 //! - The **rectangle** feature (or **window-rectangle** feature if using `dasp`) provides the
 //!   [**Rectangle**](./struct.Rectangle.html) window function implementation.
+//! - The **tukey** feature (or **window-tukey** feature if using `dasp`) provides the
+//!   [**Tukey**](./struct.Tukey.html) window function implementation.
 //!
 //! ### no_std
 //!
@@ -18,15 +22,25 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(not(feature = "std"), feature(core_intrinsics))]
 
+#[cfg(feature = "gaussian")]
+pub use gaussian::Gaussian;
 #[cfg(feature = "hann")]
 pub use hann::Hann;
 #[cfg(feature = "rectangle")]
 pub use rectangle::Rectangle;
+#[cfg(feature = "tukey")]
+pub use tukey::Tukey;
 
+#[cfg(feature = "gaussian")]
+mod gaussian;
 #[cfg(feature = "hann")]
 mod hann;
+#[cfg(any(feature = "gaussian", feature = "hann", feature = "tukey"))]
+mod ops;
 #[cfg(feature = "rectangle")]
 mod rectangle;
+#[cfg(feature = "tukey")]
+mod tukey;
 
 /// An abstraction supporting different types of `Window` functions.
 ///
@@ -9,4 +9,12 @@ pub mod f64 {
     pub fn cos(x: f64) -> f64 {
         x.cos()
     }
+    #[cfg(not(feature = "std"))]
+    pub fn exp(x: f64) -> f64 {
+        unsafe { core::intrinsics::expf64(x) }
+    }
+    #[cfg(feature = "std")]
+    pub fn exp(x: f64) -> f64 {
+        x.exp()
+    }
 }
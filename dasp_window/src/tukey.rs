@@ -0,0 +1,49 @@
+use crate::ops::f64::cos;
+use crate::Window;
+use dasp_sample::Sample;
+
+/// A tapered cosine window, where the fraction of the window that is tapered is given by the
+/// `PERCENT` parameter.
+///
+/// The `alpha` ratio is `PERCENT / 100`, clamped to `1.0`. An `alpha` of `0.0` is equivalent to
+/// the `Rectangle` window, while an `alpha` of `1.0` is equivalent to the `Hann` window. Values in
+/// between produce a flat top with cosine-tapered edges, useful for gating with controllable edge
+/// softness.
+///
+/// [Wiki entry](https://en.wikipedia.org/wiki/Window_function#Tukey_window).
+///
+/// ### Required Features
+///
+/// - When using `dasp_window`, this item requires the **tukey** feature to be enabled.
+/// - When using `dasp`, this item requires the **window-tukey** feature to be enabled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Tukey<const PERCENT: u8>;
+
+impl<const PERCENT: u8> Tukey<PERCENT> {
+    /// The fraction of the window that is tapered.
+    pub const ALPHA: f64 = if PERCENT > 100 {
+        1.0
+    } else {
+        PERCENT as f64 / 100.0
+    };
+}
+
+impl<S, const PERCENT: u8> Window<S> for Tukey<PERCENT>
+where
+    S: Sample,
+{
+    type Output = S;
+    fn window(phase: S) -> Self::Output {
+        const PI_2: f64 = core::f64::consts::PI * 2.0;
+        let alpha = Self::ALPHA;
+        let p = phase.to_float_sample().to_sample::<f64>();
+        // Distance into the nearest tapered edge.
+        let edge = if p < 0.5 { p } else { 1.0 - p };
+        let v = if edge >= alpha / 2.0 {
+            1.0
+        } else {
+            0.5 * (1.0 - cos(PI_2 * edge / alpha))
+        };
+        v.to_sample::<S::Float>().to_sample::<S>()
+    }
+}